@@ -0,0 +1,683 @@
+use core::{fmt, marker::PhantomData};
+
+use crate::{Cost, Inspect, Node, Port, Ports, Status, TickCost};
+
+pub const MAX_SCOPE_DEPTH: usize = 16;
+
+const ROOT_SCOPE: &str = "";
+
+pub struct Key<T> {
+    name: &'static str,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Key<T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _type: PhantomData,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for Key<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Key<T> {}
+
+impl<T> fmt::Debug for Key<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Key").field(&self.name).finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(&'static str),
+}
+
+impl Value {
+    pub const fn kind(&self) -> ValueKind {
+        match self {
+            Value::Bool(_) => ValueKind::Bool,
+            Value::Int(_) => ValueKind::Int,
+            Value::UInt(_) => ValueKind::UInt,
+            Value::Float(_) => ValueKind::Float,
+            Value::Str(_) => ValueKind::Str,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Bool,
+    Int,
+    UInt,
+    Float,
+    Str,
+}
+
+impl fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ValueKind::Bool => "bool",
+            ValueKind::Int => "signed integer",
+            ValueKind::UInt => "unsigned integer",
+            ValueKind::Float => "float",
+            ValueKind::Str => "string",
+        })
+    }
+}
+
+pub trait Entry: Sized {
+    const KIND: ValueKind;
+
+    fn into_value(self) -> Value;
+    fn from_value(value: Value) -> Option<Self>;
+}
+
+macro_rules! impl_entry {
+    ($ty:ty, $variant:ident, $kind:ident, $stored:ty) => {
+        impl Entry for $ty {
+            const KIND: ValueKind = ValueKind::$kind;
+
+            fn into_value(self) -> Value {
+                Value::$variant(<$stored>::from(self))
+            }
+
+            fn from_value(value: Value) -> Option<Self> {
+                match value {
+                    Value::$variant(stored) => <$ty>::try_from(stored).ok(),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_entry!(bool, Bool, Bool, bool);
+impl_entry!(i8, Int, Int, i64);
+impl_entry!(i16, Int, Int, i64);
+impl_entry!(i32, Int, Int, i64);
+impl_entry!(i64, Int, Int, i64);
+impl_entry!(u8, UInt, UInt, u64);
+impl_entry!(u16, UInt, UInt, u64);
+impl_entry!(u32, UInt, UInt, u64);
+impl_entry!(u64, UInt, UInt, u64);
+impl_entry!(&'static str, Str, Str, &'static str);
+
+impl Entry for usize {
+    const KIND: ValueKind = ValueKind::UInt;
+
+    fn into_value(self) -> Value {
+        Value::UInt(self as u64)
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::UInt(stored) => usize::try_from(stored).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl Entry for f32 {
+    const KIND: ValueKind = ValueKind::Float;
+
+    fn into_value(self) -> Value {
+        Value::Float(f64::from(self))
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::Float(stored) => Some(stored as f32),
+            _ => None,
+        }
+    }
+}
+
+impl Entry for f64 {
+    const KIND: ValueKind = ValueKind::Float;
+
+    fn into_value(self) -> Value {
+        Value::Float(self)
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::Float(stored) => Some(stored),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlackboardError {
+    Missing {
+        key: &'static str,
+    },
+    TypeMismatch {
+        key: &'static str,
+        expected: ValueKind,
+        found: ValueKind,
+    },
+    OutOfRange {
+        key: &'static str,
+    },
+    Full {
+        capacity: usize,
+    },
+}
+
+impl fmt::Display for BlackboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlackboardError::Missing { key } => write!(f, "blackboard entry `{key}` is not set"),
+            BlackboardError::TypeMismatch {
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "blackboard entry `{key}` holds a {found}, expected a {expected}"
+            ),
+            BlackboardError::OutOfRange { key } => {
+                write!(
+                    f,
+                    "blackboard entry `{key}` does not fit the requested type"
+                )
+            }
+            BlackboardError::Full { capacity } => {
+                write!(f, "blackboard is full ({capacity} entries)")
+            }
+        }
+    }
+}
+
+impl core::error::Error for BlackboardError {}
+
+pub trait Storage {
+    fn get(&self, scope: &'static str, name: &'static str) -> Option<Value>;
+    fn insert(
+        &mut self,
+        scope: &'static str,
+        name: &'static str,
+        value: Value,
+    ) -> Result<(), BlackboardError>;
+    fn remove(&mut self, scope: &'static str, name: &'static str) -> Option<Value>;
+}
+
+pub struct FixedStorage<const N: usize> {
+    entries: [Option<(&'static str, &'static str, Value)>; N],
+}
+
+impl<const N: usize> FixedStorage<N> {
+    pub const fn new() -> Self {
+        Self { entries: [None; N] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn position(&self, scope: &str, name: &str) -> Option<usize> {
+        self.entries.iter().position(|entry| match entry {
+            Some((entry_scope, entry_name, _)) => *entry_scope == scope && *entry_name == name,
+            None => false,
+        })
+    }
+}
+
+impl<const N: usize> Default for FixedStorage<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Storage for FixedStorage<N> {
+    fn get(&self, scope: &'static str, name: &'static str) -> Option<Value> {
+        self.position(scope, name)
+            .and_then(|index| self.entries[index].map(|(_, _, value)| value))
+    }
+
+    fn insert(
+        &mut self,
+        scope: &'static str,
+        name: &'static str,
+        value: Value,
+    ) -> Result<(), BlackboardError> {
+        let index = self
+            .position(scope, name)
+            .or_else(|| self.entries.iter().position(Option::is_none))
+            .ok_or(BlackboardError::Full { capacity: N })?;
+        self.entries[index] = Some((scope, name, value));
+        Ok(())
+    }
+
+    fn remove(&mut self, scope: &'static str, name: &'static str) -> Option<Value> {
+        let index = self.position(scope, name)?;
+        self.entries[index].take().map(|(_, _, value)| value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+pub struct MapStorage {
+    entries: alloc::collections::BTreeMap<(&'static str, &'static str), Value>,
+}
+
+#[cfg(feature = "alloc")]
+impl MapStorage {
+    pub const fn new() -> Self {
+        Self {
+            entries: alloc::collections::BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Storage for MapStorage {
+    fn get(&self, scope: &'static str, name: &'static str) -> Option<Value> {
+        self.entries.get(&(scope, name)).copied()
+    }
+
+    fn insert(
+        &mut self,
+        scope: &'static str,
+        name: &'static str,
+        value: Value,
+    ) -> Result<(), BlackboardError> {
+        self.entries.insert((scope, name), value);
+        Ok(())
+    }
+
+    fn remove(&mut self, scope: &'static str, name: &'static str) -> Option<Value> {
+        self.entries.remove(&(scope, name))
+    }
+}
+
+pub type Remap = (&'static str, &'static str);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Frame {
+    pub(crate) name: &'static str,
+    pub(crate) remaps: &'static [Remap],
+}
+
+pub struct Blackboard<S> {
+    storage: S,
+    frames: [Frame; MAX_SCOPE_DEPTH],
+    depth: usize,
+}
+
+impl<S> Blackboard<S> {
+    pub const fn new(storage: S) -> Self {
+        Self {
+            storage,
+            frames: [Frame {
+                name: ROOT_SCOPE,
+                remaps: &[],
+            }; MAX_SCOPE_DEPTH],
+            depth: 0,
+        }
+    }
+
+    pub const fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    pub const fn scope_depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn enter(&mut self, name: &'static str, remaps: &'static [Remap]) {
+        if self.depth == MAX_SCOPE_DEPTH {
+            panic!("blackboard scopes nest at most {MAX_SCOPE_DEPTH} deep");
+        }
+
+        self.frames[self.depth] = Frame { name, remaps };
+        self.depth += 1;
+    }
+
+    pub fn exit(&mut self) {
+        if self.depth == 0 {
+            panic!("blackboard scope exit without matching enter");
+        }
+
+        self.depth -= 1;
+    }
+
+    // Like `Vec::truncate`: closes every scope above `depth` and does nothing
+    // if fewer are open.
+    pub fn truncate(&mut self, depth: usize) {
+        self.depth = self.depth.min(depth);
+    }
+
+    fn resolve(&self, name: &'static str) -> (&'static str, &'static str) {
+        resolve(&self.frames[..self.depth], name)
+    }
+}
+
+// Remapped keys resolve in the parent scope (transitively); every other key is
+// private to the innermost scope, as with BT.CPP subtree ports.
+pub(crate) fn resolve(frames: &[Frame], mut name: &'static str) -> (&'static str, &'static str) {
+    let mut depth = frames.len();
+    while depth > 0 {
+        let frame = frames[depth - 1];
+        match frame.remaps.iter().find(|(local, _)| *local == name) {
+            Some((_, parent)) => {
+                name = parent;
+                depth -= 1;
+            }
+            None => return (frame.name, name),
+        }
+    }
+
+    (ROOT_SCOPE, name)
+}
+
+impl<S> Blackboard<S>
+where
+    S: Storage,
+{
+    pub fn get<T: Entry>(&self, key: Key<T>) -> Result<T, BlackboardError> {
+        let (scope, name) = self.resolve(key.name);
+        let value = self
+            .storage
+            .get(scope, name)
+            .ok_or(BlackboardError::Missing { key: key.name })?;
+        check_kind::<T>(key.name, value)?;
+        T::from_value(value).ok_or(BlackboardError::OutOfRange { key: key.name })
+    }
+
+    pub fn set<T: Entry>(&mut self, key: Key<T>, value: T) -> Result<(), BlackboardError> {
+        let (scope, name) = self.resolve(key.name);
+        if let Some(existing) = self.storage.get(scope, name) {
+            check_kind::<T>(key.name, existing)?;
+        }
+        self.storage.insert(scope, name, value.into_value())
+    }
+
+    pub fn contains<T: Entry>(&self, key: Key<T>) -> bool {
+        let (scope, name) = self.resolve(key.name);
+        self.storage
+            .get(scope, name)
+            .is_some_and(|value| value.kind() == T::KIND)
+    }
+
+    // A mismatched entry stays in place, as with `set`.
+    pub fn remove<T: Entry>(&mut self, key: Key<T>) -> Result<Option<T>, BlackboardError> {
+        let (scope, name) = self.resolve(key.name);
+        let Some(value) = self.storage.get(scope, name) else {
+            return Ok(None);
+        };
+        check_kind::<T>(key.name, value)?;
+        let value = T::from_value(value).ok_or(BlackboardError::OutOfRange { key: key.name })?;
+        self.storage.remove(scope, name);
+        Ok(Some(value))
+    }
+}
+
+impl<S> Default for Blackboard<S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+fn check_kind<T: Entry>(key: &'static str, value: Value) -> Result<(), BlackboardError> {
+    if value.kind() == T::KIND {
+        Ok(())
+    } else {
+        Err(BlackboardError::TypeMismatch {
+            key,
+            expected: T::KIND,
+            found: value.kind(),
+        })
+    }
+}
+
+pub trait BlackboardContext {
+    type Storage: Storage;
+
+    fn blackboard(&self) -> &Blackboard<Self::Storage>;
+    fn blackboard_mut(&mut self) -> &mut Blackboard<Self::Storage>;
+}
+
+impl<S> BlackboardContext for Blackboard<S>
+where
+    S: Storage,
+{
+    type Storage = S;
+
+    fn blackboard(&self) -> &Blackboard<S> {
+        self
+    }
+
+    fn blackboard_mut(&mut self) -> &mut Blackboard<S> {
+        self
+    }
+}
+
+pub struct Scope<Child> {
+    name: &'static str,
+    remaps: &'static [Remap],
+    child: Child,
+    // The blackboard depth before `enter`, held until the matching `exit`.
+    entered_at: Option<usize>,
+}
+
+impl<Child> Scope<Child> {
+    pub const fn new(name: &'static str, remaps: &'static [Remap], child: Child) -> Self {
+        Self {
+            name,
+            remaps,
+            child,
+            entered_at: None,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child> Node<Ctx> for Scope<Child>
+where
+    Ctx: BlackboardContext,
+    Child: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        // A tick dropped while the child was pending never reached `exit`;
+        // close the frames it left open before entering again.
+        let blackboard = ctx.blackboard_mut();
+        if let Some(depth) = self.entered_at.take() {
+            blackboard.truncate(depth);
+        }
+        self.entered_at = Some(blackboard.scope_depth());
+        blackboard.enter(self.name, self.remaps);
+
+        let status = self.child.tick(ctx).await;
+        ctx.blackboard_mut().exit();
+        self.entered_at = None;
+
+        if status != Status::Running {
+            self.child.reset();
+        }
+        status
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for Scope<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Scope"
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        Some((self.name, self.remaps))
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child> TickCost for Scope<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST.settled();
+}
+
+pub struct CheckEntry<T, F> {
+    key: Key<T>,
+    predicate: F,
+}
+
+impl<T, F> CheckEntry<T, F> {
+    pub const fn new(key: Key<T>, predicate: F) -> Self {
+        Self { key, predicate }
+    }
+
+    pub const fn key(&self) -> Key<T> {
+        self.key
+    }
+}
+
+impl<Ctx, T, F> Node<Ctx> for CheckEntry<T, F>
+where
+    Ctx: BlackboardContext,
+    T: Entry,
+    F: FnMut(&T) -> bool,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        match ctx.blackboard().get(self.key) {
+            Ok(value) if (self.predicate)(&value) => Status::Success,
+            _ => Status::Failure,
+        }
+    }
+}
+
+impl<T, F> Ports for CheckEntry<T, F>
+where
+    T: Entry,
+{
+    fn ports(&self, declare: &mut dyn FnMut(Port)) {
+        declare(Port::input(self.key));
+    }
+}
+
+impl<T, F> Inspect for CheckEntry<T, F>
+where
+    T: Entry,
+{
+    fn kind(&self) -> &'static str {
+        "CheckEntry"
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        Some(self)
+    }
+}
+
+impl<T, F> TickCost for CheckEntry<T, F> {
+    const COST: Cost = Cost::CONDITION;
+}
+
+pub struct SetEntry<T> {
+    key: Key<T>,
+    value: T,
+}
+
+impl<T> SetEntry<T> {
+    pub const fn new(key: Key<T>, value: T) -> Self {
+        Self { key, value }
+    }
+
+    pub const fn key(&self) -> Key<T> {
+        self.key
+    }
+}
+
+impl<Ctx, T> Node<Ctx> for SetEntry<T>
+where
+    Ctx: BlackboardContext,
+    T: Entry + Clone,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        match ctx.blackboard_mut().set(self.key, self.value.clone()) {
+            Ok(()) => Status::Success,
+            Err(_) => Status::Failure,
+        }
+    }
+}
+
+impl<T> Ports for SetEntry<T>
+where
+    T: Entry,
+{
+    fn ports(&self, declare: &mut dyn FnMut(Port)) {
+        declare(Port::output(self.key));
+    }
+}
+
+impl<T> Inspect for SetEntry<T>
+where
+    T: Entry,
+{
+    fn kind(&self) -> &'static str {
+        "SetEntry"
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        Some(self)
+    }
+}
+
+impl<T> TickCost for SetEntry<T> {
+    const COST: Cost = Cost::CONDITION;
+}
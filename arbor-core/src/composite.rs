@@ -1,21 +1,43 @@
-use crate::{Node, NodeList, Status};
+use core::fmt;
+
+use crate::{
+    ConcurrentNodeList, Cost, CostList, Inspect, InspectList, Node, NodeCount, NodeList, Params,
+    SplitContext, Status, TickCost,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Memory {
+    #[default]
+    Resume,
+    Persistent,
+}
 
 pub struct Sequence<Children> {
     children: Children,
     running_index: usize,
+    memory: Memory,
 }
 
 impl<Children> Sequence<Children> {
     pub const fn new(children: Children) -> Self {
+        Self::with_memory(children, Memory::Resume)
+    }
+
+    pub const fn with_memory(children: Children, memory: Memory) -> Self {
         Self {
             children,
             running_index: 0,
+            memory,
         }
     }
 
     pub fn into_children(self) -> Children {
         self.children
     }
+
+    pub const fn memory(&self) -> Memory {
+        self.memory
+    }
 }
 
 impl<Ctx, Children> Node<Ctx> for Sequence<Children>
@@ -25,14 +47,14 @@ where
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
         let mut index = self.running_index;
 
-        while index < Children::LEN {
+        while index < self.children.len() {
             match self.children.tick_at(index, ctx).await {
                 Status::Success => {
                     index += 1;
                 }
                 Status::Failure => {
-                    self.running_index = 0;
-                    self.children.reset_all();
+                    self.running_index = index;
+                    self.reset();
                     return Status::Failure;
                 }
                 Status::Running => {
@@ -48,27 +70,64 @@ where
     }
 
     fn reset(&mut self) {
-        self.running_index = 0;
-        self.children.reset_all();
+        match self.memory {
+            Memory::Resume => {
+                self.running_index = 0;
+                self.children.reset_all();
+            }
+            // Like BT.CPP's SequenceWithMemory::halt, keep the index so an
+            // interrupted or failed sequence does not redo finished children.
+            Memory::Persistent => self.children.reset_range(self.running_index),
+        }
+    }
+}
+
+impl<Children> Inspect for Sequence<Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "Sequence"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
     }
 }
 
+impl<Children> TickCost for Sequence<Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::sequence(Children::COSTS);
+}
+
 pub struct Selector<Children> {
     children: Children,
     running_index: usize,
+    memory: Memory,
 }
 
 impl<Children> Selector<Children> {
     pub const fn new(children: Children) -> Self {
+        Self::with_memory(children, Memory::Resume)
+    }
+
+    pub const fn with_memory(children: Children, memory: Memory) -> Self {
         Self {
             children,
             running_index: 0,
+            memory,
         }
     }
 
     pub fn into_children(self) -> Children {
         self.children
     }
+
+    pub const fn memory(&self) -> Memory {
+        self.memory
+    }
 }
 
 impl<Ctx, Children> Node<Ctx> for Selector<Children>
@@ -78,8 +137,10 @@ where
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
         let mut index = self.running_index;
 
-        while index < Children::LEN {
+        while index < self.children.len() {
             match self.children.tick_at(index, ctx).await {
+                // A success finishes the selector whatever its memory, so
+                // the next tick starts over from the first child.
                 Status::Success => {
                     self.running_index = 0;
                     self.children.reset_all();
@@ -101,11 +162,38 @@ where
     }
 
     fn reset(&mut self) {
-        self.running_index = 0;
-        self.children.reset_all();
+        match self.memory {
+            Memory::Resume => {
+                self.running_index = 0;
+                self.children.reset_all();
+            }
+            // Halting a running selector keeps its place, so children that
+            // already failed are not retried when it resumes.
+            Memory::Persistent => self.children.reset_range(self.running_index),
+        }
     }
 }
 
+impl<Children> Inspect for Selector<Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "Selector"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<Children> TickCost for Selector<Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::selector(Children::COSTS);
+}
+
 pub struct ReactiveSequence<Children> {
     children: Children,
 }
@@ -127,7 +215,7 @@ where
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
         let mut index = 0;
 
-        while index < Children::LEN {
+        while index < self.children.len() {
             match self.children.tick_at(index, ctx).await {
                 Status::Success => {
                     index += 1;
@@ -152,6 +240,26 @@ where
     }
 }
 
+impl<Children> Inspect for ReactiveSequence<Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "ReactiveSequence"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<Children> TickCost for ReactiveSequence<Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::reactive_sequence(Children::COSTS);
+}
+
 pub struct ReactiveSelector<Children> {
     children: Children,
 }
@@ -173,7 +281,7 @@ where
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
         let mut index = 0;
 
-        while index < Children::LEN {
+        while index < self.children.len() {
             match self.children.tick_at(index, ctx).await {
                 Status::Success => {
                     self.children.reset_range(index + 1);
@@ -198,31 +306,224 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+impl<Children> Inspect for ReactiveSelector<Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "ReactiveSelector"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<Children> TickCost for ReactiveSelector<Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::reactive_selector(Children::COSTS);
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 pub enum ParallelPolicy {
     #[default]
     SuccessOnAllFailureOnAny,
     SuccessOnAnyFailureOnAll,
     SuccessThreshold(usize),
+    Thresholds {
+        success: usize,
+        failure: usize,
+    },
+    Custom(fn(ParallelCounts) -> Status),
+    MainChild(usize),
+}
+
+impl PartialEq for ParallelPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (ParallelPolicy::Custom(a), ParallelPolicy::Custom(b)) => core::ptr::fn_addr_eq(a, b),
+            (ParallelPolicy::SuccessThreshold(a), ParallelPolicy::SuccessThreshold(b))
+            | (ParallelPolicy::MainChild(a), ParallelPolicy::MainChild(b)) => a == b,
+            (
+                ParallelPolicy::Thresholds {
+                    success: a_success,
+                    failure: a_failure,
+                },
+                ParallelPolicy::Thresholds {
+                    success: b_success,
+                    failure: b_failure,
+                },
+            ) => a_success == b_success && a_failure == b_failure,
+            (
+                ParallelPolicy::SuccessOnAllFailureOnAny,
+                ParallelPolicy::SuccessOnAllFailureOnAny,
+            )
+            | (
+                ParallelPolicy::SuccessOnAnyFailureOnAll,
+                ParallelPolicy::SuccessOnAnyFailureOnAll,
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ParallelPolicy {}
+
+impl ParallelPolicy {
+    pub fn validate(self, children: usize) -> Result<(), ParallelError> {
+        if children == 0 {
+            return Err(ParallelError::NoChildren);
+        }
+
+        let valid = |threshold: usize| (1..=children).contains(&threshold);
+
+        match self {
+            ParallelPolicy::SuccessOnAllFailureOnAny
+            | ParallelPolicy::SuccessOnAnyFailureOnAll
+            | ParallelPolicy::Custom(_) => Ok(()),
+            ParallelPolicy::SuccessThreshold(threshold)
+            | ParallelPolicy::Thresholds {
+                success: threshold, ..
+            } if !valid(threshold) => Err(ParallelError::InvalidSuccessThreshold {
+                threshold,
+                children,
+            }),
+            ParallelPolicy::Thresholds { failure, .. } if !valid(failure) => {
+                Err(ParallelError::InvalidFailureThreshold {
+                    threshold: failure,
+                    children,
+                })
+            }
+            ParallelPolicy::SuccessThreshold(_) | ParallelPolicy::Thresholds { .. } => Ok(()),
+            ParallelPolicy::MainChild(index) if index >= children => {
+                Err(ParallelError::InvalidMainChild { index, children })
+            }
+            ParallelPolicy::MainChild(_) => Ok(()),
+        }
+    }
+
+    fn decide(self, counts: ParallelCounts, main: Status) -> Status {
+        let n = counts.children;
+        match self {
+            ParallelPolicy::SuccessOnAllFailureOnAny => counts.against(n, 1),
+            ParallelPolicy::SuccessOnAnyFailureOnAll => counts.against(1, n),
+            ParallelPolicy::SuccessThreshold(success) => counts.against(success, n - success + 1),
+            ParallelPolicy::Thresholds { success, failure } => counts.against(success, failure),
+            ParallelPolicy::Custom(policy) => policy(counts),
+            ParallelPolicy::MainChild(_) => main,
+        }
+    }
+
+    const fn main_child(self) -> Option<usize> {
+        match self {
+            ParallelPolicy::MainChild(index) => Some(index),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParallelCounts {
+    pub successes: usize,
+    pub failures: usize,
+    pub running: usize,
+    pub children: usize,
+}
+
+impl ParallelCounts {
+    fn record(&mut self, status: Status) {
+        match status {
+            Status::Success => self.successes += 1,
+            Status::Failure => self.failures += 1,
+            Status::Running => self.running += 1,
+        }
+    }
+
+    fn against(self, success: usize, failure: usize) -> Status {
+        if self.successes >= success {
+            Status::Success
+        } else if self.failures >= failure || self.failures > self.children - success {
+            Status::Failure
+        } else {
+            Status::Running
+        }
+    }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParallelError {
+    NoChildren,
+    TooManyChildren { children: usize, max: usize },
+    InvalidSuccessThreshold { threshold: usize, children: usize },
+    InvalidFailureThreshold { threshold: usize, children: usize },
+    InvalidMainChild { index: usize, children: usize },
+}
+
+impl fmt::Display for ParallelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParallelError::NoChildren => f.write_str("parallel nodes require at least one child"),
+            ParallelError::TooManyChildren { children, max } => write!(
+                f,
+                "parallel node has {children} children but supports at most {max}"
+            ),
+            ParallelError::InvalidSuccessThreshold {
+                threshold,
+                children,
+            } => write!(
+                f,
+                "invalid success threshold {threshold} for parallel node with {children} children"
+            ),
+            ParallelError::InvalidFailureThreshold {
+                threshold,
+                children,
+            } => write!(
+                f,
+                "invalid failure threshold {threshold} for parallel node with {children} children"
+            ),
+            ParallelError::InvalidMainChild { index, children } => write!(
+                f,
+                "invalid main child {index} for parallel node with {children} children"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParallelError {}
+
 pub struct Parallel<Children> {
     children: Children,
     policy: ParallelPolicy,
 }
 
-impl<Children> Parallel<Children> {
+impl<Children> Parallel<Children>
+where
+    Children: NodeCount,
+{
+    // Tuples and arrays are checked at compile time; lists sized at runtime
+    // go through `with_policy`.
     pub const fn new(children: Children) -> Self {
+        const {
+            assert!(
+                Children::LEN > 0,
+                "Parallel::new needs a non-empty tuple or array; use with_policy for other lists"
+            );
+        }
         Self {
             children,
             policy: ParallelPolicy::SuccessOnAllFailureOnAny,
         }
     }
 
-    pub const fn with_policy(children: Children, policy: ParallelPolicy) -> Self {
-        Self { children, policy }
+    pub fn with_policy(children: Children, policy: ParallelPolicy) -> Result<Self, ParallelError> {
+        policy.validate(children.len())?;
+        Ok(Self { children, policy })
     }
+}
 
+impl<Children> Parallel<Children> {
     pub fn into_children(self) -> Children {
         self.children
     }
@@ -237,43 +538,273 @@ where
     Children: NodeList<Ctx>,
 {
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
-        let n = Children::LEN;
-        if n == 0 {
-            panic!("parallel nodes require at least one child");
-        }
-
-        let m = match self.policy {
-            ParallelPolicy::SuccessOnAllFailureOnAny => n,
-            ParallelPolicy::SuccessOnAnyFailureOnAll => 1,
-            ParallelPolicy::SuccessThreshold(threshold) => {
-                if threshold == 0 || threshold > n {
-                    panic!(
-                        "invalid success threshold {threshold} for parallel node with {n} children"
-                    );
-                }
-                threshold
-            }
+        let n = self.children.len();
+        let main_child = self.policy.main_child();
+        let mut main = Status::Running;
+        let mut counts = ParallelCounts {
+            children: n,
+            ..ParallelCounts::default()
         };
 
-        let mut successes = 0usize;
-        let mut failures = 0usize;
+        for index in 0..n {
+            let status = self.children.tick_at(index, ctx).await;
+            if main_child == Some(index) {
+                main = status;
+            }
+            counts.record(status);
+        }
+
+        let status = self.policy.decide(counts, main);
+        if status != Status::Running {
+            self.children.reset_all();
+        }
+
+        status
+    }
 
+    fn reset(&mut self) {
+        self.children.reset_all();
+    }
+}
+
+impl<Children> Inspect for Parallel<Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "Parallel"
+    }
+
+    fn params(&self) -> Option<Params> {
+        Some(Params::Parallel(self.policy))
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<Children> TickCost for Parallel<Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::parallel(Children::COSTS);
+}
+
+const MEMORY_PARALLEL_MAX_CHILDREN: usize = u64::BITS as usize;
+
+pub struct ParallelWithMemory<Children> {
+    children: Children,
+    policy: ParallelPolicy,
+    completed: u64,
+    succeeded: u64,
+}
+
+impl<Children> ParallelWithMemory<Children>
+where
+    Children: NodeCount,
+{
+    // Tuples and arrays are checked at compile time; lists sized at runtime
+    // go through `with_policy`.
+    pub const fn new(children: Children) -> Self {
+        const {
+            assert!(
+                Children::LEN > 0 && Children::LEN <= MEMORY_PARALLEL_MAX_CHILDREN,
+                "ParallelWithMemory::new needs a tuple or array of 1 to 64 children; use with_policy for other lists"
+            );
+        }
+        Self {
+            children,
+            policy: ParallelPolicy::SuccessOnAllFailureOnAny,
+            completed: 0,
+            succeeded: 0,
+        }
+    }
+
+    pub fn with_policy(children: Children, policy: ParallelPolicy) -> Result<Self, ParallelError> {
+        let n = children.len();
+        if n > MEMORY_PARALLEL_MAX_CHILDREN {
+            return Err(ParallelError::TooManyChildren {
+                children: n,
+                max: MEMORY_PARALLEL_MAX_CHILDREN,
+            });
+        }
+        policy.validate(n)?;
+
+        Ok(Self {
+            children,
+            policy,
+            completed: 0,
+            succeeded: 0,
+        })
+    }
+}
+
+impl<Children> ParallelWithMemory<Children> {
+    pub fn into_children(self) -> Children {
+        self.children
+    }
+
+    pub const fn policy(&self) -> ParallelPolicy {
+        self.policy
+    }
+
+    fn status_of(&self, index: usize) -> Status {
+        let bit = 1u64 << index;
+        if self.completed & bit == 0 {
+            Status::Running
+        } else if self.succeeded & bit != 0 {
+            Status::Success
+        } else {
+            Status::Failure
+        }
+    }
+}
+
+impl<Ctx, Children> Node<Ctx> for ParallelWithMemory<Children>
+where
+    Children: NodeList<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let n = self.children.len();
         for index in 0..n {
+            let bit = 1u64 << index;
+            if self.completed & bit != 0 {
+                continue;
+            }
+
             match self.children.tick_at(index, ctx).await {
-                Status::Success => successes += 1,
-                Status::Failure => failures += 1,
+                Status::Success => {
+                    self.completed |= bit;
+                    self.succeeded |= bit;
+                }
+                Status::Failure => self.completed |= bit,
                 Status::Running => {}
             }
         }
 
-        let status = if successes >= m {
-            Status::Success
-        } else if failures > n - m {
-            Status::Failure
-        } else {
-            Status::Running
+        let counts = ParallelCounts {
+            successes: self.succeeded.count_ones() as usize,
+            failures: (self.completed & !self.succeeded).count_ones() as usize,
+            running: n - self.completed.count_ones() as usize,
+            children: n,
+        };
+        let main = match self.policy.main_child() {
+            Some(index) => self.status_of(index),
+            None => Status::Running,
         };
 
+        // Once every child has finished, nothing is left to tick, so a
+        // custom policy that still waits would return `Running` forever.
+        // The built-in policies always decide by then; treat waiting as
+        // failure.
+        let mut status = self.policy.decide(counts, main);
+        if status == Status::Running && counts.running == 0 {
+            status = Status::Failure;
+        }
+        if status != Status::Running {
+            self.reset();
+        }
+
+        status
+    }
+
+    fn reset(&mut self) {
+        self.completed = 0;
+        self.succeeded = 0;
+        self.children.reset_all();
+    }
+}
+
+impl<Children> Inspect for ParallelWithMemory<Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "ParallelWithMemory"
+    }
+
+    fn params(&self) -> Option<Params> {
+        Some(Params::Parallel(self.policy))
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<Children> TickCost for ParallelWithMemory<Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::parallel_with_memory(Children::COSTS);
+}
+
+pub struct ConcurrentParallel<Split, Children> {
+    split: Split,
+    children: Children,
+    policy: ParallelPolicy,
+}
+
+impl<Split, Children> ConcurrentParallel<Split, Children> {
+    pub const fn new(split: Split, children: Children) -> Self {
+        Self {
+            split,
+            children,
+            policy: ParallelPolicy::SuccessOnAllFailureOnAny,
+        }
+    }
+
+    pub fn with_policy(
+        split: Split,
+        children: Children,
+        policy: ParallelPolicy,
+    ) -> Result<Self, ParallelError>
+    where
+        Children: NodeCount,
+    {
+        policy.validate(children.len())?;
+        Ok(Self {
+            split,
+            children,
+            policy,
+        })
+    }
+
+    pub fn into_parts(self) -> (Split, Children) {
+        (self.split, self.children)
+    }
+
+    pub const fn policy(&self) -> ParallelPolicy {
+        self.policy
+    }
+}
+
+impl<Ctx, Split, Children> Node<Ctx> for ConcurrentParallel<Split, Children>
+where
+    Split: SplitContext<Ctx>,
+    Children: for<'a> ConcurrentNodeList<Split::Parts<'a>>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let n = self.children.len();
+        let main_child = self.policy.main_child();
+        let mut main = Status::Running;
+        let mut counts = ParallelCounts {
+            children: n,
+            ..ParallelCounts::default()
+        };
+
+        let parts = self.split.split(ctx);
+        self.children
+            .tick_concurrent(parts, |index, status| {
+                if main_child == Some(index) {
+                    main = status;
+                }
+                counts.record(status);
+            })
+            .await;
+
+        let status = self.policy.decide(counts, main);
         if status != Status::Running {
             self.children.reset_all();
         }
@@ -285,3 +816,320 @@ where
         self.children.reset_all();
     }
 }
+
+impl<Split, Children> Inspect for ConcurrentParallel<Split, Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "ConcurrentParallel"
+    }
+
+    fn params(&self) -> Option<Params> {
+        Some(Params::Parallel(self.policy))
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<Split, Children> TickCost for ConcurrentParallel<Split, Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::parallel(Children::COSTS);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Branch {
+    Condition,
+    Then,
+    Else,
+}
+
+pub struct IfThenElse<Cond, Then, Else> {
+    condition: Cond,
+    then_branch: Then,
+    else_branch: Else,
+    branch: Branch,
+}
+
+impl<Cond, Then, Else> IfThenElse<Cond, Then, Else> {
+    pub const fn new(condition: Cond, then_branch: Then, else_branch: Else) -> Self {
+        Self {
+            condition,
+            then_branch,
+            else_branch,
+            branch: Branch::Condition,
+        }
+    }
+
+    pub fn into_children(self) -> (Cond, Then, Else) {
+        (self.condition, self.then_branch, self.else_branch)
+    }
+}
+
+impl<Ctx, Cond, Then, Else> Node<Ctx> for IfThenElse<Cond, Then, Else>
+where
+    Cond: Node<Ctx>,
+    Then: Node<Ctx>,
+    Else: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        if self.branch == Branch::Condition {
+            self.branch = match self.condition.tick(ctx).await {
+                Status::Success => Branch::Then,
+                Status::Failure => Branch::Else,
+                Status::Running => return Status::Running,
+            };
+        }
+
+        let status = if self.branch == Branch::Then {
+            self.then_branch.tick(ctx).await
+        } else {
+            self.else_branch.tick(ctx).await
+        };
+
+        if status != Status::Running {
+            self.reset();
+        }
+
+        status
+    }
+
+    fn reset(&mut self) {
+        self.branch = Branch::Condition;
+        self.condition.reset();
+        self.then_branch.reset();
+        self.else_branch.reset();
+    }
+}
+
+impl<Cond, Then, Else> Inspect for IfThenElse<Cond, Then, Else>
+where
+    Cond: Inspect,
+    Then: Inspect,
+    Else: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "IfThenElse"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.condition);
+        visit(&self.then_branch);
+        visit(&self.else_branch);
+    }
+}
+
+impl<Cond, Then, Else> TickCost for IfThenElse<Cond, Then, Else>
+where
+    Cond: TickCost,
+    Then: TickCost,
+    Else: TickCost,
+{
+    const COST: Cost = Cost::if_then_else(Cond::COST, Then::COST, Else::COST);
+}
+
+pub struct WhileDoElse<Cond, Do, Else> {
+    condition: Cond,
+    do_branch: Do,
+    else_branch: Else,
+}
+
+impl<Cond, Do, Else> WhileDoElse<Cond, Do, Else> {
+    pub const fn new(condition: Cond, do_branch: Do, else_branch: Else) -> Self {
+        Self {
+            condition,
+            do_branch,
+            else_branch,
+        }
+    }
+
+    pub fn into_children(self) -> (Cond, Do, Else) {
+        (self.condition, self.do_branch, self.else_branch)
+    }
+}
+
+impl<Ctx, Cond, Do, Else> Node<Ctx> for WhileDoElse<Cond, Do, Else>
+where
+    Cond: Node<Ctx>,
+    Do: Node<Ctx>,
+    Else: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let status = match self.condition.tick(ctx).await {
+            Status::Success => {
+                self.else_branch.reset();
+                self.do_branch.tick(ctx).await
+            }
+            Status::Failure => {
+                self.do_branch.reset();
+                self.else_branch.tick(ctx).await
+            }
+            Status::Running => return Status::Running,
+        };
+
+        if status != Status::Running {
+            self.reset();
+        }
+
+        status
+    }
+
+    fn reset(&mut self) {
+        self.condition.reset();
+        self.do_branch.reset();
+        self.else_branch.reset();
+    }
+}
+
+impl<Cond, Do, Else> Inspect for WhileDoElse<Cond, Do, Else>
+where
+    Cond: Inspect,
+    Do: Inspect,
+    Else: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "WhileDoElse"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.condition);
+        visit(&self.do_branch);
+        visit(&self.else_branch);
+    }
+}
+
+impl<Cond, Do, Else> TickCost for WhileDoElse<Cond, Do, Else>
+where
+    Cond: TickCost,
+    Do: TickCost,
+    Else: TickCost,
+{
+    const COST: Cost = Cost::while_do_else(Cond::COST, Do::COST, Else::COST);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchError {
+    // Every case needs a child, plus one for the default.
+    ChildCount { cases: usize, children: usize },
+}
+
+impl fmt::Display for SwitchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwitchError::ChildCount { cases, children } => write!(
+                f,
+                "switch node with {cases} cases needs {} children, found {children}",
+                cases + 1
+            ),
+        }
+    }
+}
+
+impl core::error::Error for SwitchError {}
+
+pub struct Switch<F, K, const N: usize, Children> {
+    key: F,
+    cases: [K; N],
+    children: Children,
+    active: Option<usize>,
+}
+
+impl<F, K, const N: usize, Children> Switch<F, K, N, Children>
+where
+    Children: NodeCount,
+{
+    // Tuples and arrays are checked at compile time; lists sized at runtime
+    // go through `try_new`.
+    pub const fn new(key: F, cases: [K; N], children: Children) -> Self {
+        const {
+            assert!(
+                Children::LEN == N + 1,
+                "Switch::new needs a tuple or array with one child per case plus a default; use try_new for other lists"
+            );
+        }
+        Self {
+            key,
+            cases,
+            children,
+            active: None,
+        }
+    }
+
+    pub fn try_new(key: F, cases: [K; N], children: Children) -> Result<Self, SwitchError> {
+        if children.len() != N + 1 {
+            return Err(SwitchError::ChildCount {
+                cases: N,
+                children: children.len(),
+            });
+        }
+        Ok(Self {
+            key,
+            cases,
+            children,
+            active: None,
+        })
+    }
+}
+
+impl<F, K, const N: usize, Children> Switch<F, K, N, Children> {
+    pub fn into_children(self) -> Children {
+        self.children
+    }
+}
+
+impl<Ctx, F, K, const N: usize, Children> Node<Ctx> for Switch<F, K, N, Children>
+where
+    F: FnMut(&Ctx) -> K,
+    K: PartialEq,
+    Children: NodeList<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let key = (self.key)(ctx);
+        let index = self.cases.iter().position(|case| *case == key).unwrap_or(N);
+
+        if let Some(active) = self.active
+            && active != index
+        {
+            self.children.reset_at(active);
+        }
+
+        let status = self.children.tick_at(index, ctx).await;
+        if status == Status::Running {
+            self.active = Some(index);
+        } else {
+            self.active = None;
+            self.children.reset_at(index);
+        }
+
+        status
+    }
+
+    fn reset(&mut self) {
+        self.active = None;
+        self.children.reset_all();
+    }
+}
+
+impl<F, K, const N: usize, Children> Inspect for Switch<F, K, N, Children>
+where
+    Children: InspectList,
+{
+    fn kind(&self) -> &'static str {
+        "Switch"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.children.inspect_each(visit);
+    }
+}
+
+impl<F, K, const N: usize, Children> TickCost for Switch<F, K, N, Children>
+where
+    Children: CostList,
+{
+    const COST: Cost = Cost::switch(Children::COSTS);
+}
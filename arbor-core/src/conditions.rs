@@ -0,0 +1,384 @@
+use crate::{Cost, Inspect, Node, Status, TickCost};
+
+pub trait Predicate<Ctx> {
+    fn test(&mut self, ctx: &Ctx) -> bool;
+}
+
+impl<Ctx, F> Predicate<Ctx> for F
+where
+    F: FnMut(&Ctx) -> bool,
+{
+    fn test(&mut self, ctx: &Ctx) -> bool {
+        self(ctx)
+    }
+}
+
+pub trait Callback<Ctx> {
+    fn call(&mut self, ctx: &mut Ctx);
+}
+
+impl<Ctx, F> Callback<Ctx> for F
+where
+    F: FnMut(&mut Ctx),
+{
+    fn call(&mut self, ctx: &mut Ctx) {
+        self(ctx)
+    }
+}
+
+pub struct And<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> And<A, B> {
+    pub const fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<Ctx, A, B> Predicate<Ctx> for And<A, B>
+where
+    A: Predicate<Ctx>,
+    B: Predicate<Ctx>,
+{
+    fn test(&mut self, ctx: &Ctx) -> bool {
+        self.left.test(ctx) && self.right.test(ctx)
+    }
+}
+
+pub struct Or<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A, B> Or<A, B> {
+    pub const fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<Ctx, A, B> Predicate<Ctx> for Or<A, B>
+where
+    A: Predicate<Ctx>,
+    B: Predicate<Ctx>,
+{
+    fn test(&mut self, ctx: &Ctx) -> bool {
+        self.left.test(ctx) || self.right.test(ctx)
+    }
+}
+
+pub struct Not<P> {
+    inner: P,
+}
+
+impl<P> Not<P> {
+    pub const fn new(inner: P) -> Self {
+        Self { inner }
+    }
+}
+
+impl<Ctx, P> Predicate<Ctx> for Not<P>
+where
+    P: Predicate<Ctx>,
+{
+    fn test(&mut self, ctx: &Ctx) -> bool {
+        !self.inner.test(ctx)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unset;
+
+impl<Ctx> Predicate<Ctx> for Unset {
+    fn test(&mut self, _ctx: &Ctx) -> bool {
+        false
+    }
+}
+
+impl<Ctx> Callback<Ctx> for Unset {
+    fn call(&mut self, _ctx: &mut Ctx) {}
+}
+
+pub struct Conditioned<
+    Child,
+    SkipIf = Unset,
+    SuccessIf = Unset,
+    FailureIf = Unset,
+    While = Unset,
+    OnSuccess = Unset,
+    OnFailure = Unset,
+    Post = Unset,
+> {
+    child: Child,
+    skip_if: Option<SkipIf>,
+    success_if: Option<SuccessIf>,
+    failure_if: Option<FailureIf>,
+    while_true: Option<While>,
+    on_success: Option<OnSuccess>,
+    on_failure: Option<OnFailure>,
+    post: Option<Post>,
+    skip_status: Status,
+    running: bool,
+    skipped: bool,
+}
+
+impl<Child> Conditioned<Child> {
+    pub const fn new(child: Child) -> Self {
+        Self {
+            child,
+            skip_if: None,
+            success_if: None,
+            failure_if: None,
+            while_true: None,
+            on_success: None,
+            on_failure: None,
+            post: None,
+            skip_status: Status::Failure,
+            running: false,
+            skipped: false,
+        }
+    }
+}
+
+impl<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post>
+    Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post>
+{
+    pub fn skip_if<P>(
+        self,
+        predicate: P,
+    ) -> Conditioned<Child, P, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post> {
+        Conditioned {
+            child: self.child,
+            skip_if: Some(predicate),
+            success_if: self.success_if,
+            failure_if: self.failure_if,
+            while_true: self.while_true,
+            on_success: self.on_success,
+            on_failure: self.on_failure,
+            post: self.post,
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub fn success_if<P>(
+        self,
+        predicate: P,
+    ) -> Conditioned<Child, SkipIf, P, FailureIf, While, OnSuccess, OnFailure, Post> {
+        Conditioned {
+            child: self.child,
+            skip_if: self.skip_if,
+            success_if: Some(predicate),
+            failure_if: self.failure_if,
+            while_true: self.while_true,
+            on_success: self.on_success,
+            on_failure: self.on_failure,
+            post: self.post,
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub fn failure_if<P>(
+        self,
+        predicate: P,
+    ) -> Conditioned<Child, SkipIf, SuccessIf, P, While, OnSuccess, OnFailure, Post> {
+        Conditioned {
+            child: self.child,
+            skip_if: self.skip_if,
+            success_if: self.success_if,
+            failure_if: Some(predicate),
+            while_true: self.while_true,
+            on_success: self.on_success,
+            on_failure: self.on_failure,
+            post: self.post,
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub fn while_true<P>(
+        self,
+        predicate: P,
+    ) -> Conditioned<Child, SkipIf, SuccessIf, FailureIf, P, OnSuccess, OnFailure, Post> {
+        Conditioned {
+            child: self.child,
+            skip_if: self.skip_if,
+            success_if: self.success_if,
+            failure_if: self.failure_if,
+            while_true: Some(predicate),
+            on_success: self.on_success,
+            on_failure: self.on_failure,
+            post: self.post,
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub fn on_success<P>(
+        self,
+        callback: P,
+    ) -> Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, P, OnFailure, Post> {
+        Conditioned {
+            child: self.child,
+            skip_if: self.skip_if,
+            success_if: self.success_if,
+            failure_if: self.failure_if,
+            while_true: self.while_true,
+            on_success: Some(callback),
+            on_failure: self.on_failure,
+            post: self.post,
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub fn on_failure<P>(
+        self,
+        callback: P,
+    ) -> Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, P, Post> {
+        Conditioned {
+            child: self.child,
+            skip_if: self.skip_if,
+            success_if: self.success_if,
+            failure_if: self.failure_if,
+            while_true: self.while_true,
+            on_success: self.on_success,
+            on_failure: Some(callback),
+            post: self.post,
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub fn post<P>(
+        self,
+        callback: P,
+    ) -> Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, P> {
+        Conditioned {
+            child: self.child,
+            skip_if: self.skip_if,
+            success_if: self.success_if,
+            failure_if: self.failure_if,
+            while_true: self.while_true,
+            on_success: self.on_success,
+            on_failure: self.on_failure,
+            post: Some(callback),
+            skip_status: self.skip_status,
+            running: self.running,
+            skipped: self.skipped,
+        }
+    }
+
+    pub const fn skip_status(mut self, status: Status) -> Self {
+        self.skip_status = status;
+        self
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post> Node<Ctx>
+    for Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post>
+where
+    Child: Node<Ctx>,
+    SkipIf: Predicate<Ctx>,
+    SuccessIf: Predicate<Ctx>,
+    FailureIf: Predicate<Ctx>,
+    While: Predicate<Ctx>,
+    OnSuccess: Callback<Ctx>,
+    OnFailure: Callback<Ctx>,
+    Post: Callback<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.skipped = false;
+
+        // BT.CPP order: _failureIf, _successIf and _skipIf gate starting the
+        // child; _while is also re-checked while the child is running.
+        let status = if !self.running && holds(&mut self.failure_if, ctx) {
+            Status::Failure
+        } else if !self.running && holds(&mut self.success_if, ctx) {
+            Status::Success
+        } else if (!self.running && holds(&mut self.skip_if, ctx))
+            || self.while_true.as_mut().is_some_and(|p| !p.test(ctx))
+        {
+            if self.running {
+                self.running = false;
+                self.child.reset();
+            }
+            self.skipped = true;
+            return self.skip_status;
+        } else {
+            match self.child.tick(ctx).await {
+                Status::Running => {
+                    self.running = true;
+                    return Status::Running;
+                }
+                status => {
+                    self.running = false;
+                    self.child.reset();
+                    status
+                }
+            }
+        };
+
+        match status {
+            Status::Success => run(&mut self.on_success, ctx),
+            Status::Failure => run(&mut self.on_failure, ctx),
+            Status::Running => {}
+        }
+        run(&mut self.post, ctx);
+
+        status
+    }
+
+    fn reset(&mut self) {
+        self.running = false;
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.skipped
+    }
+}
+
+impl<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post> Inspect
+    for Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Conditioned"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post> TickCost
+    for Conditioned<Child, SkipIf, SuccessIf, FailureIf, While, OnSuccess, OnFailure, Post>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::gated(Child::COST);
+}
+
+fn holds<Ctx, P: Predicate<Ctx>>(predicate: &mut Option<P>, ctx: &Ctx) -> bool {
+    predicate.as_mut().is_some_and(|p| p.test(ctx))
+}
+
+fn run<Ctx, C: Callback<Ctx>>(callback: &mut Option<C>, ctx: &mut Ctx) {
+    if let Some(callback) = callback {
+        callback.call(ctx);
+    }
+}
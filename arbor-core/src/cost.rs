@@ -0,0 +1,560 @@
+use crate::Status;
+
+// Inclusive bounds on a weighted count of leaf ticks or leaf resets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Span {
+    pub const ZERO: Span = Span::exactly(0);
+
+    pub const fn new(min: u64, max: u64) -> Self {
+        Self { min, max }
+    }
+
+    pub const fn exactly(count: u64) -> Self {
+        Self::new(count, count)
+    }
+
+    const fn plus(self, other: Span) -> Span {
+        Span::new(
+            self.min.saturating_add(other.min),
+            self.max.saturating_add(other.max),
+        )
+    }
+
+    const fn join(self, other: Span) -> Span {
+        Span::new(
+            if self.min < other.min {
+                self.min
+            } else {
+                other.min
+            },
+            if self.max > other.max {
+                self.max
+            } else {
+                other.max
+            },
+        )
+    }
+
+    const fn times(self, weight: u64) -> Span {
+        Span::new(
+            self.min.saturating_mul(weight),
+            self.max.saturating_mul(weight),
+        )
+    }
+}
+
+// What one tick of a node can cost when it returns a given status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Effort {
+    pub ticks: Span,
+    pub resets: Span,
+}
+
+impl Effort {
+    pub const NONE: Effort = Effort::resetting(Span::ZERO);
+
+    const fn resetting(resets: Span) -> Self {
+        Self {
+            ticks: Span::ZERO,
+            resets,
+        }
+    }
+
+    const fn plus(self, other: Effort) -> Effort {
+        Effort {
+            ticks: self.ticks.plus(other.ticks),
+            resets: self.resets.plus(other.resets),
+        }
+    }
+
+    const fn join(self, other: Effort) -> Effort {
+        Effort {
+            ticks: self.ticks.join(other.ticks),
+            resets: self.resets.join(other.resets),
+        }
+    }
+
+    const fn then_reset(self, resets: Span) -> Effort {
+        self.plus(Effort::resetting(resets))
+    }
+}
+
+// Per-tick bounds for a subtree, kept separately for each status it can
+// return so that composites only add up the paths their children can
+// actually take. `reset` bounds the leaf resets that resetting the subtree
+// from outside causes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cost {
+    success: Option<Effort>,
+    failure: Option<Effort>,
+    running: Option<Effort>,
+    reset: Span,
+}
+
+// Implemented by static trees. Values are built from the types alone, so
+// runtime parameters such as retry counts, parallel policies, sequence memory
+// and `Constant` statuses are treated as unknown.
+pub trait TickCost {
+    const COST: Cost;
+}
+
+pub trait CostList {
+    const COSTS: &'static [Cost];
+}
+
+pub const fn cost_of<T: TickCost>(_: &T) -> Cost {
+    T::COST
+}
+
+impl Cost {
+    const NEVER: Cost = Cost {
+        success: None,
+        failure: None,
+        running: None,
+        reset: Span::ZERO,
+    };
+
+    pub const LEAF: Cost = Cost::leaf(1, 1);
+    pub const CONDITION: Cost = Cost::condition(1, 1);
+
+    pub const fn leaf(tick: u64, reset: u64) -> Self {
+        let effort = Effort {
+            ticks: Span::exactly(tick),
+            resets: Span::ZERO,
+        };
+        Cost {
+            success: Some(effort),
+            failure: Some(effort),
+            running: Some(effort),
+            reset: Span::exactly(reset),
+        }
+    }
+
+    // A leaf that answers within the tick and never returns `Running`.
+    pub const fn condition(tick: u64, reset: u64) -> Self {
+        Cost {
+            running: None,
+            ..Cost::leaf(tick, reset)
+        }
+    }
+
+    pub const fn outcome(&self, status: Status) -> Option<Effort> {
+        match status {
+            Status::Success => self.success,
+            Status::Failure => self.failure,
+            Status::Running => self.running,
+        }
+    }
+
+    pub const fn returns(&self, status: Status) -> bool {
+        self.outcome(status).is_some()
+    }
+
+    pub const fn ticks(&self) -> Span {
+        match self.any() {
+            Some(effort) => effort.ticks,
+            None => Span::ZERO,
+        }
+    }
+
+    pub const fn resets(&self) -> Span {
+        match self.any() {
+            Some(effort) => effort.resets,
+            None => Span::ZERO,
+        }
+    }
+
+    pub const fn reset(&self) -> Span {
+        self.reset
+    }
+
+    // Multiplies every leaf tick by `tick` and every leaf reset by `reset`.
+    pub const fn weighted(self, tick: u64, reset: u64) -> Self {
+        Cost {
+            success: weigh(self.success, tick, reset),
+            failure: weigh(self.failure, tick, reset),
+            running: weigh(self.running, tick, reset),
+            reset: self.reset.times(reset),
+        }
+    }
+
+    pub const fn sequence(children: &[Cost]) -> Self {
+        ordered(children, Status::Success, false)
+    }
+
+    pub const fn selector(children: &[Cost]) -> Self {
+        ordered(children, Status::Failure, false)
+    }
+
+    pub const fn reactive_sequence(children: &[Cost]) -> Self {
+        ordered(children, Status::Success, true)
+    }
+
+    pub const fn reactive_selector(children: &[Cost]) -> Self {
+        ordered(children, Status::Failure, true)
+    }
+
+    pub const fn parallel(children: &[Cost]) -> Self {
+        concurrent(children, false)
+    }
+
+    // Children that finished on an earlier tick are skipped, so each one may
+    // contribute nothing.
+    pub const fn parallel_with_memory(children: &[Cost]) -> Self {
+        concurrent(children, true)
+    }
+
+    pub const fn if_then_else(condition: Cost, then_branch: Cost, else_branch: Cost) -> Self {
+        let all = condition
+            .reset
+            .plus(then_branch.reset)
+            .plus(else_branch.reset);
+        let mut cost = Cost {
+            reset: all,
+            ..Cost::NEVER
+        };
+        cost = cost.add(Status::Running, condition.running);
+        cost = cost.branch(condition.success, then_branch, all);
+        cost = cost.branch(condition.failure, else_branch, all);
+
+        // A running branch is resumed without re-checking the condition.
+        if condition.success.is_some() && then_branch.running.is_some() {
+            cost = cost.branch(Some(Effort::NONE), then_branch, all);
+        }
+        if condition.failure.is_some() && else_branch.running.is_some() {
+            cost = cost.branch(Some(Effort::NONE), else_branch, all);
+        }
+        cost
+    }
+
+    pub const fn while_do_else(condition: Cost, do_branch: Cost, else_branch: Cost) -> Self {
+        let all = condition
+            .reset
+            .plus(do_branch.reset)
+            .plus(else_branch.reset);
+        let mut cost = Cost {
+            reset: all,
+            ..Cost::NEVER
+        };
+        cost = cost.add(Status::Running, condition.running);
+        cost = cost.branch(
+            then_reset(condition.success, else_branch.reset),
+            do_branch,
+            all,
+        );
+        cost = cost.branch(
+            then_reset(condition.failure, do_branch.reset),
+            else_branch,
+            all,
+        );
+        cost
+    }
+
+    pub const fn switch(children: &[Cost]) -> Self {
+        let mut cost = Cost {
+            reset: resets_from(children, 0),
+            ..Cost::NEVER
+        };
+        let mut index = 0;
+        while index < children.len() {
+            // Switching cases resets whichever other child was running.
+            let mut previous = 0;
+            let mut other = 0;
+            while other < children.len() {
+                if other != index
+                    && children[other].running.is_some()
+                    && children[other].reset.max > previous
+                {
+                    previous = children[other].reset.max;
+                }
+                other += 1;
+            }
+            let before = Effort::resetting(Span::new(0, previous));
+            cost = cost.branch(Some(before), children[index], children[index].reset);
+            index += 1;
+        }
+        cost
+    }
+
+    pub const fn inverter(child: Cost) -> Self {
+        let settled = child.settled();
+        Cost {
+            success: settled.failure,
+            failure: settled.success,
+            ..settled
+        }
+    }
+
+    pub const fn force_success(child: Cost) -> Self {
+        let settled = child.settled();
+        Cost {
+            success: join(settled.success, settled.failure),
+            failure: None,
+            ..settled
+        }
+    }
+
+    pub const fn force_failure(child: Cost) -> Self {
+        let settled = child.settled();
+        Cost {
+            success: None,
+            failure: join(settled.success, settled.failure),
+            ..settled
+        }
+    }
+
+    // Retries happen on later ticks: a failed attempt returns `Running`
+    // rather than ticking the child again, so one tick costs at most one
+    // attempt. A zero count fails at once.
+    pub const fn retry(child: Cost) -> Self {
+        let settled = child.settled();
+        Cost {
+            failure: join(settled.failure, Some(Effort::resetting(child.reset))),
+            running: join(settled.running, settled.failure),
+            ..settled
+        }
+    }
+
+    pub const fn repeat(child: Cost) -> Self {
+        let settled = child.settled();
+        Cost {
+            success: join(settled.success, Some(Effort::resetting(child.reset))),
+            running: join(settled.running, settled.success),
+            ..settled
+        }
+    }
+
+    // Fails without ticking the child once the deadline has passed, and may
+    // turn a running child into a failure.
+    pub const fn timeout(child: Cost) -> Self {
+        let settled = child.settled();
+        let expired = join(
+            Some(Effort::resetting(child.reset)),
+            then_reset(child.running, child.reset),
+        );
+        Cost {
+            failure: join(settled.failure, expired),
+            ..settled
+        }
+    }
+
+    pub const fn fresh(child: Cost) -> Self {
+        let settled = child.settled();
+        Cost {
+            failure: join(settled.failure, Some(Effort::resetting(child.reset))),
+            ..settled
+        }
+    }
+
+    // For decorators that may return any status without ticking the child,
+    // resetting it at most once.
+    pub const fn gated(child: Cost) -> Self {
+        let skip = Some(Effort::resetting(Span::new(0, child.reset.max)));
+        let settled = child.settled();
+        Cost {
+            success: join(settled.success, skip),
+            failure: join(settled.failure, skip),
+            running: join(settled.running, skip),
+            ..settled
+        }
+    }
+
+    // For decorators that reset the child whenever it finishes.
+    pub const fn settled(self) -> Self {
+        Cost {
+            success: then_reset(self.success, self.reset),
+            failure: then_reset(self.failure, self.reset),
+            ..self
+        }
+    }
+
+    const fn any(&self) -> Option<Effort> {
+        join(join(self.success, self.failure), self.running)
+    }
+
+    const fn add(self, status: Status, effort: Option<Effort>) -> Self {
+        match status {
+            Status::Success => Cost {
+                success: join(self.success, effort),
+                ..self
+            },
+            Status::Failure => Cost {
+                failure: join(self.failure, effort),
+                ..self
+            },
+            Status::Running => Cost {
+                running: join(self.running, effort),
+                ..self
+            },
+        }
+    }
+
+    // Adds `body` ticked after `before`; a finished body costs `finish`
+    // resets on top.
+    const fn branch(self, before: Option<Effort>, body: Cost, finish: Span) -> Self {
+        let Some(before) = before else {
+            return self;
+        };
+        self.add(
+            Status::Success,
+            then_reset(plus(before, body.success), finish),
+        )
+        .add(
+            Status::Failure,
+            then_reset(plus(before, body.failure), finish),
+        )
+        .add(Status::Running, plus(before, body.running))
+    }
+}
+
+const fn join(a: Option<Effort>, b: Option<Effort>) -> Option<Effort> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.join(b)),
+        (Some(effort), None) | (None, Some(effort)) => Some(effort),
+        (None, None) => None,
+    }
+}
+
+const fn plus(before: Effort, effort: Option<Effort>) -> Option<Effort> {
+    match effort {
+        Some(effort) => Some(before.plus(effort)),
+        None => None,
+    }
+}
+
+const fn then_reset(effort: Option<Effort>, resets: Span) -> Option<Effort> {
+    match effort {
+        Some(effort) => Some(effort.then_reset(resets)),
+        None => None,
+    }
+}
+
+const fn weigh(effort: Option<Effort>, tick: u64, reset: u64) -> Option<Effort> {
+    match effort {
+        Some(effort) => Some(Effort {
+            ticks: effort.ticks.times(tick),
+            resets: effort.resets.times(reset),
+        }),
+        None => None,
+    }
+}
+
+const fn resets_from(children: &[Cost], start: usize) -> Span {
+    let mut total = Span::ZERO;
+    let mut index = start;
+    while index < children.len() {
+        total = total.plus(children[index].reset);
+        index += 1;
+    }
+    total
+}
+
+const fn opposite(status: Status) -> Status {
+    match status {
+        Status::Success => Status::Failure,
+        _ => Status::Success,
+    }
+}
+
+// Sequences and selectors: children returning `next` hand over to the next
+// sibling, the other finished status stops the composite. Memory composites
+// resume at a running child, and sequences at a failed one with
+// `Memory::Persistent`, resetting either every child or only those from the
+// stopping one on.
+const fn ordered(children: &[Cost], next: Status, reactive: bool) -> Cost {
+    let stop = opposite(next);
+    let count = children.len();
+    let all = resets_from(children, 0);
+    let mut cost = Cost {
+        reset: if reactive || count == 0 {
+            all
+        } else {
+            Span::new(children[count - 1].reset.min, all.max)
+        },
+        ..Cost::NEVER
+    };
+    if count == 0 {
+        return cost.add(next, Some(Effort::NONE));
+    }
+
+    let mut start = 0;
+    while start < count {
+        let resumable = children[start].running.is_some()
+            || (matches!(stop, Status::Failure) && children[start].returns(stop));
+        if start == 0 || (!reactive && resumable) {
+            let mut prefix = Effort::NONE;
+            let mut index = start;
+            while index < count {
+                let child = children[index];
+                let (stopped, running) = if reactive {
+                    let rest = resets_from(children, index + 1);
+                    (rest, rest)
+                } else {
+                    (
+                        Span::new(resets_from(children, index).min, all.max),
+                        Span::ZERO,
+                    )
+                };
+                cost = cost
+                    .add(stop, then_reset(plus(prefix, child.outcome(stop)), stopped))
+                    .add(
+                        Status::Running,
+                        then_reset(plus(prefix, child.running), running),
+                    );
+                match child.outcome(next) {
+                    Some(effort) => prefix = prefix.plus(effort),
+                    None => break,
+                }
+                index += 1;
+            }
+            if index == count {
+                cost = cost.add(next, Some(prefix.then_reset(all)));
+            }
+        }
+        start += 1;
+    }
+    cost
+}
+
+// Every child is ticked once; the policy is a runtime value, so any status
+// may come out, and a finished parallel resets all children.
+const fn concurrent(children: &[Cost], memory: bool) -> Cost {
+    let all = resets_from(children, 0);
+    if children.is_empty() {
+        return Cost {
+            reset: all,
+            ..Cost::NEVER
+        };
+    }
+
+    let mut total = Effort::NONE;
+    let mut index = 0;
+    while index < children.len() {
+        let child = children[index];
+        let Some(mut effort) = child.any() else {
+            return Cost {
+                reset: all,
+                ..Cost::NEVER
+            };
+        };
+        if memory && (child.returns(Status::Success) || child.returns(Status::Failure)) {
+            effort.ticks.min = 0;
+            effort.resets.min = 0;
+        }
+        total = total.plus(effort);
+        index += 1;
+    }
+
+    let finished = Some(total.then_reset(all));
+    Cost {
+        success: finished,
+        failure: finished,
+        running: Some(total),
+        reset: all,
+    }
+}
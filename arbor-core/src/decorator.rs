@@ -1,6 +1,6 @@
 use core::time::Duration;
 
-use crate::{Clock, Node, Status};
+use crate::{Clock, Cost, Inspect, Node, NodeState, Params, Ports, Remap, Status, TickCost};
 
 pub struct Inverter<Child> {
     child: Child,
@@ -37,6 +37,30 @@ where
     fn reset(&mut self) {
         self.child.reset();
     }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for Inverter<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Inverter"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child> TickCost for Inverter<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::inverter(Child::COST);
 }
 
 pub struct Retry<Child> {
@@ -93,6 +117,36 @@ where
         self.failures = 0;
         self.child.reset();
     }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for Retry<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Retry"
+    }
+
+    fn params(&self) -> Option<Params> {
+        Some(Params::Retry {
+            max_failures: self.max_failures,
+        })
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child> TickCost for Retry<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::retry(Child::COST);
 }
 
 pub struct Repeat<Child> {
@@ -149,6 +203,36 @@ where
         self.successes = 0;
         self.child.reset();
     }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for Repeat<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Repeat"
+    }
+
+    fn params(&self) -> Option<Params> {
+        Some(Params::Repeat {
+            max_successes: self.max_successes,
+        })
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child> TickCost for Repeat<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::repeat(Child::COST);
 }
 
 pub struct Timeout<Child, Clk: Clock> {
@@ -156,6 +240,7 @@ pub struct Timeout<Child, Clk: Clock> {
     clock: Clk,
     duration: Duration,
     started_at: Option<Clk::Instant>,
+    expired: bool,
 }
 
 impl<Child, Clk> Timeout<Child, Clk>
@@ -168,6 +253,7 @@ where
             clock,
             duration,
             started_at: None,
+            expired: false,
         }
     }
 
@@ -182,10 +268,12 @@ where
     Clk: Clock,
 {
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.expired = false;
         if let Some(started_at) = self.started_at
             && self.clock.elapsed(started_at) >= self.duration
         {
             self.started_at = None;
+            self.expired = true;
             self.child.reset();
             return Status::Failure;
         }
@@ -226,6 +314,33 @@ where
         self.started_at = None;
         self.child.reset();
     }
+
+    // Expiring does not tick the child, so its skip is from an older tick.
+    fn skipped(&self) -> bool {
+        !self.expired && self.child.skipped()
+    }
+}
+
+impl<Child, Clk> Inspect for Timeout<Child, Clk>
+where
+    Child: Inspect,
+    Clk: Clock,
+{
+    fn kind(&self) -> &'static str {
+        "Timeout"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child, Clk> TickCost for Timeout<Child, Clk>
+where
+    Child: TickCost,
+    Clk: Clock,
+{
+    const COST: Cost = Cost::timeout(Child::COST);
 }
 
 pub struct ForceSuccess<Child> {
@@ -259,6 +374,30 @@ where
     fn reset(&mut self) {
         self.child.reset();
     }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for ForceSuccess<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "ForceSuccess"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child> TickCost for ForceSuccess<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::force_success(Child::COST);
 }
 
 pub struct ForceFailure<Child> {
@@ -292,4 +431,494 @@ where
     fn reset(&mut self) {
         self.child.reset();
     }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for ForceFailure<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "ForceFailure"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child> TickCost for ForceFailure<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::force_failure(Child::COST);
+}
+
+pub struct Fresh<Child, Clk, F> {
+    child: Child,
+    clock: Clk,
+    max_age: Duration,
+    timestamp: F,
+    stale: bool,
+}
+
+impl<Child, Clk, F> Fresh<Child, Clk, F> {
+    pub const fn new(child: Child, clock: Clk, max_age: Duration, timestamp: F) -> Self {
+        Self {
+            child,
+            clock,
+            max_age,
+            timestamp,
+            stale: false,
+        }
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child, Clk, F> Node<Ctx> for Fresh<Child, Clk, F>
+where
+    Child: Node<Ctx>,
+    Clk: Clock,
+    F: FnMut(&Ctx) -> Option<Clk::Instant>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let fresh = match (self.timestamp)(ctx) {
+            Some(stamped_at) => self.clock.elapsed(stamped_at) <= self.max_age,
+            None => false,
+        };
+
+        self.stale = !fresh;
+        if self.stale {
+            self.child.reset();
+            return Status::Failure;
+        }
+
+        match self.child.tick(ctx).await {
+            Status::Running => Status::Running,
+            status => {
+                self.child.reset();
+                status
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        !self.stale && self.child.skipped()
+    }
+}
+
+impl<Child, Clk, F> Inspect for Fresh<Child, Clk, F>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Fresh"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Child, Clk, F> TickCost for Fresh<Child, Clk, F>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::fresh(Child::COST);
+}
+
+pub struct Named<Child> {
+    child: Child,
+    name: &'static str,
+}
+
+impl<Child> Named<Child> {
+    pub const fn new(child: Child, name: &'static str) -> Self {
+        Self { child, name }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child> Node<Ctx> for Named<Child>
+where
+    Child: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.child.tick(ctx).await
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for Named<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        self.child.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        Some(self.name)
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.child.inspect_children(visit);
+    }
+    fn ports(&self) -> Option<&dyn Ports> {
+        self.child.ports()
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        self.child.scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.child.params()
+    }
+}
+
+impl<Child> TickCost for Named<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST;
+}
+
+// Lets a subtree written against `Inner` run inside a tree over `Outer`. The
+// lens is a plain `fn` so that it is higher-ranked over the borrow; a closure
+// returning a reborrow of its argument does not infer that signature.
+pub struct MapCtx<Outer, Inner, Child> {
+    lens: fn(&mut Outer) -> &mut Inner,
+    child: Child,
+}
+
+impl<Outer, Inner, Child> MapCtx<Outer, Inner, Child> {
+    pub const fn new(lens: fn(&mut Outer) -> &mut Inner, child: Child) -> Self {
+        Self { lens, child }
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Outer, Inner, Child> Node<Outer> for MapCtx<Outer, Inner, Child>
+where
+    Child: Node<Inner>,
+{
+    async fn tick(&mut self, ctx: &mut Outer) -> Status {
+        self.child.tick((self.lens)(ctx)).await
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Outer, Inner, Child> Inspect for MapCtx<Outer, Inner, Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "MapCtx"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<Outer, Inner, Child> TickCost for MapCtx<Outer, Inner, Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST;
+}
+
+pub struct Precondition<F, Child> {
+    predicate: F,
+    child: Child,
+    skip_status: Status,
+    running: bool,
+    skipped: bool,
+}
+
+impl<F, Child> Precondition<F, Child> {
+    pub const fn new(predicate: F, child: Child) -> Self {
+        Self::with_skip_status(predicate, child, Status::Failure)
+    }
+
+    pub const fn with_skip_status(predicate: F, child: Child, skip_status: Status) -> Self {
+        Self {
+            predicate,
+            child,
+            skip_status,
+            running: false,
+            skipped: false,
+        }
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, F, Child> Node<Ctx> for Precondition<F, Child>
+where
+    F: FnMut(&Ctx) -> bool,
+    Child: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        // Like BT.CPP preconditions, the predicate gates starting the child and
+        // is not re-checked while the child is running.
+        if !self.running && !(self.predicate)(ctx) {
+            self.skipped = true;
+            return self.skip_status;
+        }
+
+        self.skipped = false;
+        match self.child.tick(ctx).await {
+            Status::Running => {
+                self.running = true;
+                Status::Running
+            }
+            status => {
+                self.running = false;
+                self.child.reset();
+                status
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        // `skipped` describes the last tick, so parents that reset on
+        // completion do not hide it from observers.
+        self.running = false;
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.skipped
+    }
+}
+
+impl<F, Child> Inspect for Precondition<F, Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        "Precondition"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&self.child);
+    }
+}
+
+impl<F, Child> TickCost for Precondition<F, Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Cost::gated(Child::COST);
+}
+
+pub struct Tracked<Child, F = fn(NodeState, NodeState)> {
+    child: Child,
+    state: NodeState,
+    observer: F,
+}
+
+impl<Child> Tracked<Child> {
+    pub const fn new(child: Child) -> Self {
+        Self::with_observer(child, |_, _| {})
+    }
+}
+
+impl<Child, F> Tracked<Child, F> {
+    pub const fn with_observer(child: Child, observer: F) -> Self {
+        Self {
+            child,
+            state: NodeState::Idle,
+            observer,
+        }
+    }
+
+    pub const fn state(&self) -> NodeState {
+        self.state
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Child, F> Tracked<Child, F>
+where
+    F: FnMut(NodeState, NodeState),
+{
+    fn transition(&mut self, next: NodeState) {
+        let previous = self.state;
+        self.state = next;
+        if previous != next {
+            (self.observer)(previous, next);
+        }
+    }
+}
+
+impl<Ctx, Child, F> Node<Ctx> for Tracked<Child, F>
+where
+    Child: Node<Ctx>,
+    F: FnMut(NodeState, NodeState),
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let status = self.child.tick(ctx).await;
+        let state = if self.child.skipped() {
+            NodeState::Skipped
+        } else {
+            NodeState::from(status)
+        };
+        self.transition(state);
+        status
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+        self.transition(NodeState::Idle);
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child, F> Inspect for Tracked<Child, F>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        self.child.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.child.label()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.child.inspect_children(visit);
+    }
+    fn ports(&self) -> Option<&dyn Ports> {
+        self.child.ports()
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        self.child.scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.child.params()
+    }
+}
+
+impl<Child, F> TickCost for Tracked<Child, F>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST;
+}
+
+// Annotates a subtree with per-leaf costs for `TickCost`: every leaf tick
+// inside counts `TICK` and every leaf reset `RESET`. Otherwise transparent.
+pub struct Weighted<Child, const TICK: u64, const RESET: u64> {
+    child: Child,
+}
+
+impl<Child, const TICK: u64, const RESET: u64> Weighted<Child, TICK, RESET> {
+    pub const fn new(child: Child) -> Self {
+        Self { child }
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child, const TICK: u64, const RESET: u64> Node<Ctx> for Weighted<Child, TICK, RESET>
+where
+    Child: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.child.tick(ctx).await
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child, const TICK: u64, const RESET: u64> Inspect for Weighted<Child, TICK, RESET>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        self.child.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.child.label()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.child.inspect_children(visit);
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        self.child.ports()
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        self.child.scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.child.params()
+    }
+}
+
+impl<Child, const TICK: u64, const RESET: u64> TickCost for Weighted<Child, TICK, RESET>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST.weighted(TICK, RESET);
 }
@@ -0,0 +1,68 @@
+use core::time::Duration;
+
+use crate::{
+    Clock, Conditioned, ForceFailure, ForceSuccess, Inverter, MapCtx, Named, Observed, Port,
+    Repeat, Retry, Timeout, Tracked, Weighted, WithPorts,
+};
+
+// Not generic over `Ctx`: nodes such as `Constant` implement `Node<Ctx>` for
+// every context, and a `NodeExt<Ctx>` method call could not infer which one.
+// The wrappers only implement `Node<Ctx>` when the wrapped value does.
+pub trait NodeExt: Sized {
+    fn inverted(self) -> Inverter<Self> {
+        Inverter::new(self)
+    }
+
+    fn retry(self, max_failures: usize) -> Retry<Self> {
+        Retry::new(self, max_failures)
+    }
+
+    fn repeat(self, max_successes: usize) -> Repeat<Self> {
+        Repeat::new(self, max_successes)
+    }
+
+    fn timeout<Clk: Clock>(self, clock: Clk, duration: Duration) -> Timeout<Self, Clk> {
+        Timeout::new(self, clock, duration)
+    }
+
+    fn force_success(self) -> ForceSuccess<Self> {
+        ForceSuccess::new(self)
+    }
+
+    fn force_failure(self) -> ForceFailure<Self> {
+        ForceFailure::new(self)
+    }
+
+    fn named(self, name: &'static str) -> Named<Self> {
+        Named::new(self, name)
+    }
+
+    fn tracked(self) -> Tracked<Self> {
+        Tracked::new(self)
+    }
+
+    fn observed(self, node: u8) -> Observed<Self> {
+        Observed::new(self, node)
+    }
+
+    fn conditioned(self) -> Conditioned<Self> {
+        Conditioned::new(self)
+    }
+
+    fn map_ctx<Outer, Inner>(
+        self,
+        lens: fn(&mut Outer) -> &mut Inner,
+    ) -> MapCtx<Outer, Inner, Self> {
+        MapCtx::new(lens, self)
+    }
+
+    fn with_ports(self, ports: &'static [Port]) -> WithPorts<Self> {
+        WithPorts::new(self, ports)
+    }
+
+    fn weighted<const TICK: u64, const RESET: u64>(self) -> Weighted<Self, TICK, RESET> {
+        Weighted::new(self)
+    }
+}
+
+impl<N> NodeExt for N {}
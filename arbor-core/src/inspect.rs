@@ -0,0 +1,174 @@
+use core::fmt;
+
+use crate::{ParallelPolicy, Ports, Remap, Status};
+
+pub trait Inspect {
+    fn kind(&self) -> &'static str;
+
+    fn label(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn inspect_children(&self, _visit: &mut dyn FnMut(&dyn Inspect)) {}
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        None
+    }
+
+    // The blackboard scope a node enters before ticking its children.
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        None
+    }
+
+    fn params(&self) -> Option<Params> {
+        None
+    }
+}
+
+// Constructor arguments that decide what a node can return, exposed so
+// tools like `lint` can reason about a tree without ticking it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Params {
+    Constant(Status),
+    Retry { max_failures: usize },
+    Repeat { max_successes: usize },
+    Parallel(ParallelPolicy),
+}
+
+pub const MAX_PATH_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    Label(&'static str),
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Label(label) => f.write_str(label),
+            PathSegment::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodePath {
+    segments: [PathSegment; MAX_PATH_DEPTH],
+    depth: usize,
+}
+
+impl NodePath {
+    pub const fn new() -> Self {
+        Self {
+            segments: [PathSegment::Index(0); MAX_PATH_DEPTH],
+            depth: 0,
+        }
+    }
+
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments[..self.depth.min(MAX_PATH_DEPTH)]
+    }
+
+    pub const fn is_truncated(&self) -> bool {
+        self.depth > MAX_PATH_DEPTH
+    }
+
+    pub fn matches(&self, path: &str) -> bool {
+        if self.is_truncated() {
+            return false;
+        }
+
+        let mut parts = path.split('/');
+        let all_match = self.segments().iter().all(|segment| match parts.next() {
+            Some(part) => match segment {
+                PathSegment::Label(label) => *label == part,
+                PathSegment::Index(index) => part.parse() == Ok(*index),
+            },
+            None => false,
+        });
+        all_match && parts.next().is_none()
+    }
+
+    pub(crate) fn push(&mut self, segment: PathSegment) {
+        if self.depth < MAX_PATH_DEPTH {
+            self.segments[self.depth] = segment;
+        }
+        self.depth += 1;
+    }
+
+    fn pop(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl Default for NodePath {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.segments().iter().enumerate() {
+            if index > 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{segment}")?;
+        }
+        if self.is_truncated() {
+            f.write_str("/...")?;
+        }
+        Ok(())
+    }
+}
+
+// Each node contributes its label, or its index among its parent's children
+// when it has none; an unlabeled root is `root`.
+pub fn walk(root: &dyn Inspect, visit: &mut dyn FnMut(&NodePath, &dyn Inspect)) {
+    let mut path = NodePath::new();
+    path.push(
+        root.label()
+            .map_or(PathSegment::Label("root"), PathSegment::Label),
+    );
+    walk_from(root, &mut path, visit);
+}
+
+fn walk_from(
+    node: &dyn Inspect,
+    path: &mut NodePath,
+    visit: &mut dyn FnMut(&NodePath, &dyn Inspect),
+) {
+    visit(path, node);
+
+    let mut index = 0;
+    node.inspect_children(&mut |child| {
+        path.push(
+            child
+                .label()
+                .map_or(PathSegment::Index(index), PathSegment::Label),
+        );
+        walk_from(child, path, visit);
+        path.pop();
+        index += 1;
+    });
+}
+
+pub fn find(
+    root: &dyn Inspect,
+    path: &str,
+    visit: &mut dyn FnMut(&NodePath, &dyn Inspect),
+) -> bool {
+    let mut found = false;
+    walk(root, &mut |node_path, node| {
+        if !found && node_path.matches(path) {
+            found = true;
+            visit(node_path, node);
+        }
+    });
+    found
+}
@@ -1,6 +1,6 @@
-use core::future::Future;
+use core::{future::Future, time::Duration};
 
-use crate::{Node, Status};
+use crate::{And, Clock, Cost, Inspect, Node, Not, Or, Params, Predicate, Status, TickCost};
 
 pub struct Action<F> {
     f: F,
@@ -26,6 +26,16 @@ where
     }
 }
 
+impl<F> Inspect for Action<F> {
+    fn kind(&self) -> &'static str {
+        "Action"
+    }
+}
+
+impl<F> TickCost for Action<F> {
+    const COST: Cost = Cost::LEAF;
+}
+
 pub struct Condition<F> {
     predicate: F,
 }
@@ -38,19 +48,274 @@ impl<F> Condition<F> {
     pub fn into_inner(self) -> F {
         self.predicate
     }
+
+    pub fn and<G>(self, other: G) -> Condition<And<F, G>> {
+        Condition::new(And::new(self.predicate, other))
+    }
+
+    pub fn or<G>(self, other: G) -> Condition<Or<F, G>> {
+        Condition::new(Or::new(self.predicate, other))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Condition<Not<F>> {
+        Condition::new(Not::new(self.predicate))
+    }
+
+    fn evaluate<Ctx>(&mut self, ctx: &Ctx) -> bool
+    where
+        F: Predicate<Ctx>,
+    {
+        self.predicate.test(ctx)
+    }
+}
+
+impl<Ctx, F> Predicate<Ctx> for Condition<F>
+where
+    F: Predicate<Ctx>,
+{
+    fn test(&mut self, ctx: &Ctx) -> bool {
+        self.evaluate(ctx)
+    }
 }
 
 impl<Ctx, F> Node<Ctx> for Condition<F>
 where
-    F: FnMut(&Ctx) -> bool,
+    F: Predicate<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        status_from(self.evaluate(ctx))
+    }
+}
+
+impl<F> Inspect for Condition<F> {
+    fn kind(&self) -> &'static str {
+        "Condition"
+    }
+}
+
+impl<F> TickCost for Condition<F> {
+    const COST: Cost = Cost::CONDITION;
+}
+
+fn status_from(value: bool) -> Status {
+    if value {
+        Status::Success
+    } else {
+        Status::Failure
+    }
+}
+
+// Debounce and hysteresis state follows the sensor signal rather than the
+// node's execution, so `reset` keeps it: composites reset finished children
+// every cycle and clearing it there would defeat the filtering.
+pub struct Debounce<F> {
+    condition: Condition<F>,
+    stable_ticks: usize,
+    output: Option<bool>,
+    pending: usize,
+}
+
+impl<F> Debounce<F> {
+    pub const fn new(condition: Condition<F>, stable_ticks: usize) -> Self {
+        Self {
+            condition,
+            stable_ticks,
+            output: None,
+            pending: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> Condition<F> {
+        self.condition
+    }
+}
+
+impl<Ctx, F> Node<Ctx> for Debounce<F>
+where
+    F: Predicate<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let raw = self.condition.evaluate(ctx);
+
+        let output = match self.output {
+            Some(output) if output == raw => {
+                self.pending = 0;
+                output
+            }
+            Some(output) => {
+                self.pending += 1;
+                if self.pending >= self.stable_ticks {
+                    self.pending = 0;
+                    raw
+                } else {
+                    output
+                }
+            }
+            None => raw,
+        };
+
+        self.output = Some(output);
+        status_from(output)
+    }
+}
+
+impl<F> Inspect for Debounce<F> {
+    fn kind(&self) -> &'static str {
+        "Debounce"
+    }
+}
+
+impl<F> TickCost for Debounce<F> {
+    const COST: Cost = Cost::CONDITION;
+}
+
+pub struct DebounceFor<F, Clk: Clock> {
+    condition: Condition<F>,
+    clock: Clk,
+    hold: Duration,
+    output: Option<bool>,
+    changed_at: Option<Clk::Instant>,
+}
+
+impl<F, Clk> DebounceFor<F, Clk>
+where
+    Clk: Clock,
+{
+    pub const fn new(condition: Condition<F>, clock: Clk, hold: Duration) -> Self {
+        Self {
+            condition,
+            clock,
+            hold,
+            output: None,
+            changed_at: None,
+        }
+    }
+
+    pub fn into_inner(self) -> Condition<F> {
+        self.condition
+    }
+}
+
+impl<Ctx, F, Clk> Node<Ctx> for DebounceFor<F, Clk>
+where
+    F: Predicate<Ctx>,
+    Clk: Clock,
 {
     async fn tick(&mut self, ctx: &mut Ctx) -> Status {
-        if (self.predicate)(ctx) {
-            Status::Success
-        } else {
-            Status::Failure
+        let raw = self.condition.evaluate(ctx);
+
+        let output = match self.output {
+            Some(output) if output == raw => {
+                self.changed_at = None;
+                output
+            }
+            Some(output) => {
+                let changed_at = match self.changed_at {
+                    Some(changed_at) => changed_at,
+                    None => {
+                        let now = self.clock.now();
+                        self.changed_at = Some(now);
+                        now
+                    }
+                };
+
+                if self.clock.elapsed(changed_at) >= self.hold {
+                    self.changed_at = None;
+                    raw
+                } else {
+                    output
+                }
+            }
+            None => raw,
+        };
+
+        self.output = Some(output);
+        status_from(output)
+    }
+}
+
+impl<F, Clk> Inspect for DebounceFor<F, Clk>
+where
+    Clk: Clock,
+{
+    fn kind(&self) -> &'static str {
+        "DebounceFor"
+    }
+}
+
+impl<F, Clk> TickCost for DebounceFor<F, Clk>
+where
+    Clk: Clock,
+{
+    const COST: Cost = Cost::CONDITION;
+}
+
+pub struct Hysteresis<F, T> {
+    value: F,
+    on: T,
+    off: T,
+    rising: bool,
+    active: bool,
+}
+
+impl<F, T> Hysteresis<F, T> {
+    pub const fn rising(value: F, on_above: T, off_below: T) -> Self {
+        Self {
+            value,
+            on: on_above,
+            off: off_below,
+            rising: true,
+            active: false,
+        }
+    }
+
+    pub const fn falling(value: F, on_below: T, off_above: T) -> Self {
+        Self {
+            value,
+            on: on_below,
+            off: off_above,
+            rising: false,
+            active: false,
         }
     }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn into_inner(self) -> F {
+        self.value
+    }
+}
+
+impl<Ctx, F, T> Node<Ctx> for Hysteresis<F, T>
+where
+    F: FnMut(&Ctx) -> T,
+    T: PartialOrd,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let value = (self.value)(ctx);
+
+        self.active = match (self.active, self.rising) {
+            (false, true) => value > self.on,
+            (true, true) => value >= self.off,
+            (false, false) => value < self.on,
+            (true, false) => value <= self.off,
+        };
+
+        status_from(self.active)
+    }
+}
+
+impl<F, T> Inspect for Hysteresis<F, T> {
+    fn kind(&self) -> &'static str {
+        "Hysteresis"
+    }
+}
+
+impl<F, T> TickCost for Hysteresis<F, T> {
+    const COST: Cost = Cost::CONDITION;
 }
 
 pub struct Constant {
@@ -68,3 +333,17 @@ impl<Ctx> Node<Ctx> for Constant {
         self.status
     }
 }
+
+impl Inspect for Constant {
+    fn kind(&self) -> &'static str {
+        "Constant"
+    }
+
+    fn params(&self) -> Option<Params> {
+        Some(Params::Constant(self.status))
+    }
+}
+
+impl TickCost for Constant {
+    const COST: Cost = Cost::LEAF;
+}
@@ -1,19 +1,55 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod blackboard;
 mod clock;
 mod composite;
+mod conditions;
+mod cost;
 mod decorator;
+mod ext;
+mod inspect;
 mod leaf;
+mod lint;
 mod list;
+mod monitor;
 mod node;
+mod ports;
 mod status;
 
+#[cfg(feature = "alloc")]
+pub use blackboard::MapStorage;
+pub use blackboard::{
+    Blackboard, BlackboardContext, BlackboardError, CheckEntry, Entry, FixedStorage, Key,
+    MAX_SCOPE_DEPTH, Remap, Scope, SetEntry, Storage, Value, ValueKind,
+};
 pub use clock::Clock;
 pub use composite::{
-    Parallel, ParallelPolicy, ReactiveSelector, ReactiveSequence, Selector, Sequence,
+    ConcurrentParallel, IfThenElse, Memory, Parallel, ParallelCounts, ParallelError,
+    ParallelPolicy, ParallelWithMemory, ReactiveSelector, ReactiveSequence, Selector, Sequence,
+    Switch, SwitchError, WhileDoElse,
+};
+pub use conditions::{And, Callback, Conditioned, Not, Or, Predicate, Unset};
+pub use cost::{Cost, CostList, Effort, Span, TickCost, cost_of};
+pub use decorator::{
+    ForceFailure, ForceSuccess, Fresh, Inverter, MapCtx, Named, Precondition, Repeat, Retry,
+    Timeout, Tracked, Weighted,
+};
+pub use ext::NodeExt;
+pub use inspect::{Inspect, MAX_PATH_DEPTH, NodePath, Params, PathSegment, find, walk};
+pub use leaf::{Action, Condition, Constant, Debounce, DebounceFor, Hysteresis};
+#[cfg(feature = "alloc")]
+pub use lint::findings;
+pub use lint::{Finding, Severity, lint};
+pub use list::{ConcurrentNodeList, InspectList, NodeCount, NodeList, SplitContext};
+pub use monitor::{
+    Formula, MAX_OBSERVED, Monitor, MonitorContext, Monitored, Monitors, Observation, Observed,
+    Violation,
 };
-pub use decorator::{ForceFailure, ForceSuccess, Inverter, Repeat, Retry, Timeout};
-pub use leaf::{Action, Condition, Constant};
-pub use list::NodeList;
 pub use node::Node;
-pub use status::Status;
+#[cfg(feature = "alloc")]
+pub use ports::diagnostics;
+pub use ports::{Diagnostic, Port, PortDirection, Ports, WithPorts, validate};
+pub use status::{NodeState, Status};
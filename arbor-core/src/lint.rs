@@ -0,0 +1,205 @@
+use core::fmt;
+
+use crate::{Inspect, NodePath, ParallelError, Params, PathSegment, Status, walk};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Finding {
+    // A sequence child that never succeeds, or a selector child that never
+    // fails, keeps every later sibling from running.
+    UnreachableChildren {
+        path: NodePath,
+        after: PathSegment,
+        never: Status,
+    },
+    RetryNeverTicks {
+        path: NodePath,
+    },
+    RepeatNeverTicks {
+        path: NodePath,
+    },
+    InvalidParallelPolicy {
+        path: NodePath,
+        error: ParallelError,
+    },
+    DoubleInverter {
+        path: NodePath,
+    },
+    // An action before the last child of a reactive composite runs again on
+    // every tick while a later sibling is running.
+    ReactiveAction {
+        path: NodePath,
+    },
+}
+
+impl Finding {
+    pub const fn path(&self) -> &NodePath {
+        match self {
+            Finding::UnreachableChildren { path, .. }
+            | Finding::RetryNeverTicks { path }
+            | Finding::RepeatNeverTicks { path }
+            | Finding::InvalidParallelPolicy { path, .. }
+            | Finding::DoubleInverter { path }
+            | Finding::ReactiveAction { path } => path,
+        }
+    }
+
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Finding::RetryNeverTicks { .. }
+            | Finding::RepeatNeverTicks { .. }
+            | Finding::InvalidParallelPolicy { .. } => Severity::Error,
+            Finding::UnreachableChildren { .. }
+            | Finding::DoubleInverter { .. }
+            | Finding::ReactiveAction { .. } => Severity::Warning,
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: ", self.severity(), self.path())?;
+        match self {
+            Finding::UnreachableChildren { after, never, .. } => {
+                let outcome = match never {
+                    Status::Success => "succeeds",
+                    Status::Failure => "fails",
+                    Status::Running => "runs",
+                };
+                write!(
+                    f,
+                    "children after `{after}` never run because it never {outcome}"
+                )
+            }
+            Finding::RetryNeverTicks { .. } => {
+                f.write_str("retry with zero attempts fails without ticking its child")
+            }
+            Finding::RepeatNeverTicks { .. } => {
+                f.write_str("repeat with zero successes succeeds without ticking its child")
+            }
+            Finding::InvalidParallelPolicy { error, .. } => write!(f, "{error}"),
+            Finding::DoubleInverter { .. } => f.write_str("inverter directly wraps an inverter"),
+            Finding::ReactiveAction { .. } => {
+                f.write_str("action is ticked again on every tick while a later sibling is running")
+            }
+        }
+    }
+}
+
+// Reports structural problems visible through `Inspect` alone, like
+// `validate` does for ports. Transparent wrappers such as `Named` forward
+// `kind` and `params`, so they do not hide what they wrap.
+pub fn lint(root: &dyn Inspect, report: &mut dyn FnMut(&Finding)) -> usize {
+    let mut count = 0;
+    let mut emit = |finding: Finding| {
+        count += 1;
+        report(&finding);
+    };
+
+    walk(root, &mut |path, node| {
+        let path = *path;
+        match (node.kind(), node.params()) {
+            ("Retry", Some(Params::Retry { max_failures: 0 })) => {
+                emit(Finding::RetryNeverTicks { path });
+            }
+            ("Repeat", Some(Params::Repeat { max_successes: 0 })) => {
+                emit(Finding::RepeatNeverTicks { path });
+            }
+            (_, Some(Params::Parallel(policy))) => {
+                if let Err(error) = policy.validate(child_count(node)) {
+                    emit(Finding::InvalidParallelPolicy { path, error });
+                }
+            }
+            ("Inverter", _) => {
+                let mut doubled = false;
+                node.inspect_children(&mut |child| doubled |= child.kind() == "Inverter");
+                if doubled {
+                    emit(Finding::DoubleInverter { path });
+                }
+            }
+            (kind @ ("Sequence" | "Selector" | "ReactiveSequence" | "ReactiveSelector"), _) => {
+                let needs = if kind.ends_with("Sequence") {
+                    Status::Success
+                } else {
+                    Status::Failure
+                };
+                let reactive = kind.starts_with("Reactive");
+                let last = child_count(node).saturating_sub(1);
+
+                let mut index = 0;
+                let mut blocked = false;
+                node.inspect_children(&mut |child| {
+                    let segment = child
+                        .label()
+                        .map_or(PathSegment::Index(index), PathSegment::Label);
+                    if !blocked && index < last && never_returns(child, needs) {
+                        blocked = true;
+                        emit(Finding::UnreachableChildren {
+                            path,
+                            after: segment,
+                            never: needs,
+                        });
+                    }
+                    if reactive && !blocked && index < last && child.kind() == "Action" {
+                        let mut child_path = path;
+                        child_path.push(segment);
+                        emit(Finding::ReactiveAction { path: child_path });
+                    }
+                    index += 1;
+                });
+            }
+            _ => {}
+        }
+    });
+
+    count
+}
+
+#[cfg(feature = "alloc")]
+pub fn findings(root: &dyn Inspect) -> alloc::vec::Vec<Finding> {
+    let mut findings = alloc::vec::Vec::new();
+    lint(root, &mut |finding| findings.push(*finding));
+    findings
+}
+
+fn child_count(node: &dyn Inspect) -> usize {
+    let mut count = 0;
+    node.inspect_children(&mut |_| count += 1);
+    count
+}
+
+// Conservative: `false` unless the node's kind and params prove it.
+fn never_returns(node: &dyn Inspect, status: Status) -> bool {
+    match (node.kind(), node.params()) {
+        (_, Some(Params::Constant(constant))) => constant != status,
+        ("ForceSuccess", _) => status == Status::Failure,
+        ("ForceFailure", _) => status == Status::Success,
+        ("Retry", Some(Params::Retry { max_failures: 0 })) => status == Status::Success,
+        ("Repeat", Some(Params::Repeat { max_successes: 0 })) => status == Status::Failure,
+        ("Inverter", _) => {
+            let inverted = match status {
+                Status::Success => Status::Failure,
+                Status::Failure => Status::Success,
+                Status::Running => return false,
+            };
+            let mut never = false;
+            node.inspect_children(&mut |child| never = never_returns(child, inverted));
+            never
+        }
+        _ => false,
+    }
+}
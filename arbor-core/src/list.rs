@@ -1,20 +1,69 @@
-use crate::{Node, Status};
+use core::future::{Future, poll_fn};
+use core::pin::pin;
+use core::task::Poll;
 
-#[allow(async_fn_in_trait)]
-pub trait NodeList<Ctx> {
+use crate::{Cost, CostList, Inspect, Node, Status, TickCost};
+
+pub trait NodeCount {
+    // The number of children the type itself guarantees. Lists sized at
+    // runtime guarantee none: they set `LEN` to 0 and override `len`, so
+    // constructors checked against `LEN` only accept tuples and arrays.
     const LEN: usize;
 
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub trait InspectList {
+    fn inspect_each(&self, visit: &mut dyn FnMut(&dyn Inspect));
+}
+
+#[allow(async_fn_in_trait)]
+pub trait NodeList<Ctx>: NodeCount {
     async fn tick_at(&mut self, index: usize, ctx: &mut Ctx) -> Status;
+    fn reset_at(&mut self, index: usize);
     fn reset_range(&mut self, start: usize);
     fn reset_all(&mut self);
 }
 
+pub trait SplitContext<Ctx> {
+    type Parts<'a>
+    where
+        Ctx: 'a;
+
+    fn split<'a>(&mut self, ctx: &'a mut Ctx) -> Self::Parts<'a>;
+}
+
+#[allow(async_fn_in_trait)]
+pub trait ConcurrentNodeList<Parts>: NodeCount {
+    async fn tick_concurrent(&mut self, parts: Parts, report: impl FnMut(usize, Status));
+    fn reset_all(&mut self);
+}
+
+impl<T, const N: usize> NodeCount for [T; N] {
+    const LEN: usize = N;
+}
+
+impl<T, const N: usize> InspectList for [T; N]
+where
+    T: Inspect,
+{
+    fn inspect_each(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        for child in self {
+            visit(child);
+        }
+    }
+}
+
 impl<Ctx, T, const N: usize> NodeList<Ctx> for [T; N]
 where
     T: Node<Ctx>,
 {
-    const LEN: usize = N;
-
     async fn tick_at(&mut self, index: usize, ctx: &mut Ctx) -> Status {
         if index >= N {
             panic!("child index out of bounds: {index} >= {N}");
@@ -23,6 +72,80 @@ where
         self[index].tick(ctx).await
     }
 
+    fn reset_at(&mut self, index: usize) {
+        if index >= N {
+            panic!("child index out of bounds: {index} >= {N}");
+        }
+
+        self[index].reset();
+    }
+
+    fn reset_range(&mut self, start: usize) {
+        for child in self.iter_mut().skip(start) {
+            child.reset();
+        }
+    }
+
+    fn reset_all(&mut self) {
+        for child in self.iter_mut() {
+            child.reset();
+        }
+    }
+}
+
+impl<T, const N: usize> CostList for [T; N]
+where
+    T: TickCost,
+{
+    const COSTS: &'static [Cost] = &[T::COST; N];
+}
+
+// Lets trees whose shape is only known at runtime (e.g. parsed from a file)
+// use the same composites; the child count is then fixed at construction.
+#[cfg(feature = "alloc")]
+impl<T> NodeCount for alloc::vec::Vec<T> {
+    const LEN: usize = 0;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> InspectList for alloc::vec::Vec<T>
+where
+    T: Inspect,
+{
+    fn inspect_each(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        for child in self {
+            visit(child);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Ctx, T> NodeList<Ctx> for alloc::vec::Vec<T>
+where
+    T: Node<Ctx>,
+{
+    async fn tick_at(&mut self, index: usize, ctx: &mut Ctx) -> Status {
+        let len = self.as_slice().len();
+        if index >= len {
+            panic!("child index out of bounds: {index} >= {len}");
+        }
+
+        self[index].tick(ctx).await
+    }
+
+    fn reset_at(&mut self, index: usize) {
+        let len = self.as_slice().len();
+        if index >= len {
+            panic!("child index out of bounds: {index} >= {len}");
+        }
+
+        self[index].reset();
+    }
+
     fn reset_range(&mut self, start: usize) {
         for child in self.iter_mut().skip(start) {
             child.reset();
@@ -38,12 +161,30 @@ where
 
 macro_rules! impl_node_list_for_tuple {
     ($len:expr, $( $idx:tt => $ty:ident ),+ $(,)?) => {
+        impl<$( $ty ),+> NodeCount for ($( $ty, )+) {
+            const LEN: usize = $len;
+        }
+
+        impl<$( $ty ),+> InspectList for ($( $ty, )+)
+        where
+            $( $ty: Inspect, )+
+        {
+            fn inspect_each(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+                $( visit(&self.$idx); )+
+            }
+        }
+
+        impl<$( $ty ),+> CostList for ($( $ty, )+)
+        where
+            $( $ty: TickCost, )+
+        {
+            const COSTS: &'static [Cost] = &[$( $ty::COST ),+];
+        }
+
         impl<Ctx, $( $ty ),+> NodeList<Ctx> for ($( $ty, )+)
         where
             $( $ty: Node<Ctx>, )+
         {
-            const LEN: usize = $len;
-
             async fn tick_at(&mut self, index: usize, ctx: &mut Ctx) -> Status {
                 match index {
                     $( $idx => self.$idx.tick(ctx).await, )+
@@ -54,6 +195,16 @@ macro_rules! impl_node_list_for_tuple {
                 }
             }
 
+            fn reset_at(&mut self, index: usize) {
+                match index {
+                    $( $idx => self.$idx.reset(), )+
+                    _ => panic!(
+                        "child index out of bounds: {index} >= {}",
+                        Self::LEN
+                    ),
+                }
+            }
+
             fn reset_range(&mut self, start: usize) {
                 $(
                     if start <= $idx {
@@ -69,6 +220,48 @@ macro_rules! impl_node_list_for_tuple {
     };
 }
 
+macro_rules! impl_concurrent_node_list_for_tuple {
+    ($( $idx:tt => $ty:ident, $part:ident, $future:ident, $done:ident );+ $(;)?) => {
+        impl<'a, $( $ty, $part, )+> ConcurrentNodeList<($( &'a mut $part, )+)> for ($( $ty, )+)
+        where
+            $( $ty: Node<$part>, )+
+        {
+            async fn tick_concurrent(
+                &mut self,
+                parts: ($( &'a mut $part, )+),
+                mut report: impl FnMut(usize, Status),
+            ) {
+                $(
+                    let mut $future = pin!(self.$idx.tick(parts.$idx));
+                    let mut $done = false;
+                )+
+
+                poll_fn(|cx| {
+                    $(
+                        if !$done {
+                            if let Poll::Ready(status) = $future.as_mut().poll(cx) {
+                                $done = true;
+                                report($idx, status);
+                            }
+                        }
+                    )+
+
+                    if $( $done )&&+ {
+                        Poll::Ready(())
+                    } else {
+                        Poll::Pending
+                    }
+                })
+                .await;
+            }
+
+            fn reset_all(&mut self) {
+                $( self.$idx.reset(); )+
+            }
+        }
+    };
+}
+
 impl_node_list_for_tuple!(1, 0 => A);
 impl_node_list_for_tuple!(2, 0 => A, 1 => B);
 impl_node_list_for_tuple!(3, 0 => A, 1 => B, 2 => C);
@@ -131,3 +324,106 @@ impl_node_list_for_tuple!(
     10 => K,
     11 => L
 );
+
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f;
+    6 => G, PG, future_g, done_g
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f;
+    6 => G, PG, future_g, done_g;
+    7 => H, PH, future_h, done_h
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f;
+    6 => G, PG, future_g, done_g;
+    7 => H, PH, future_h, done_h;
+    8 => I, PI, future_i, done_i
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f;
+    6 => G, PG, future_g, done_g;
+    7 => H, PH, future_h, done_h;
+    8 => I, PI, future_i, done_i;
+    9 => J, PJ, future_j, done_j
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f;
+    6 => G, PG, future_g, done_g;
+    7 => H, PH, future_h, done_h;
+    8 => I, PI, future_i, done_i;
+    9 => J, PJ, future_j, done_j;
+    10 => K, PK, future_k, done_k
+);
+impl_concurrent_node_list_for_tuple!(
+    0 => A, PA, future_a, done_a;
+    1 => B, PB, future_b, done_b;
+    2 => C, PC, future_c, done_c;
+    3 => D, PD, future_d, done_d;
+    4 => E, PE, future_e, done_e;
+    5 => F, PF, future_f, done_f;
+    6 => G, PG, future_g, done_g;
+    7 => H, PH, future_h, done_h;
+    8 => I, PI, future_i, done_i;
+    9 => J, PJ, future_j, done_j;
+    10 => K, PK, future_k, done_k;
+    11 => L, PL, future_l, done_l
+);
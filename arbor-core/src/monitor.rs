@@ -0,0 +1,510 @@
+use core::fmt;
+
+use crate::{Cost, Inspect, Node, Params, Ports, Remap, Status, TickCost};
+
+pub const MAX_OBSERVED: u8 = 64;
+
+// What one tick looked like: the status of each `Observed` node that ran,
+// and the context-derived propositions that held afterwards. Nodes and
+// propositions are numbered separately, each below `MAX_OBSERVED`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Observation {
+    success: u64,
+    failure: u64,
+    running: u64,
+    props: u64,
+}
+
+impl Observation {
+    pub const EMPTY: Self = Self {
+        success: 0,
+        failure: 0,
+        running: 0,
+        props: 0,
+    };
+
+    // A node ticked twice in one tick keeps its last status.
+    pub fn record(&mut self, node: u8, status: Status) {
+        let bit = bit(node);
+        self.success &= !bit;
+        self.failure &= !bit;
+        self.running &= !bit;
+        match status {
+            Status::Success => self.success |= bit,
+            Status::Failure => self.failure |= bit,
+            Status::Running => self.running |= bit,
+        }
+    }
+
+    pub fn set(&mut self, prop: u8, value: bool) {
+        if value {
+            self.props |= bit(prop);
+        } else {
+            self.props &= !bit(prop);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::EMPTY;
+    }
+
+    pub const fn ticked(&self, node: u8) -> bool {
+        self.status(node).is_some()
+    }
+
+    pub const fn status(&self, node: u8) -> Option<Status> {
+        if node >= MAX_OBSERVED {
+            None
+        } else if self.success & (1 << node) != 0 {
+            Some(Status::Success)
+        } else if self.failure & (1 << node) != 0 {
+            Some(Status::Failure)
+        } else if self.running & (1 << node) != 0 {
+            Some(Status::Running)
+        } else {
+            None
+        }
+    }
+
+    pub const fn holds(&self, prop: u8) -> bool {
+        prop < MAX_OBSERVED && self.props & (1 << prop) != 0
+    }
+}
+
+fn bit(index: u8) -> u64 {
+    assert!(
+        index < MAX_OBSERVED,
+        "observation index {index} is not below {MAX_OBSERVED}"
+    );
+    1 << index
+}
+
+pub trait MonitorContext {
+    fn observation(&self) -> &Observation;
+    fn observation_mut(&mut self) -> &mut Observation;
+}
+
+impl MonitorContext for Observation {
+    fn observation(&self) -> &Observation {
+        self
+    }
+
+    fn observation_mut(&mut self) -> &mut Observation {
+        self
+    }
+}
+
+// A past-time temporal formula over a run of observations, evaluated after
+// every tick. Bounded operators count the current tick: `within: 0` only
+// looks at it, `within: 3` at it and the three before. Ticks before the
+// monitor started never satisfy `Once` and never falsify `Historically`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Formula {
+    True,
+    Ticked(u8),
+    Returned(u8, Status),
+    Prop(u8),
+    Not(&'static Formula),
+    And(&'static Formula, &'static Formula),
+    Or(&'static Formula, &'static Formula),
+    // Held on the previous tick; false on the first.
+    Previously(&'static Formula),
+    Once {
+        formula: &'static Formula,
+        within: u32,
+    },
+    Historically {
+        formula: &'static Formula,
+        within: u32,
+    },
+    // The second operand held at some tick, and the first on every tick
+    // after it.
+    Since(&'static Formula, &'static Formula),
+}
+
+impl Formula {
+    // How many state slots a `Monitor` needs for this formula: one per
+    // temporal operator.
+    pub const fn slots(&self) -> usize {
+        match *self {
+            Formula::True | Formula::Ticked(_) | Formula::Returned(..) | Formula::Prop(_) => 0,
+            Formula::Not(formula) => formula.slots(),
+            Formula::And(left, right) | Formula::Or(left, right) => left.slots() + right.slots(),
+            Formula::Previously(formula)
+            | Formula::Once { formula, .. }
+            | Formula::Historically { formula, .. } => formula.slots() + 1,
+            Formula::Since(left, right) => left.slots() + right.slots() + 1,
+        }
+    }
+
+    // Slots are laid out in post-order, the same order `evaluate` takes them.
+    const fn initial<const N: usize>(&self, slots: &mut [u32; N], next: &mut usize) {
+        match *self {
+            Formula::True | Formula::Ticked(_) | Formula::Returned(..) | Formula::Prop(_) => {}
+            Formula::Not(formula) => formula.initial(slots, next),
+            Formula::And(left, right) | Formula::Or(left, right) => {
+                left.initial(slots, next);
+                right.initial(slots, next);
+            }
+            Formula::Previously(formula) => {
+                formula.initial(slots, next);
+                slots[*next] = 0;
+                *next += 1;
+            }
+            Formula::Once { formula, .. } | Formula::Historically { formula, .. } => {
+                formula.initial(slots, next);
+                slots[*next] = u32::MAX;
+                *next += 1;
+            }
+            Formula::Since(left, right) => {
+                left.initial(slots, next);
+                right.initial(slots, next);
+                slots[*next] = 0;
+                *next += 1;
+            }
+        }
+    }
+
+    // Every operand is evaluated, never short-circuited, so each temporal
+    // operator sees every tick.
+    fn evaluate(&self, observation: &Observation, slots: &mut [u32], next: &mut usize) -> bool {
+        match *self {
+            Formula::True => true,
+            Formula::Ticked(node) => observation.ticked(node),
+            Formula::Returned(node, status) => observation.status(node) == Some(status),
+            Formula::Prop(prop) => observation.holds(prop),
+            Formula::Not(formula) => !formula.evaluate(observation, slots, next),
+            Formula::And(left, right) => {
+                let left = left.evaluate(observation, slots, next);
+                right.evaluate(observation, slots, next) && left
+            }
+            Formula::Or(left, right) => {
+                let left = left.evaluate(observation, slots, next);
+                right.evaluate(observation, slots, next) || left
+            }
+            Formula::Previously(formula) => {
+                let now = formula.evaluate(observation, slots, next);
+                let slot = take(slots, next);
+                let held = *slot != 0;
+                *slot = u32::from(now);
+                held
+            }
+            // The slot counts ticks since the operand last held.
+            Formula::Once { formula, within } => {
+                let now = formula.evaluate(observation, slots, next);
+                let since = take(slots, next);
+                *since = if now { 0 } else { since.saturating_add(1) };
+                *since <= within
+            }
+            // The slot counts ticks since the operand last failed.
+            Formula::Historically { formula, within } => {
+                let now = formula.evaluate(observation, slots, next);
+                let since = take(slots, next);
+                *since = if now { since.saturating_add(1) } else { 0 };
+                *since > within
+            }
+            Formula::Since(left, right) => {
+                let left = left.evaluate(observation, slots, next);
+                let right = right.evaluate(observation, slots, next);
+                let held = take(slots, next);
+                *held = u32::from(right || (left && *held != 0));
+                *held != 0
+            }
+        }
+    }
+}
+
+fn take<'a>(slots: &'a mut [u32], next: &mut usize) -> &'a mut u32 {
+    let slot = &mut slots[*next];
+    *next += 1;
+    slot
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub property: &'static str,
+    // Counted from 1 since the monitor started or was last reset.
+    pub tick: u64,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "property `{}` violated at tick {}",
+            self.property, self.tick
+        )
+    }
+}
+
+// Checks one formula after every tick in fixed memory. `N` must be at least
+// `formula.slots()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monitor<const N: usize> {
+    property: &'static str,
+    formula: &'static Formula,
+    slots: [u32; N],
+    ticks: u64,
+    violations: u64,
+}
+
+impl<const N: usize> Monitor<N> {
+    pub const fn new(property: &'static str, formula: &'static Formula) -> Self {
+        assert!(
+            formula.slots() <= N,
+            "monitor has fewer slots than its formula needs"
+        );
+        let mut slots = [0; N];
+        formula.initial(&mut slots, &mut 0);
+        Self {
+            property,
+            formula,
+            slots,
+            ticks: 0,
+            violations: 0,
+        }
+    }
+
+    pub const fn property(&self) -> &'static str {
+        self.property
+    }
+
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    pub const fn violations(&self) -> u64 {
+        self.violations
+    }
+
+    pub fn step(&mut self, observation: &Observation) -> Result<(), Violation> {
+        self.ticks += 1;
+        if self.formula.evaluate(observation, &mut self.slots, &mut 0) {
+            return Ok(());
+        }
+        self.violations += 1;
+        Err(Violation {
+            property: self.property,
+            tick: self.ticks,
+        })
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(self.property, self.formula);
+    }
+}
+
+pub trait Monitors {
+    fn step(&mut self, observation: &Observation, report: &mut dyn FnMut(&Violation));
+    fn reset(&mut self);
+}
+
+impl<const N: usize> Monitors for Monitor<N> {
+    fn step(&mut self, observation: &Observation, report: &mut dyn FnMut(&Violation)) {
+        if let Err(violation) = Monitor::step(self, observation) {
+            report(&violation);
+        }
+    }
+
+    fn reset(&mut self) {
+        Monitor::reset(self);
+    }
+}
+
+impl<M, const K: usize> Monitors for [M; K]
+where
+    M: Monitors,
+{
+    fn step(&mut self, observation: &Observation, report: &mut dyn FnMut(&Violation)) {
+        for monitor in self {
+            monitor.step(observation, report);
+        }
+    }
+
+    fn reset(&mut self) {
+        for monitor in self {
+            monitor.reset();
+        }
+    }
+}
+
+// Records the child's status as node `node` of the current observation.
+// Ticks where the child was skipped are not recorded. Otherwise transparent.
+pub struct Observed<Child> {
+    child: Child,
+    node: u8,
+}
+
+impl<Child> Observed<Child> {
+    pub const fn new(child: Child, node: u8) -> Self {
+        assert!(
+            node < MAX_OBSERVED,
+            "observed node index is not below MAX_OBSERVED"
+        );
+        Self { child, node }
+    }
+
+    pub const fn node(&self) -> u8 {
+        self.node
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child> Node<Ctx> for Observed<Child>
+where
+    Ctx: MonitorContext,
+    Child: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        let status = self.child.tick(ctx).await;
+        if !self.child.skipped() {
+            ctx.observation_mut().record(self.node, status);
+        }
+        status
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Inspect for Observed<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        self.child.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.child.label()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.child.inspect_children(visit);
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        self.child.ports()
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        self.child.scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.child.params()
+    }
+}
+
+impl<Child> TickCost for Observed<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST;
+}
+
+// Wraps a root: clears the context's observation before each tick, lets
+// `propositions` add context-derived atoms after it, then steps every
+// monitor and reports violations. Resetting the tree does not reset the
+// monitors, since properties usually span halts; use `monitors_mut`.
+pub struct Monitored<Child, M, P, V> {
+    child: Child,
+    monitors: M,
+    propositions: P,
+    on_violation: V,
+}
+
+impl<Child, M, P, V> Monitored<Child, M, P, V> {
+    pub const fn new(child: Child, monitors: M, propositions: P, on_violation: V) -> Self {
+        Self {
+            child,
+            monitors,
+            propositions,
+            on_violation,
+        }
+    }
+
+    pub const fn monitors(&self) -> &M {
+        &self.monitors
+    }
+
+    pub fn monitors_mut(&mut self) -> &mut M {
+        &mut self.monitors
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child, M, P, V> Node<Ctx> for Monitored<Child, M, P, V>
+where
+    Ctx: MonitorContext,
+    Child: Node<Ctx>,
+    M: Monitors,
+    P: FnMut(&Ctx, &mut Observation),
+    V: FnMut(&Violation),
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        ctx.observation_mut().clear();
+        let status = self.child.tick(ctx).await;
+
+        let mut observation = *ctx.observation();
+        (self.propositions)(ctx, &mut observation);
+        *ctx.observation_mut() = observation;
+        self.monitors.step(&observation, &mut self.on_violation);
+        status
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child, M, P, V> Inspect for Monitored<Child, M, P, V>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        self.child.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.child.label()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.child.inspect_children(visit);
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        self.child.ports()
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        self.child.scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.child.params()
+    }
+}
+
+impl<Child, M, P, V> TickCost for Monitored<Child, M, P, V>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST;
+}
@@ -5,4 +5,8 @@ pub trait Node<Ctx> {
     async fn tick(&mut self, ctx: &mut Ctx) -> Status;
 
     fn reset(&mut self) {}
+
+    fn skipped(&self) -> bool {
+        false
+    }
 }
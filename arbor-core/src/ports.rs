@@ -0,0 +1,373 @@
+use crate::{
+    Cost, Inspect, Key, MAX_SCOPE_DEPTH, Node, NodePath, Params, Remap, Status, TickCost,
+    ValueKind,
+    blackboard::{self, Entry, Frame},
+    walk,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortDirection {
+    Input,
+    Output,
+    InOut,
+}
+
+impl PortDirection {
+    pub const fn reads(self) -> bool {
+        matches!(self, PortDirection::Input | PortDirection::InOut)
+    }
+
+    pub const fn writes(self) -> bool {
+        matches!(self, PortDirection::Output | PortDirection::InOut)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Port {
+    name: &'static str,
+    kind: ValueKind,
+    direction: PortDirection,
+    required: bool,
+}
+
+impl Port {
+    pub const fn input<T: Entry>(key: Key<T>) -> Self {
+        Self::new(key, PortDirection::Input)
+    }
+
+    pub const fn output<T: Entry>(key: Key<T>) -> Self {
+        Self::new(key, PortDirection::Output)
+    }
+
+    pub const fn in_out<T: Entry>(key: Key<T>) -> Self {
+        Self::new(key, PortDirection::InOut)
+    }
+
+    const fn new<T: Entry>(key: Key<T>, direction: PortDirection) -> Self {
+        Self {
+            name: key.name(),
+            kind: T::KIND,
+            direction,
+            required: true,
+        }
+    }
+
+    // Optional inputs may be absent at runtime; the node is expected to
+    // handle the missing entry itself.
+    pub const fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn kind(&self) -> ValueKind {
+        self.kind
+    }
+
+    pub const fn direction(&self) -> PortDirection {
+        self.direction
+    }
+
+    pub const fn is_required(&self) -> bool {
+        self.required
+    }
+}
+
+pub trait Ports {
+    fn ports(&self, declare: &mut dyn FnMut(Port));
+}
+
+impl Ports for [Port] {
+    fn ports(&self, declare: &mut dyn FnMut(Port)) {
+        self.iter().copied().for_each(declare);
+    }
+}
+
+impl<const N: usize> Ports for [Port; N] {
+    fn ports(&self, declare: &mut dyn FnMut(Port)) {
+        self.as_slice().ports(declare);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ports for alloc::vec::Vec<Port> {
+    fn ports(&self, declare: &mut dyn FnMut(Port)) {
+        self.as_slice().ports(declare);
+    }
+}
+
+pub struct WithPorts<Child> {
+    child: Child,
+    ports: &'static [Port],
+}
+
+impl<Child> WithPorts<Child> {
+    pub const fn new(child: Child, ports: &'static [Port]) -> Self {
+        Self { child, ports }
+    }
+
+    pub fn into_child(self) -> Child {
+        self.child
+    }
+}
+
+impl<Ctx, Child> Node<Ctx> for WithPorts<Child>
+where
+    Child: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.child.tick(ctx).await
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped()
+    }
+}
+
+impl<Child> Ports for WithPorts<Child> {
+    fn ports(&self, declare: &mut dyn FnMut(Port)) {
+        self.ports.ports(declare);
+    }
+}
+
+impl<Child> Inspect for WithPorts<Child>
+where
+    Child: Inspect,
+{
+    fn kind(&self) -> &'static str {
+        self.child.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.child.label()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.child.inspect_children(visit);
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        Some(self)
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        self.child.scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.child.params()
+    }
+}
+
+impl<Child> TickCost for WithPorts<Child>
+where
+    Child: TickCost,
+{
+    const COST: Cost = Child::COST;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagnostic {
+    MissingInput {
+        path: NodePath,
+        port: Port,
+    },
+    TypeMismatch {
+        path: NodePath,
+        port: Port,
+        found: ValueKind,
+    },
+    UnusedOutput {
+        path: NodePath,
+        port: Port,
+    },
+    ScopeTooDeep {
+        path: NodePath,
+    },
+}
+
+impl Diagnostic {
+    pub const fn path(&self) -> &NodePath {
+        match self {
+            Diagnostic::MissingInput { path, .. }
+            | Diagnostic::TypeMismatch { path, .. }
+            | Diagnostic::UnusedOutput { path, .. }
+            | Diagnostic::ScopeTooDeep { path } => path,
+        }
+    }
+}
+
+impl core::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Diagnostic::MissingInput { path, port } => {
+                write!(f, "{path}: input `{}` is never written", port.name)
+            }
+            Diagnostic::TypeMismatch { path, port, found } => write!(
+                f,
+                "{path}: port `{}` expects {} but the entry is used as {found}",
+                port.name, port.kind
+            ),
+            Diagnostic::UnusedOutput { path, port } => {
+                write!(f, "{path}: output `{}` is never read", port.name)
+            }
+            Diagnostic::ScopeTooDeep { path } => {
+                write!(f, "{path}: scopes nest deeper than {MAX_SCOPE_DEPTH}")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Site<'a> {
+    index: usize,
+    // `None` for ports of the environment the tree runs in.
+    path: Option<&'a NodePath>,
+    port: Port,
+    entry: (&'static str, &'static str),
+}
+
+// Ports are checked against each other without knowing the order nodes run
+// in: an input is satisfied by a writer anywhere in the tree or environment.
+// Each check re-walks the tree instead of collecting ports, so validation
+// needs no allocation.
+pub fn validate(
+    root: &dyn Inspect,
+    environment: &dyn Ports,
+    report: &mut dyn FnMut(&Diagnostic),
+) -> usize {
+    let mut count = 0;
+    let mut emit = |diagnostic: Diagnostic| {
+        count += 1;
+        report(&diagnostic);
+    };
+
+    for_each_site(root, environment, &mut |_| {}, &mut |path| {
+        emit(Diagnostic::ScopeTooDeep { path: *path });
+    });
+    for_each_site(
+        root,
+        environment,
+        &mut |site| {
+            let Some(path) = site.path else {
+                return;
+            };
+
+            let mut written = false;
+            let mut read = false;
+            let mut found = None;
+            for_each_site(
+                root,
+                environment,
+                &mut |other| {
+                    if other.index == site.index || other.entry != site.entry {
+                        return;
+                    }
+                    written |= other.port.direction.writes();
+                    read |= other.port.direction.reads();
+                    if other.index < site.index && other.port.kind != site.port.kind {
+                        found.get_or_insert(other.port.kind);
+                    }
+                },
+                &mut |_| {},
+            );
+
+            let port = site.port;
+            if let Some(found) = found {
+                emit(Diagnostic::TypeMismatch {
+                    path: *path,
+                    port,
+                    found,
+                });
+            }
+            if port.direction.reads() && port.required && !written {
+                emit(Diagnostic::MissingInput { path: *path, port });
+            }
+            if port.direction == PortDirection::Output && !read {
+                emit(Diagnostic::UnusedOutput { path: *path, port });
+            }
+        },
+        &mut |_| {},
+    );
+
+    count
+}
+
+#[cfg(feature = "alloc")]
+pub fn diagnostics(root: &dyn Inspect, environment: &dyn Ports) -> alloc::vec::Vec<Diagnostic> {
+    let mut diagnostics = alloc::vec::Vec::new();
+    validate(root, environment, &mut |diagnostic| {
+        diagnostics.push(*diagnostic);
+    });
+    diagnostics
+}
+
+fn for_each_site(
+    root: &dyn Inspect,
+    environment: &dyn Ports,
+    visit: &mut dyn FnMut(Site<'_>),
+    too_deep: &mut dyn FnMut(&NodePath),
+) {
+    let mut index = 0;
+    environment.ports(&mut |port| {
+        visit(Site {
+            index,
+            path: None,
+            port,
+            entry: blackboard::resolve(&[], port.name),
+        });
+        index += 1;
+    });
+
+    // Scopes are popped by path depth: a node at depth `d` is outside every
+    // scope entered at depth `d` or deeper.
+    let mut frames = [Frame {
+        name: "",
+        remaps: &[],
+    }; MAX_SCOPE_DEPTH];
+    let mut frame_depths = [0; MAX_SCOPE_DEPTH];
+    let mut depth = 0;
+    let mut overflow_depth = None;
+
+    walk(root, &mut |path, node| {
+        while depth > 0 && frame_depths[depth - 1] >= path.depth() {
+            depth -= 1;
+        }
+        if overflow_depth.is_some_and(|overflow| overflow >= path.depth()) {
+            overflow_depth = None;
+        }
+
+        if let Some(ports) = node.ports() {
+            ports.ports(&mut |port| {
+                visit(Site {
+                    index,
+                    path: Some(path),
+                    port,
+                    entry: blackboard::resolve(&frames[..depth], port.name),
+                });
+                index += 1;
+            });
+        }
+
+        if let Some((name, remaps)) = node.scope() {
+            if overflow_depth.is_some() {
+                return;
+            }
+            if depth == MAX_SCOPE_DEPTH {
+                overflow_depth = Some(path.depth());
+                too_deep(path);
+                return;
+            }
+            frames[depth] = Frame { name, remaps };
+            frame_depths[depth] = path.depth();
+            depth += 1;
+        }
+    });
+}
@@ -1,6 +1,37 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Status {
     Success,
     Failure,
     Running,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NodeState {
+    #[default]
+    Idle,
+    Running,
+    Success,
+    Failure,
+    Skipped,
+}
+
+impl NodeState {
+    pub const fn status(self) -> Option<Status> {
+        match self {
+            NodeState::Running => Some(Status::Running),
+            NodeState::Success => Some(Status::Success),
+            NodeState::Failure => Some(Status::Failure),
+            NodeState::Idle | NodeState::Skipped => None,
+        }
+    }
+}
+
+impl From<Status> for NodeState {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Success => NodeState::Success,
+            Status::Failure => NodeState::Failure,
+            Status::Running => NodeState::Running,
+        }
+    }
+}
@@ -3,11 +3,11 @@
 
 mod common;
 
-use std::time::Duration;
+use std::{cell::Cell, rc::Rc, time::Duration};
 
 use arbor_core::{
-    ForceFailure, ForceSuccess, Inverter, Node, Parallel, ParallelPolicy, ReactiveSelector,
-    ReactiveSequence, Retry, Selector, Sequence, Status, Timeout,
+    ForceFailure, ForceSuccess, Inverter, Memory, Node, Parallel, ParallelPolicy, ReactiveSelector,
+    ReactiveSequence, Retry, Selector, Sequence, Status, Switch, Timeout,
 };
 
 use common::{MockClock, ScriptedLeaf, TickCtx};
@@ -41,6 +41,95 @@ async fn btcpp_sequence_with_memory_does_not_retick_previous_success_children()
     assert_eq!(ctx.ticks, vec![1, 2]);
 }
 
+// Source: tests/gtest_sequence.cpp :: ComplexSequenceWithMemoryTest.Conditions1ToFalse
+#[tokio::test]
+async fn btcpp_complex_sequence_with_memory_conditions_1_to_false_equivalent() {
+    let mut tree = Sequence::with_memory(
+        (
+            Sequence::with_memory(
+                (
+                    ScriptedLeaf::with_reset_behavior(
+                        0,
+                        vec![Status::Success, Status::Failure],
+                        false,
+                    ),
+                    ScriptedLeaf::new(1, vec![Status::Success]),
+                ),
+                Memory::Persistent,
+            ),
+            Sequence::with_memory(
+                (
+                    ScriptedLeaf::new(2, vec![Status::Running]),
+                    ScriptedLeaf::new(3, vec![Status::Success]),
+                ),
+                Memory::Persistent,
+            ),
+        ),
+        Memory::Persistent,
+    );
+    let mut ctx = TickCtx::new(4);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    // Condition 1 turning false is not observed: the conditions already passed.
+    assert_eq!(ctx.ticks, vec![1, 1, 2, 0]);
+}
+
+// Source: src/controls/sequence_with_memory_node.cpp :: SequenceWithMemory::tick (FAILURE branch)
+#[tokio::test]
+async fn btcpp_sequence_with_memory_failure_reticks_only_failed_child() {
+    let mut tree = Sequence::with_memory(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::with_reset_behavior(1, vec![Status::Failure, Status::Success], false),
+            ScriptedLeaf::new(2, vec![Status::Success]),
+        ),
+        Memory::Persistent,
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    // Child 0 already succeeded and is not restarted after the failure.
+    assert_eq!(ctx.ticks, vec![1, 2, 1]);
+}
+
+// Source: src/controls/sequence_with_memory_node.cpp :: SequenceWithMemory::halt
+#[tokio::test]
+async fn btcpp_sequence_with_memory_halt_keeps_current_child() {
+    let mut tree = Sequence::with_memory(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Running]),
+        ),
+        Memory::Persistent,
+    );
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    tree.reset();
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    assert_eq!(ctx.ticks, vec![1, 2]);
+}
+
+// Source: src/controls/sequence_node.cpp :: SequenceNode::tick (FAILURE branch)
+#[tokio::test]
+async fn btcpp_sequence_failure_restarts_from_first_child() {
+    let mut tree = Sequence::new((
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        ScriptedLeaf::with_reset_behavior(1, vec![Status::Failure, Status::Success], false),
+    ));
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.ticks, vec![2, 2]);
+}
+
 // Source: tests/gtest_fallback.cpp :: SimpleFallbackTest.ConditionChangeWhileRunning
 #[tokio::test]
 async fn btcpp_fallback_with_memory_resumes_running_branch() {
@@ -98,7 +187,8 @@ async fn btcpp_parallel_threshold_two_equivalent() {
             ScriptedLeaf::new(2, vec![Status::Success]),
         ),
         ParallelPolicy::SuccessThreshold(2),
-    );
+    )
+    .unwrap();
     let mut ctx = TickCtx::new(3);
 
     assert_eq!(tree.tick(&mut ctx).await, Status::Success);
@@ -158,3 +248,47 @@ async fn btcpp_timeout_deadline_triggered_equivalent() {
     clock.advance(Duration::from_millis(120));
     assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
 }
+
+// Source: tests/gtest_switch.cpp :: SwitchTest.DefaultCase
+#[tokio::test]
+async fn btcpp_switch_default_case_equivalent() {
+    let mut tree = Switch::new(
+        |_: &TickCtx| "other",
+        ["1", "42"],
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Success]),
+            ScriptedLeaf::new(2, vec![Status::Success]),
+        ),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![0, 0, 1]);
+}
+
+// Source: tests/gtest_switch.cpp :: SwitchTest.CaseSwitchToDefault
+#[tokio::test]
+async fn btcpp_switch_case_switch_to_default_halts_running_case() {
+    let variable = Rc::new(Cell::new("1"));
+    let reading = variable.clone();
+    let (case_one, case_one_probe) = ScriptedLeaf::with_probe(0, vec![Status::Running], true);
+    let mut tree = Switch::new(
+        move |_: &TickCtx| reading.get(),
+        ["1", "42"],
+        (
+            case_one,
+            ScriptedLeaf::new(1, vec![Status::Running]),
+            ScriptedLeaf::new(2, vec![Status::Running]),
+        ),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    variable.set("");
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    assert_eq!(case_one_probe.count(), 1);
+    assert_eq!(ctx.ticks, vec![1, 0, 1]);
+}
@@ -6,9 +6,11 @@ use std::{
 };
 
 use arbor_core::{
-    Action, Condition, Constant, ForceFailure, ForceSuccess, Inverter, Node, NodeList, Parallel,
-    ParallelPolicy, ReactiveSelector, ReactiveSequence, Repeat, Retry, Selector, Sequence, Status,
-    Timeout,
+    Action, Callback, ConcurrentParallel, Condition, Conditioned, Constant, Debounce, DebounceFor,
+    ForceFailure, ForceSuccess, Fresh, Hysteresis, IfThenElse, Inspect, Inverter, Node, NodeCount,
+    NodeExt, NodeList, Parallel, ParallelPolicy, ParallelWithMemory, Precondition, Predicate,
+    ReactiveSelector, ReactiveSequence, Repeat, Retry, Selector, Sequence, SplitContext, Status,
+    Switch, Timeout, Tracked, Unset, WhileDoElse, walk,
 };
 
 use common::{MockClock, ScriptedLeaf, TickCtx, block_on};
@@ -36,6 +38,29 @@ async fn leaf_constructor_and_into_inner_paths_are_covered() {
 
     let mut constant = Constant::new(Status::Failure);
     assert_eq!(constant.tick(&mut ctx_value).await, Status::Failure);
+
+    let mut debounce = Debounce::new(Condition::new(|ctx: &u32| *ctx > 0), 2);
+    assert_eq!(debounce.tick(&mut ctx_value).await, Status::Success);
+    debounce.reset();
+    let _ = Debounce::new(Condition::new(|ctx: &u32| *ctx > 0), 2).into_inner();
+
+    let mut debounce_for = DebounceFor::new(
+        Condition::new(|ctx: &u32| *ctx > 0),
+        MockClock::new(),
+        Duration::from_millis(5),
+    );
+    assert_eq!(debounce_for.tick(&mut ctx_value).await, Status::Success);
+    let _ = DebounceFor::new(
+        Condition::new(|ctx: &u32| *ctx > 0),
+        MockClock::new(),
+        Duration::from_millis(5),
+    )
+    .into_inner();
+
+    let mut hysteresis = Hysteresis::rising(|ctx: &u32| *ctx, 1, 0);
+    assert_eq!(hysteresis.tick(&mut ctx_value).await, Status::Success);
+    let value = Hysteresis::falling(|ctx: &u32| *ctx, 1, 2).into_inner();
+    assert_eq!(value(&ctx_value), ctx_value);
 }
 
 #[tokio::test]
@@ -76,7 +101,8 @@ async fn composite_accessor_policy_and_reset_paths_are_covered() {
             ScriptedLeaf::new(2, vec![Status::Running]),
         ),
         ParallelPolicy::SuccessOnAnyFailureOnAll,
-    );
+    )
+    .unwrap();
     assert_eq!(par.policy(), ParallelPolicy::SuccessOnAnyFailureOnAll);
     assert_eq!(par.tick(&mut TickCtx::new(3)).await, Status::Running);
     par.reset();
@@ -86,6 +112,37 @@ async fn composite_accessor_policy_and_reset_paths_are_covered() {
     let _ = ReactiveSequence::new((ScriptedLeaf::new(0, vec![Status::Success]),)).into_children();
     let _ = ReactiveSelector::new((ScriptedLeaf::new(0, vec![Status::Success]),)).into_children();
     let _ = Parallel::new((ScriptedLeaf::new(0, vec![Status::Success]),)).into_children();
+    let _ = ParallelWithMemory::new((ScriptedLeaf::new(0, vec![Status::Success]),)).into_children();
+
+    let mut if_then_else = IfThenElse::new(
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        ScriptedLeaf::new(1, vec![Status::Running]),
+        ScriptedLeaf::new(2, vec![Status::Success]),
+    );
+    assert_eq!(if_then_else.tick(&mut ctx).await, Status::Running);
+    if_then_else.reset();
+    let _ = if_then_else.into_children();
+
+    let mut while_do_else = WhileDoElse::new(
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        ScriptedLeaf::new(1, vec![Status::Running]),
+        ScriptedLeaf::new(2, vec![Status::Success]),
+    );
+    assert_eq!(while_do_else.tick(&mut ctx).await, Status::Running);
+    while_do_else.reset();
+    let _ = while_do_else.into_children();
+
+    let mut switch = Switch::new(
+        |_: &TickCtx| 0u8,
+        [0u8],
+        (
+            ScriptedLeaf::new(0, vec![Status::Running]),
+            ScriptedLeaf::new(1, vec![Status::Success]),
+        ),
+    );
+    assert_eq!(switch.tick(&mut ctx).await, Status::Running);
+    switch.reset();
+    let _ = switch.into_children();
 }
 
 #[tokio::test]
@@ -131,6 +188,48 @@ async fn decorator_accessor_and_reset_paths_are_covered() {
     assert_eq!(force_failure.tick(&mut ctx).await, Status::Running);
     force_failure.reset();
     let _ = ForceFailure::new(ScriptedLeaf::new(0, vec![Status::Success])).into_child();
+
+    let mut fresh = Fresh::new(
+        ScriptedLeaf::new(0, vec![Status::Running]),
+        MockClock::new(),
+        Duration::from_millis(10),
+        |_: &TickCtx| Some(Duration::ZERO),
+    );
+    assert_eq!(fresh.tick(&mut ctx).await, Status::Running);
+    fresh.reset();
+    let _ = Fresh::new(
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        MockClock::new(),
+        Duration::from_millis(1),
+        |_: &TickCtx| None::<Duration>,
+    )
+    .into_child();
+
+    let mut precondition = Precondition::new(
+        |_: &TickCtx| true,
+        ScriptedLeaf::new(0, vec![Status::Running]),
+    );
+    assert_eq!(precondition.tick(&mut ctx).await, Status::Running);
+    precondition.reset();
+    assert!(!precondition.skipped());
+    let _ = precondition.into_child();
+
+    let mut tracked = Tracked::new(Precondition::new(
+        |_: &TickCtx| false,
+        ScriptedLeaf::new(0, vec![Status::Success]),
+    ));
+    assert_eq!(tracked.tick(&mut ctx).await, Status::Failure);
+    assert!(tracked.skipped());
+    let _ = tracked.into_child();
+
+    let mut conditioned = Conditioned::new(ScriptedLeaf::new(0, vec![Status::Running]))
+        .while_true(|_: &TickCtx| true);
+    assert_eq!(conditioned.tick(&mut ctx).await, Status::Running);
+    conditioned.reset();
+    let _ = conditioned.into_child();
+    assert!(!Predicate::<TickCtx>::test(&mut Unset, &ctx));
+    Callback::<TickCtx>::call(&mut Unset, &mut ctx);
+    assert!(!ScriptedLeaf::new(0, vec![Status::Success]).skipped());
 }
 
 #[tokio::test]
@@ -165,6 +264,91 @@ fn nodelist_out_of_bounds_panics_are_covered() {
         })
     }));
     assert!(tuple_result.is_err());
+
+    let array_reset = catch_unwind(AssertUnwindSafe(|| {
+        <[ScriptedLeaf; 1] as NodeList<TickCtx>>::reset_at(&mut array, 1)
+    }));
+    assert!(array_reset.is_err());
+
+    let tuple_reset = catch_unwind(AssertUnwindSafe(|| {
+        <(ScriptedLeaf,) as NodeList<TickCtx>>::reset_at(&mut tuple, 1)
+    }));
+    assert!(tuple_reset.is_err());
+
+    const _: () = assert!(<[ScriptedLeaf; 3] as NodeCount>::LEN == 3);
+    const _: () = assert!(<(ScriptedLeaf, ScriptedLeaf) as NodeCount>::LEN == 2);
+    assert_eq!(tuple.len(), 1);
+}
+
+struct Whole;
+
+impl SplitContext<TickCtx> for Whole {
+    type Parts<'a> = (&'a mut TickCtx,);
+
+    fn split<'a>(&mut self, ctx: &'a mut TickCtx) -> Self::Parts<'a> {
+        (ctx,)
+    }
+}
+
+struct Each;
+
+type TickCtx12<'a> = (
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+    &'a mut TickCtx,
+);
+
+impl SplitContext<[TickCtx; 12]> for Each {
+    type Parts<'a> = TickCtx12<'a>;
+
+    fn split<'a>(&mut self, ctx: &'a mut [TickCtx; 12]) -> Self::Parts<'a> {
+        let [a, b, c, d, e, f, g, h, i, j, k, l] = ctx.each_mut();
+        (a, b, c, d, e, f, g, h, i, j, k, l)
+    }
+}
+
+#[tokio::test]
+async fn concurrent_parallel_accessor_and_reset_paths_are_covered() {
+    let mut par = ConcurrentParallel::with_policy(
+        Whole,
+        (ScriptedLeaf::new(0, vec![Status::Running]),),
+        ParallelPolicy::SuccessOnAnyFailureOnAll,
+    )
+    .unwrap();
+    assert_eq!(par.policy(), ParallelPolicy::SuccessOnAnyFailureOnAll);
+    assert_eq!(par.tick(&mut TickCtx::new(1)).await, Status::Running);
+    par.reset();
+    let (_, _) = par.into_parts();
+
+    let mut wide = ConcurrentParallel::new(
+        Each,
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+        ),
+    );
+    let mut ctx: [TickCtx; 12] = core::array::from_fn(|_| TickCtx::new(1));
+    assert_eq!(wide.tick(&mut ctx).await, Status::Success);
+    assert!(ctx.iter().all(|part| part.ticks == vec![1]));
 }
 
 macro_rules! tuple_sequence_success_test {
@@ -327,3 +511,85 @@ tuple_sequence_success_test!(
         ScriptedLeaf::new(11, vec![Status::Success]),
     )
 );
+
+#[test]
+fn inspect_kinds_are_covered() {
+    struct Split;
+    impl SplitContext<TickCtx> for Split {
+        type Parts<'a> = (&'a mut TickCtx,);
+
+        fn split<'a>(&mut self, ctx: &'a mut TickCtx) -> Self::Parts<'a> {
+            (ctx,)
+        }
+    }
+
+    let leaf = || ScriptedLeaf::new(0, vec![Status::Success]);
+    let clock = MockClock::new();
+    let tree = Sequence::new((
+        Selector::new([leaf()]),
+        ReactiveSequence::new((ReactiveSelector::new((leaf(),)),)),
+        Parallel::new((ParallelWithMemory::new((leaf(),)),)),
+        ConcurrentParallel::new(Split, (leaf(),)),
+        IfThenElse::new(leaf(), leaf(), leaf()),
+        WhileDoElse::new(leaf(), leaf(), leaf()),
+        Switch::new(|_: &TickCtx| 0u8, [0u8], (leaf(), leaf())),
+        Action::new(|_: &mut TickCtx| async { Status::Success }).inverted(),
+        leaf()
+            .retry(1)
+            .repeat(1)
+            .force_success()
+            .force_failure()
+            .timeout(clock.clone(), Duration::ZERO),
+        Fresh::new(
+            leaf(),
+            clock,
+            Duration::ZERO,
+            |_: &TickCtx| None::<Duration>,
+        ),
+        Precondition::new(|_: &TickCtx| true, leaf()).conditioned(),
+        leaf().map_ctx(|ctx: &mut TickCtx| ctx),
+    ));
+
+    let mut kinds = Vec::new();
+    walk(&tree, &mut |_, node| kinds.push(node.kind()));
+    for kind in [
+        "Sequence",
+        "Selector",
+        "ReactiveSequence",
+        "ReactiveSelector",
+        "Parallel",
+        "ParallelWithMemory",
+        "ConcurrentParallel",
+        "IfThenElse",
+        "WhileDoElse",
+        "Switch",
+        "Action",
+        "Inverter",
+        "Retry",
+        "Repeat",
+        "ForceSuccess",
+        "ForceFailure",
+        "Timeout",
+        "Fresh",
+        "Precondition",
+        "Conditioned",
+        "MapCtx",
+    ] {
+        assert!(kinds.contains(&kind), "{kind} missing from {kinds:?}");
+    }
+
+    for node in [
+        &Condition::new(|_: &TickCtx| true) as &dyn Inspect,
+        &Constant::new(Status::Success),
+        &Debounce::new(Condition::new(|_: &TickCtx| true), 1),
+        &DebounceFor::new(
+            Condition::new(|_: &TickCtx| true),
+            MockClock::new(),
+            Duration::ZERO,
+        ),
+        &Hysteresis::rising(|_: &TickCtx| 0.0, 1.0, 0.5),
+    ] {
+        assert!(!node.kind().is_empty());
+        assert_eq!(node.label(), None);
+    }
+}
@@ -211,7 +211,7 @@ proptest! {
                         ScriptedLeaf::new(2, vec![Status::Success]),
                     ),
                     ParallelPolicy::SuccessOnAllFailureOnAny,
-                );
+                ).unwrap();
                 let mut ctx = TickCtx::new(3);
                 block_on(async { tree.tick(&mut ctx).await })
             }
@@ -223,7 +223,7 @@ proptest! {
                         ScriptedLeaf::new(2, vec![Status::Success]),
                     ),
                     ParallelPolicy::SuccessThreshold(2),
-                );
+                ).unwrap();
                 let mut ctx = TickCtx::new(3);
                 block_on(async { tree.tick(&mut ctx).await })
             }
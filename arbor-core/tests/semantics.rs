@@ -1,13 +1,35 @@
 mod common;
 
+#[path = "semantics/blackboard.rs"]
+mod blackboard;
+#[path = "semantics/branching.rs"]
+mod branching;
+#[path = "semantics/combinators.rs"]
+mod combinators;
 #[path = "semantics/composition.rs"]
 mod composition;
+#[path = "semantics/conditions.rs"]
+mod conditions;
+#[path = "semantics/cost.rs"]
+mod cost;
 #[path = "semantics/decorators.rs"]
 mod decorators;
 #[path = "semantics/edge_cases.rs"]
 mod edge_cases;
+#[path = "semantics/inspect.rs"]
+mod inspect;
+#[path = "semantics/lifecycle.rs"]
+mod lifecycle;
+#[path = "semantics/lint.rs"]
+mod lint;
+#[path = "semantics/monitor.rs"]
+mod monitor;
 #[path = "semantics/parallel.rs"]
 mod parallel;
+#[path = "semantics/ports.rs"]
+mod ports;
+#[path = "semantics/pre_post.rs"]
+mod pre_post;
 #[path = "semantics/reactive.rs"]
 mod reactive;
 #[path = "semantics/sequence_selector.rs"]
@@ -0,0 +1,294 @@
+use std::{
+    cell::Cell,
+    panic::{AssertUnwindSafe, catch_unwind},
+    rc::Rc,
+    time::Duration,
+};
+
+use arbor_core::{
+    Action, Blackboard, BlackboardContext, BlackboardError, CheckEntry, FixedStorage, Inspect, Key,
+    MAX_SCOPE_DEPTH, Node, Scope, Sequence, SetEntry, Status, Value, ValueKind,
+};
+
+use crate::common::block_on;
+
+const ALTITUDE: Key<f64> = Key::new("altitude");
+const ARMED: Key<bool> = Key::new("armed");
+const WAYPOINT: Key<u32> = Key::new("waypoint");
+const TARGET: Key<u32> = Key::new("target");
+const MODE: Key<&'static str> = Key::new("mode");
+
+type Board = Blackboard<FixedStorage<8>>;
+
+#[test]
+fn typed_keys_round_trip_values() {
+    let mut board = Board::default();
+
+    board.set(ALTITUDE, 12.5).unwrap();
+    board.set(ARMED, true).unwrap();
+    board.set(MODE, "survey").unwrap();
+
+    assert_eq!(board.get(ALTITUDE), Ok(12.5));
+    assert_eq!(board.get(ARMED), Ok(true));
+    assert_eq!(board.get(MODE), Ok("survey"));
+    assert!(board.contains(ARMED));
+    assert_eq!(board.storage().len(), 3);
+}
+
+#[test]
+fn reads_report_missing_mismatched_and_out_of_range_entries() {
+    let mut board = Board::default();
+    const ALTITUDE_AS_FLAG: Key<bool> = Key::new("altitude");
+    const WAYPOINT_BYTE: Key<u8> = Key::new("waypoint");
+
+    assert_eq!(
+        board.get(ALTITUDE),
+        Err(BlackboardError::Missing { key: "altitude" })
+    );
+
+    board.set(ALTITUDE, 3.0).unwrap();
+    assert_eq!(
+        board.get(ALTITUDE_AS_FLAG),
+        Err(BlackboardError::TypeMismatch {
+            key: "altitude",
+            expected: ValueKind::Bool,
+            found: ValueKind::Float,
+        })
+    );
+    assert_eq!(
+        board.set(ALTITUDE_AS_FLAG, true),
+        Err(BlackboardError::TypeMismatch {
+            key: "altitude",
+            expected: ValueKind::Bool,
+            found: ValueKind::Float,
+        })
+    );
+    assert!(!board.contains(ALTITUDE_AS_FLAG));
+    assert_eq!(
+        board.remove(ALTITUDE_AS_FLAG),
+        Err(BlackboardError::TypeMismatch {
+            key: "altitude",
+            expected: ValueKind::Bool,
+            found: ValueKind::Float,
+        })
+    );
+    assert_eq!(board.get(ALTITUDE), Ok(3.0));
+
+    board.set(WAYPOINT, 300).unwrap();
+    assert_eq!(
+        board.get(WAYPOINT_BYTE),
+        Err(BlackboardError::OutOfRange { key: "waypoint" })
+    );
+}
+
+#[test]
+fn fixed_storage_rejects_entries_beyond_capacity() {
+    let mut board = Blackboard::new(FixedStorage::<2>::new());
+
+    board.set(ARMED, true).unwrap();
+    board.set(WAYPOINT, 1).unwrap();
+    board.set(WAYPOINT, 2).unwrap();
+    assert_eq!(
+        board.set(ALTITUDE, 1.0),
+        Err(BlackboardError::Full { capacity: 2 })
+    );
+
+    assert_eq!(board.remove(ARMED), Ok(Some(true)));
+    assert_eq!(board.remove(ARMED), Ok(None));
+    board.set(ALTITUDE, 1.0).unwrap();
+    assert_eq!(board.get(WAYPOINT), Ok(2));
+}
+
+#[test]
+fn scopes_keep_local_keys_private_and_remap_shared_ones() {
+    static REMAPS: [(&str, &str); 1] = [("target", "waypoint")];
+    let mut board = Board::default();
+    board.set(WAYPOINT, 4).unwrap();
+    board.set(ARMED, true).unwrap();
+
+    board.enter("approach", &REMAPS);
+    assert_eq!(board.get(TARGET), Ok(4));
+    assert_eq!(
+        board.get(ARMED),
+        Err(BlackboardError::Missing { key: "armed" })
+    );
+    board.set(TARGET, 5).unwrap();
+    board.set(ARMED, false).unwrap();
+    board.exit();
+
+    assert_eq!(board.get(WAYPOINT), Ok(5));
+    assert_eq!(board.get(ARMED), Ok(true));
+    assert!(!board.contains(TARGET));
+}
+
+#[test]
+fn nested_remaps_resolve_through_every_parent() {
+    static OUTER: [(&str, &str); 1] = [("goal", "waypoint")];
+    static INNER: [(&str, &str); 1] = [("target", "goal")];
+    let mut board = Board::default();
+
+    board.enter("mission", &OUTER);
+    board.enter("leg", &INNER);
+    board.set(TARGET, 9).unwrap();
+    assert_eq!(board.scope_depth(), 2);
+    board.exit();
+    board.exit();
+
+    assert_eq!(board.get(WAYPOINT), Ok(9));
+}
+
+#[test]
+fn scope_stack_misuse_panics() {
+    let mut board = Board::default();
+    assert!(catch_unwind(AssertUnwindSafe(|| board.exit())).is_err());
+
+    let mut board = Board::default();
+    for _ in 0..MAX_SCOPE_DEPTH {
+        board.enter("deep", &[]);
+    }
+    assert!(catch_unwind(AssertUnwindSafe(|| board.enter("deep", &[]))).is_err());
+}
+
+struct Drone {
+    board: Board,
+}
+
+impl BlackboardContext for Drone {
+    type Storage = FixedStorage<8>;
+
+    fn blackboard(&self) -> &Board {
+        &self.board
+    }
+
+    fn blackboard_mut(&mut self) -> &mut Board {
+        &mut self.board
+    }
+}
+
+#[tokio::test]
+async fn leaves_read_and_write_entries_through_scopes() {
+    static REMAPS: [(&str, &str); 1] = [("target", "waypoint")];
+    let mut tree = Sequence::new((
+        SetEntry::new(WAYPOINT, 7),
+        Scope::new(
+            "approach",
+            &REMAPS,
+            Sequence::new((
+                CheckEntry::new(TARGET, |target: &u32| *target == 7),
+                SetEntry::new(ARMED, true),
+                SetEntry::new(TARGET, 8),
+            )),
+        ),
+        CheckEntry::new(WAYPOINT, |waypoint: &u32| *waypoint == 8),
+    ));
+    let mut drone = Drone {
+        board: Board::default(),
+    };
+
+    assert_eq!(tree.tick(&mut drone).await, Status::Success);
+    assert_eq!(drone.board.scope_depth(), 0);
+    assert!(!drone.board.contains(ARMED));
+
+    let (_, scope, _) = tree.into_children();
+    assert_eq!((scope.kind(), scope.name()), ("Scope", "approach"));
+    let mut found = 0;
+    scope.inspect_children(&mut |_| found += 1);
+    assert_eq!(found, 1);
+    let _ = scope.into_child();
+}
+
+#[tokio::test]
+async fn scopes_close_frames_left_open_by_a_dropped_tick() {
+    let stall = Rc::new(Cell::new(true));
+    let stalling = stall.clone();
+    let mut tree = Scope::new(
+        "mission",
+        &[],
+        Scope::new(
+            "approach",
+            &[],
+            Action::new(move |_: &mut Drone| {
+                let stall = stalling.get();
+                async move {
+                    if stall {
+                        core::future::pending::<()>().await;
+                    }
+                    Status::Success
+                }
+            }),
+        ),
+    );
+    let mut drone = Drone {
+        board: Board::default(),
+    };
+
+    let dropped = tokio::time::timeout(Duration::from_millis(1), tree.tick(&mut drone)).await;
+    assert!(dropped.is_err());
+    assert_eq!(drone.board.scope_depth(), 2);
+
+    stall.set(false);
+    assert_eq!(tree.tick(&mut drone).await, Status::Success);
+    assert_eq!(drone.board.scope_depth(), 0);
+
+    drone.board.enter("land", &[]);
+    drone.board.enter("flare", &[]);
+    drone.board.truncate(3);
+    assert_eq!(drone.board.scope_depth(), 2);
+    drone.board.truncate(0);
+    assert_eq!(drone.board.scope_depth(), 0);
+}
+
+#[test]
+fn entry_leaves_fail_on_missing_or_mismatched_entries() {
+    const WAYPOINT_AS_FLAG: Key<bool> = Key::new("waypoint");
+    let mut board = Board::default();
+
+    let mut check = CheckEntry::new(WAYPOINT, |_: &u32| true);
+    assert_eq!(block_on(check.tick(&mut board)), Status::Failure);
+
+    board.set(WAYPOINT, 1).unwrap();
+    let mut set = SetEntry::new(WAYPOINT_AS_FLAG, true);
+    assert_eq!(block_on(set.tick(&mut board)), Status::Failure);
+    assert_eq!(block_on(check.tick(&mut board)), Status::Success);
+
+    assert_eq!(check.key().name(), "waypoint");
+    assert_eq!(set.key().name(), "waypoint");
+    assert_eq!(check.kind(), "CheckEntry");
+    assert_eq!(set.kind(), "SetEntry");
+}
+
+#[test]
+fn blackboard_errors_describe_the_entry() {
+    assert_eq!(
+        BlackboardError::TypeMismatch {
+            key: "altitude",
+            expected: ValueKind::Bool,
+            found: ValueKind::Float,
+        }
+        .to_string(),
+        "blackboard entry `altitude` holds a float, expected a bool"
+    );
+    assert_eq!(
+        BlackboardError::Full { capacity: 2 }.to_string(),
+        "blackboard is full (2 entries)"
+    );
+    assert_eq!(Value::Str("x").kind(), ValueKind::Str);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn map_storage_grows_without_a_fixed_capacity() {
+    use arbor_core::{MapStorage, Storage};
+
+    let mut board = Blackboard::new(MapStorage::new());
+    for _ in 0..3 {
+        board.set(WAYPOINT, 1).unwrap();
+    }
+    board.set(ALTITUDE, 2.0).unwrap();
+    board.set(ARMED, true).unwrap();
+
+    assert_eq!(board.storage().len(), 3);
+    assert_eq!(board.remove(ALTITUDE), Ok(Some(2.0)));
+    assert_eq!(board.storage().get("", "altitude"), None);
+    assert!(!board.into_storage().is_empty());
+}
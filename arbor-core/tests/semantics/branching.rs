@@ -0,0 +1,212 @@
+use std::{cell::Cell, rc::Rc};
+
+use arbor_core::{Condition, IfThenElse, Node, Status, Switch, SwitchError, WhileDoElse};
+
+use crate::common::{ScriptedLeaf, TickCtx};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Survey,
+    Loiter,
+    Land,
+}
+
+#[tokio::test]
+async fn if_then_else_picks_branch_from_condition() {
+    let mut then_tree = IfThenElse::new(
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Failure]),
+    );
+    let mut else_tree = IfThenElse::new(
+        ScriptedLeaf::new(0, vec![Status::Failure]),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Failure]),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(then_tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![1, 1, 0]);
+
+    assert_eq!(else_tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(ctx.ticks, vec![2, 1, 1]);
+}
+
+#[tokio::test]
+async fn if_then_else_does_not_recheck_condition_while_branch_runs() {
+    let mut tree = IfThenElse::new(
+        ScriptedLeaf::with_reset_behavior(0, vec![Status::Success, Status::Failure], false),
+        ScriptedLeaf::new(1, vec![Status::Running, Status::Running, Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Success]),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![1, 3, 0]);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![2, 3, 1]);
+}
+
+#[tokio::test]
+async fn if_then_else_rechecks_running_condition() {
+    let mut tree = IfThenElse::new(
+        ScriptedLeaf::new(0, vec![Status::Running, Status::Failure]),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Success]),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![2, 0, 1]);
+}
+
+#[tokio::test]
+async fn while_do_else_rechecks_condition_and_halts_other_branch() {
+    let ok = Rc::new(Cell::new(true));
+    let reading = ok.clone();
+    let (do_leaf, do_probe) = ScriptedLeaf::with_probe(1, vec![Status::Running], true);
+    let (else_leaf, else_probe) = ScriptedLeaf::with_probe(2, vec![Status::Running], true);
+    let mut tree = WhileDoElse::new(
+        Condition::new(move |_: &TickCtx| reading.get()),
+        do_leaf,
+        else_leaf,
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(ctx.ticks, vec![0, 2, 0]);
+
+    ok.set(false);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(ctx.ticks, vec![0, 2, 1]);
+
+    assert_eq!(do_probe.count(), 1);
+    assert_eq!(else_probe.count(), 2);
+}
+
+#[tokio::test]
+async fn while_do_else_returns_running_while_condition_runs() {
+    let mut tree = WhileDoElse::new(
+        ScriptedLeaf::new(0, vec![Status::Running, Status::Success]),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Success]),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![2, 1, 0]);
+}
+
+#[tokio::test]
+async fn switch_ticks_child_matching_key_or_default() {
+    let mode = Rc::new(Cell::new(Mode::Loiter));
+    let reading = mode.clone();
+    let mut tree = Switch::new(
+        move |_: &TickCtx| reading.get(),
+        [Mode::Survey, Mode::Loiter],
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Success]),
+            ScriptedLeaf::new(2, vec![Status::Failure]),
+        ),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    mode.set(Mode::Land);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    mode.set(Mode::Survey);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.ticks, vec![1, 1, 1]);
+}
+
+#[tokio::test]
+async fn switch_resets_previously_running_branch_when_key_changes() {
+    let mode = Rc::new(Cell::new(Mode::Survey));
+    let reading = mode.clone();
+    let (survey, survey_probe) =
+        ScriptedLeaf::with_probe(0, vec![Status::Running, Status::Success], true);
+    let (loiter, loiter_probe) = ScriptedLeaf::with_probe(1, vec![Status::Running], true);
+    let mut tree = Switch::new(
+        move |_: &TickCtx| reading.get(),
+        [Mode::Survey, Mode::Loiter],
+        (survey, loiter, ScriptedLeaf::new(2, vec![Status::Failure])),
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(survey_probe.count(), 1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    mode.set(Mode::Loiter);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(survey_probe.count(), 2);
+    assert_eq!(loiter_probe.count(), 0);
+
+    mode.set(Mode::Survey);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(loiter_probe.count(), 1);
+    assert_eq!(ctx.ticks, vec![4, 1, 0]);
+}
+
+#[test]
+fn switch_rejects_children_that_do_not_match_cases_plus_default() {
+    let result = Switch::try_new(
+        |_: &TickCtx| Mode::Survey,
+        [Mode::Survey, Mode::Loiter],
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Success]),
+        ),
+    );
+
+    assert_eq!(
+        result.err(),
+        Some(SwitchError::ChildCount {
+            cases: 2,
+            children: 2
+        })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[tokio::test]
+async fn switch_checks_lists_sized_at_runtime() {
+    let children = |count| {
+        (0..count)
+            .map(|id| ScriptedLeaf::new(id, vec![Status::Success]))
+            .collect::<Vec<_>>()
+    };
+
+    let short = Switch::try_new(
+        |_: &TickCtx| Mode::Land,
+        [Mode::Survey, Mode::Loiter],
+        children(2),
+    );
+    assert_eq!(
+        short.err(),
+        Some(SwitchError::ChildCount {
+            cases: 2,
+            children: 2
+        })
+    );
+
+    let mut tree = Switch::try_new(
+        |_: &TickCtx| Mode::Land,
+        [Mode::Survey, Mode::Loiter],
+        children(3),
+    )
+    .unwrap();
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![0, 0, 1]);
+}
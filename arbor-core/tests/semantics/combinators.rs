@@ -0,0 +1,124 @@
+use std::{cell::Cell, time::Duration};
+
+use arbor_core::{
+    Condition, Constant, ForceSuccess, Inverter, Node, NodeExt, Retry, Sequence, Status, Timeout,
+};
+
+use crate::common::{MockClock, ScriptedLeaf, TickCtx};
+
+#[tokio::test]
+async fn chained_decorators_build_the_nested_types() {
+    let clock = MockClock::new();
+    let mut tree: Timeout<Retry<Inverter<ScriptedLeaf>>, MockClock> =
+        ScriptedLeaf::new(0, vec![Status::Success, Status::Failure])
+            .inverted()
+            .retry(3)
+            .timeout(clock, Duration::from_millis(100));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(ctx.ticks, vec![2]);
+}
+
+#[tokio::test]
+async fn chained_decorators_match_hand_nested_behavior() {
+    let mut chained = ScriptedLeaf::new(0, vec![Status::Success])
+        .repeat(2)
+        .force_failure()
+        .force_success();
+    let mut nested = ForceSuccess::new(arbor_core::ForceFailure::new(arbor_core::Repeat::new(
+        ScriptedLeaf::new(1, vec![Status::Success]),
+        2,
+    )));
+    let mut ctx = TickCtx::new(2);
+
+    for _ in 0..3 {
+        assert_eq!(chained.tick(&mut ctx).await, nested.tick(&mut ctx).await);
+    }
+    assert_eq!(ctx.ticks[0], ctx.ticks[1]);
+}
+
+#[tokio::test]
+async fn named_node_is_transparent() {
+    let mut tree = Sequence::new((
+        Constant::new(Status::Success).named("ready"),
+        ScriptedLeaf::new(0, vec![Status::Running]).named("work"),
+    ));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    let (ready, work) = tree.into_children();
+    assert_eq!(ready.name(), "ready");
+    assert_eq!(work.name(), "work");
+    assert_eq!(ctx.ticks, vec![1]);
+}
+
+#[tokio::test]
+async fn condition_combinators_compose_predicates() {
+    let mut ctx = TickCtx::new(2);
+    ctx.ticks[0] = 1;
+
+    let first = |ctx: &TickCtx| ctx.ticks[0] > 0;
+    let second = |ctx: &TickCtx| ctx.ticks[1] > 0;
+
+    let mut both = Condition::new(first).and(second);
+    let mut either = Condition::new(first).or(second);
+    let mut neither = Condition::new(first).or(second).not();
+    let mut nested = Condition::new(second)
+        .not()
+        .and(Condition::new(first).or(second));
+
+    assert_eq!(both.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(either.tick(&mut ctx).await, Status::Success);
+    assert_eq!(neither.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(nested.tick(&mut ctx).await, Status::Success);
+}
+
+#[tokio::test]
+async fn condition_combinators_short_circuit() {
+    let mut ctx = TickCtx::new(1);
+    let calls = Cell::new(0);
+    let mut tree = Condition::new(|_: &TickCtx| false).and(|_: &TickCtx| {
+        calls.set(calls.get() + 1);
+        true
+    });
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(calls.get(), 0);
+}
+
+#[test]
+fn combinators_add_no_runtime_state() {
+    let armed = |ctx: &TickCtx| ctx.ticks[0] > 0;
+    let landed = |ctx: &TickCtx| ctx.ticks[0] == 0;
+
+    assert_eq!(
+        std::mem::size_of_val(&Condition::new(armed).and(landed).not()),
+        0
+    );
+    assert_eq!(
+        std::mem::size_of_val(&Constant::new(Status::Success).inverted().force_success()),
+        std::mem::size_of::<Constant>()
+    );
+}
+
+#[tokio::test]
+async fn wrapping_helpers_forward_skips() {
+    let mut tree = ScriptedLeaf::new(0, vec![Status::Success])
+        .conditioned()
+        .skip_if(|_: &TickCtx| true)
+        .named("skipped")
+        .tracked();
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert!(tree.skipped());
+    assert_eq!(tree.state(), arbor_core::NodeState::Skipped);
+
+    tree.reset();
+    assert_eq!(tree.state(), arbor_core::NodeState::Idle);
+    assert_eq!(tree.into_child().name(), "skipped");
+    assert_eq!(ctx.ticks, vec![0]);
+}
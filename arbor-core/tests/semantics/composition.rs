@@ -1,7 +1,8 @@
 use std::{cell::Cell, rc::Rc};
 
 use arbor_core::{
-    Condition, Node, Parallel, ParallelPolicy, ReactiveSequence, Selector, Sequence, Status,
+    Action, Condition, MapCtx, Node, NodeExt, Parallel, ParallelPolicy, ReactiveSequence, Selector,
+    Sequence, Status, walk,
 };
 
 use crate::common::{ScriptedLeaf, TickCtx};
@@ -49,7 +50,8 @@ async fn three_level_composition_short_circuit_and_parallel_policy() {
                 ScriptedLeaf::new(4, vec![Status::Success]),
             ),
             ParallelPolicy::SuccessThreshold(2),
-        ),
+        )
+        .unwrap(),
     ));
 
     let mut ctx = TickCtx::new(5);
@@ -57,3 +59,66 @@ async fn three_level_composition_short_circuit_and_parallel_policy() {
     assert_eq!(tree.tick(&mut ctx).await, Status::Success);
     assert_eq!(ctx.ticks, vec![1, 1, 1, 1, 1]);
 }
+
+struct MissionCtx {
+    flight: TickCtx,
+    abort: bool,
+}
+
+fn flight(mission: &mut MissionCtx) -> &mut TickCtx {
+    &mut mission.flight
+}
+
+#[tokio::test]
+async fn map_ctx_runs_a_subtree_against_part_of_the_outer_context() {
+    let takeoff = || {
+        Sequence::new((
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Running, Status::Success]),
+        ))
+    };
+    let mut tree = Sequence::new((
+        MapCtx::new(flight, takeoff()),
+        Action::new(|mission: &mut MissionCtx| {
+            mission.abort = mission.flight.ticks[1] != 2;
+            async { Status::Success }
+        }),
+        takeoff().map_ctx(flight),
+    ));
+    let mut mission = MissionCtx {
+        flight: TickCtx::new(2),
+        abort: true,
+    };
+
+    assert_eq!(tree.tick(&mut mission).await, Status::Running);
+    assert_eq!(tree.tick(&mut mission).await, Status::Running);
+    assert!(!mission.abort);
+    assert_eq!(tree.tick(&mut mission).await, Status::Success);
+    assert_eq!(mission.flight.ticks, vec![2, 4]);
+
+    let mut kinds = Vec::new();
+    walk(&tree, &mut |_, node| kinds.push(node.kind()));
+    assert_eq!(kinds[..3], ["Sequence", "MapCtx", "Sequence"]);
+}
+
+#[tokio::test]
+async fn map_ctx_forwards_reset_to_a_halted_subtree() {
+    let (leaf, probe) = ScriptedLeaf::with_probe(0, vec![Status::Running, Status::Success], true);
+    let mut tree = ReactiveSequence::new((
+        Condition::new(|mission: &MissionCtx| !mission.abort),
+        MapCtx::new(flight, leaf),
+    ));
+    let mut mission = MissionCtx {
+        flight: TickCtx::new(1),
+        abort: false,
+    };
+
+    assert_eq!(tree.tick(&mut mission).await, Status::Running);
+    mission.abort = true;
+    assert_eq!(tree.tick(&mut mission).await, Status::Failure);
+    assert_eq!(probe.count(), 1);
+
+    mission.abort = false;
+    assert_eq!(tree.tick(&mut mission).await, Status::Running);
+    assert_eq!(mission.flight.ticks, vec![2]);
+}
@@ -0,0 +1,162 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use arbor_core::{
+    Condition, Debounce, DebounceFor, Hysteresis, Node, ReactiveSelector, Sequence, Status,
+};
+
+use crate::common::{MockClock, ScriptedLeaf, TickCtx};
+
+#[tokio::test]
+async fn debounce_holds_output_until_new_value_is_stable_for_n_ticks() {
+    let hot = Rc::new(Cell::new(false));
+    let reading = hot.clone();
+    let mut tree = Debounce::new(Condition::new(move |_: &TickCtx| reading.get()), 3);
+    let mut ctx = TickCtx::new(0);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    hot.set(true);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    hot.set(false);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    hot.set(true);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    hot.set(false);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+}
+
+#[tokio::test]
+async fn debounce_with_zero_or_one_tick_follows_the_raw_value() {
+    let hot = Rc::new(Cell::new(false));
+    let zero_reading = hot.clone();
+    let one_reading = hot.clone();
+    let mut zero = Debounce::new(Condition::new(move |_: &TickCtx| zero_reading.get()), 0);
+    let mut one = Debounce::new(Condition::new(move |_: &TickCtx| one_reading.get()), 1);
+    let mut ctx = TickCtx::new(0);
+
+    assert_eq!(zero.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(one.tick(&mut ctx).await, Status::Failure);
+
+    hot.set(true);
+    assert_eq!(zero.tick(&mut ctx).await, Status::Success);
+    assert_eq!(one.tick(&mut ctx).await, Status::Success);
+}
+
+#[tokio::test]
+async fn debounce_state_survives_composite_resets() {
+    let hot = Rc::new(Cell::new(true));
+    let reading = hot.clone();
+    let mut tree = Sequence::new((
+        Debounce::new(Condition::new(move |_: &TickCtx| reading.get()), 2),
+        ScriptedLeaf::new(0, vec![Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    hot.set(false);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    assert_eq!(ctx.ticks, vec![2]);
+}
+
+#[tokio::test]
+async fn debounce_for_switches_after_value_is_held_for_duration() {
+    let clock = MockClock::new();
+    let hot = Rc::new(Cell::new(false));
+    let reading = hot.clone();
+    let mut tree = DebounceFor::new(
+        Condition::new(move |_: &TickCtx| reading.get()),
+        clock.clone(),
+        Duration::from_millis(100),
+    );
+    let mut ctx = TickCtx::new(0);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    hot.set(true);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    hot.set(false);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    hot.set(true);
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+}
+
+#[tokio::test]
+async fn hysteresis_rising_uses_separate_on_and_off_thresholds() {
+    let temp = Rc::new(Cell::new(100.0f32));
+    let reading = temp.clone();
+    let mut tree = Hysteresis::rising(move |_: &TickCtx| reading.get(), 110.0, 105.0);
+    let mut ctx = TickCtx::new(0);
+
+    let mut trace = Vec::new();
+    for value in [100.0, 110.0, 110.5, 108.0, 105.0, 104.9, 109.0, 111.0] {
+        temp.set(value);
+        trace.push(tree.tick(&mut ctx).await);
+    }
+
+    assert_eq!(
+        trace,
+        vec![
+            Status::Failure,
+            Status::Failure,
+            Status::Success,
+            Status::Success,
+            Status::Success,
+            Status::Failure,
+            Status::Failure,
+            Status::Success,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn hysteresis_falling_activates_below_and_clears_above() {
+    let voltage = Rc::new(Cell::new(15.2f32));
+    let reading = voltage.clone();
+    let mut tree = Hysteresis::falling(move |_: &TickCtx| reading.get(), 14.9, 15.1);
+    let mut ctx = TickCtx::new(0);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    voltage.set(14.8);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert!(tree.is_active());
+    voltage.set(15.0);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    voltage.set(15.2);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+}
+
+#[tokio::test]
+async fn hysteresis_keeps_reactive_selector_from_flapping() {
+    let temp = Rc::new(Cell::new(109.0f32));
+    let reading = temp.clone();
+    let mut tree = ReactiveSelector::new((
+        Sequence::new((
+            Hysteresis::rising(move |_: &TickCtx| reading.get(), 110.0, 108.0),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+        )),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(2);
+
+    for value in [109.0, 110.5, 109.8, 110.2, 109.5, 107.0] {
+        temp.set(value);
+        assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    }
+
+    assert_eq!(ctx.ticks, vec![4, 2]);
+}
@@ -0,0 +1,199 @@
+use arbor_core::{
+    Constant, Cost, Effort, IfThenElse, Memory, Node, NodeExt, Parallel, ParallelWithMemory,
+    ReactiveSelector, ReactiveSequence, Retry, Selector, Sequence, Span, Status, Switch, TickCost,
+    Weighted, WhileDoElse, cost_of,
+};
+use arbor_test::LeafSet;
+
+use crate::common::{ScriptedLeaf, TickCtx, action, block_on, condition};
+
+const fn effort(ticks: (u64, u64), resets: (u64, u64)) -> Option<Effort> {
+    Some(Effort {
+        ticks: Span::new(ticks.0, ticks.1),
+        resets: Span::new(resets.0, resets.1),
+    })
+}
+
+const CONSTANTS: Cost = cost_of(&Sequence::new((
+    Constant::new(Status::Success),
+    Constant::new(Status::Success),
+    Constant::new(Status::Success),
+)));
+const _: () = assert!(CONSTANTS.ticks().max == 3);
+
+#[test]
+fn static_trees_have_const_costs() {
+    assert_eq!(CONSTANTS.ticks(), Span::new(1, 3));
+    assert_eq!(CONSTANTS.resets(), Span::new(0, 3));
+    assert_eq!(
+        CONSTANTS,
+        <Sequence<(Constant, Constant, Constant)> as TickCost>::COST
+    );
+    assert_eq!(CONSTANTS.reset(), Span::new(1, 3));
+}
+
+#[test]
+fn sequences_track_each_outcome_separately() {
+    let cost = cost_of(&Sequence::new((condition(), action())));
+    assert_eq!(cost.outcome(Status::Success), effort((1, 2), (2, 2)));
+    assert_eq!(cost.outcome(Status::Failure), effort((1, 2), (1, 2)));
+    assert_eq!(cost.outcome(Status::Running), effort((1, 2), (0, 0)));
+
+    // Restarting from the first child every tick rules out the resumed path.
+    let cost = cost_of(&ReactiveSequence::new((condition(), action())));
+    assert_eq!(cost.outcome(Status::Success), effort((2, 2), (2, 2)));
+    assert_eq!(cost.outcome(Status::Failure), effort((1, 2), (0, 1)));
+    assert_eq!(cost.outcome(Status::Running), effort((2, 2), (0, 0)));
+
+    let cost = cost_of(&Selector::new((condition(), condition())));
+    assert!(!cost.returns(Status::Running));
+    assert_eq!(cost.ticks(), Span::new(1, 2));
+}
+
+#[test]
+fn parallels_tick_every_child_unless_it_already_finished() {
+    type Three = (ScriptedLeaf, ScriptedLeaf, ScriptedLeaf);
+
+    let cost = <Parallel<Three> as TickCost>::COST;
+    assert_eq!(cost.outcome(Status::Running), effort((3, 3), (0, 0)));
+    assert_eq!(cost.outcome(Status::Failure), effort((3, 3), (3, 3)));
+
+    let cost = <ParallelWithMemory<Three> as TickCost>::COST;
+    assert_eq!(cost.outcome(Status::Running), effort((0, 3), (0, 0)));
+    assert_eq!(cost.outcome(Status::Success), effort((0, 3), (3, 3)));
+}
+
+#[test]
+fn retry_ticks_its_child_at_most_once_per_tick() {
+    let cost = <Retry<ScriptedLeaf> as TickCost>::COST;
+    assert_eq!(cost.ticks(), Span::new(0, 1));
+    assert_eq!(cost.outcome(Status::Running), effort((1, 1), (0, 1)));
+
+    let mut tree = ScriptedLeaf::sticky(0, Status::Failure).retry(3);
+    let mut ctx = TickCtx::new(1);
+    for expected in [Status::Running, Status::Running, Status::Failure] {
+        assert_eq!(block_on(tree.tick(&mut ctx)), expected);
+    }
+    assert_eq!(ctx.ticks, [3]);
+}
+
+#[test]
+fn weights_scale_the_leaves_they_wrap() {
+    type Tree = Sequence<(
+        Weighted<ScriptedLeaf, 10, 2>,
+        Constant,
+        Weighted<Sequence<(ScriptedLeaf, ScriptedLeaf)>, 3, 0>,
+    )>;
+    let cost = <Tree as TickCost>::COST;
+    assert_eq!(cost.ticks(), Span::new(1, 17));
+    assert_eq!(cost.outcome(Status::Success), effort((3, 17), (3, 3)));
+    assert_eq!(cost.reset(), Span::new(0, 3));
+
+    let tree = Sequence::new((action().weighted::<40, 5>(), condition().weighted::<2, 0>()));
+    assert_eq!(cost_of(&tree).ticks().max, 42);
+}
+
+struct Leaves {
+    set: LeafSet,
+    state: u64,
+}
+
+impl Leaves {
+    fn new(seed: u64) -> Self {
+        Self {
+            set: LeafSet::new(),
+            state: seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1,
+        }
+    }
+
+    // A long random script that keeps its place across resets, so one run
+    // walks many combinations of child outcomes.
+    fn leaf(&mut self) -> ScriptedLeaf {
+        let script: Vec<_> = (0..32)
+            .map(|_| {
+                self.state ^= self.state << 13;
+                self.state ^= self.state >> 7;
+                self.state ^= self.state << 17;
+                [Status::Success, Status::Failure, Status::Running][(self.state % 3) as usize]
+            })
+            .collect();
+        self.set.leaf_keeping_cursor(&script)
+    }
+}
+
+fn assert_within_bounds<T>(mut build: impl FnMut(&mut Leaves) -> T)
+where
+    T: Node<TickCtx> + TickCost,
+{
+    for seed in 0..200 {
+        let mut leaves = Leaves::new(seed);
+        let mut tree = build(&mut leaves);
+        let probes = leaves.set.into_probes();
+        let mut ctx = TickCtx::new(probes.len());
+        let totals = |ctx: &TickCtx| {
+            let ticks: usize = ctx.ticks.iter().sum();
+            let resets: usize = probes.iter().map(|probe| probe.count()).sum();
+            (ticks as u64, resets as u64)
+        };
+
+        block_on(async {
+            for tick in 0..24 {
+                let (ticks, resets) = totals(&ctx);
+                let status = tree.tick(&mut ctx).await;
+                let (after_ticks, after_resets) = totals(&ctx);
+
+                let effort = T::COST
+                    .outcome(status)
+                    .unwrap_or_else(|| panic!("seed {seed} tick {tick}: unexpected {status:?}"));
+                let within = |span: Span, count: u64| (span.min..=span.max).contains(&count);
+                assert!(
+                    within(effort.ticks, after_ticks - ticks),
+                    "seed {seed} tick {tick}: {} leaf ticks outside {:?}",
+                    after_ticks - ticks,
+                    effort.ticks
+                );
+                assert!(
+                    within(effort.resets, after_resets - resets),
+                    "seed {seed} tick {tick}: {} leaf resets outside {:?}",
+                    after_resets - resets,
+                    effort.resets
+                );
+            }
+        });
+    }
+}
+
+#[test]
+fn bounds_hold_for_sequences_and_selectors() {
+    assert_within_bounds(|leaves| {
+        Sequence::new((
+            leaves.leaf(),
+            Selector::with_memory((leaves.leaf(), leaves.leaf()), Memory::Persistent),
+            ReactiveSequence::new((leaves.leaf(), leaves.leaf().inverted())),
+            ReactiveSelector::new((leaves.leaf().retry(2), leaves.leaf().repeat(2))),
+        ))
+    });
+}
+
+#[test]
+fn bounds_hold_for_parallels_and_branches() {
+    assert_within_bounds(|leaves| {
+        Selector::new((
+            Parallel::new((leaves.leaf(), leaves.leaf().force_success())),
+            WhileDoElse::new(
+                leaves.leaf(),
+                IfThenElse::new(
+                    leaves.leaf(),
+                    Sequence::with_memory((leaves.leaf(), leaves.leaf()), Memory::Persistent),
+                    leaves.leaf().force_failure(),
+                ),
+                ParallelWithMemory::new((leaves.leaf(), leaves.leaf(), leaves.leaf())),
+            ),
+            Switch::new(
+                |ctx: &TickCtx| ctx.ticks.iter().sum::<usize>() % 3,
+                [0, 1],
+                (leaves.leaf(), leaves.leaf(), leaves.leaf().retry(2)),
+            ),
+        ))
+    });
+}
@@ -1,6 +1,8 @@
-use std::time::Duration;
+use std::{cell::Cell, rc::Rc, time::Duration};
 
-use arbor_core::{ForceFailure, ForceSuccess, Inverter, Node, Repeat, Retry, Status, Timeout};
+use arbor_core::{
+    Clock, ForceFailure, ForceSuccess, Fresh, Inverter, Node, Repeat, Retry, Status, Timeout,
+};
 
 use crate::common::{MockClock, ScriptedLeaf, TickCtx};
 
@@ -154,3 +156,71 @@ async fn force_success_and_force_failure_behave_as_defined() {
     assert_eq!(force_failure.tick(&mut ctx).await, Status::Failure);
     assert_eq!(running_passthrough.tick(&mut ctx).await, Status::Running);
 }
+
+#[tokio::test]
+async fn fresh_ticks_child_while_reading_is_within_max_age() {
+    let clock = MockClock::new();
+    let stamp = Rc::new(Cell::new(Some(Duration::ZERO)));
+    let reading = stamp.clone();
+    let mut tree = Fresh::new(
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        clock.clone(),
+        Duration::from_millis(100),
+        move |_: &TickCtx| reading.get(),
+    );
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.ticks, vec![2]);
+}
+
+#[tokio::test]
+async fn fresh_fails_without_ticking_child_when_reading_is_stale_or_missing() {
+    let clock = MockClock::new();
+    let stamp = Rc::new(Cell::new(Some(Duration::ZERO)));
+    let reading = stamp.clone();
+    let mut tree = Fresh::new(
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        clock.clone(),
+        Duration::from_millis(100),
+        move |_: &TickCtx| reading.get(),
+    );
+    let mut ctx = TickCtx::new(1);
+
+    clock.advance(Duration::from_millis(101));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    stamp.set(None);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    stamp.set(Some(clock.now()));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.ticks, vec![1]);
+}
+
+#[tokio::test]
+async fn fresh_resets_running_child_when_reading_goes_stale() {
+    let clock = MockClock::new();
+    let (leaf, probe) = ScriptedLeaf::with_probe(0, vec![Status::Running, Status::Success], true);
+    let mut tree = Fresh::new(
+        leaf,
+        clock.clone(),
+        Duration::from_millis(50),
+        |_: &TickCtx| Some(Duration::ZERO),
+    );
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    assert_eq!(ctx.ticks, vec![1]);
+    let snapshot = ctx.snapshot_with_probes(&[probe]);
+    assert_eq!(snapshot.resets, vec![1]);
+}
@@ -0,0 +1,138 @@
+use arbor_core::{
+    Inspect, MAX_PATH_DEPTH, NodeExt, NodePath, PathSegment, Selector, Sequence, Status, find, walk,
+};
+
+use crate::common::ScriptedLeaf;
+
+fn leaf() -> ScriptedLeaf {
+    ScriptedLeaf::new(0, vec![Status::Success])
+}
+
+fn paths(root: &dyn Inspect) -> Vec<(String, &'static str)> {
+    let mut out = Vec::new();
+    walk(root, &mut |path, node| {
+        out.push((path.to_string(), node.kind()))
+    });
+    out
+}
+
+#[test]
+fn paths_use_labels_and_fall_back_to_child_indices() {
+    let tree = Selector::new((
+        Sequence::new((
+            leaf(),
+            leaf(),
+            Sequence::new((leaf(), leaf().named("warn_user"))),
+        ))
+        .named("safety"),
+        leaf(),
+    ))
+    .named("root");
+
+    assert_eq!(
+        paths(&tree),
+        vec![
+            ("root".to_string(), "Selector"),
+            ("root/safety".to_string(), "Sequence"),
+            ("root/safety/0".to_string(), "ScriptedLeaf"),
+            ("root/safety/1".to_string(), "ScriptedLeaf"),
+            ("root/safety/2".to_string(), "Sequence"),
+            ("root/safety/2/0".to_string(), "ScriptedLeaf"),
+            ("root/safety/2/warn_user".to_string(), "ScriptedLeaf"),
+            ("root/1".to_string(), "ScriptedLeaf"),
+        ]
+    );
+}
+
+#[test]
+fn unlabeled_root_and_decorator_children_get_indices() {
+    let tree = Sequence::new((leaf().inverted().retry(2), leaf()));
+
+    assert_eq!(
+        paths(&tree),
+        vec![
+            ("root".to_string(), "Sequence"),
+            ("root/0".to_string(), "Retry"),
+            ("root/0/0".to_string(), "Inverter"),
+            ("root/0/0/0".to_string(), "ScriptedLeaf"),
+            ("root/1".to_string(), "ScriptedLeaf"),
+        ]
+    );
+}
+
+#[test]
+fn labels_and_tracking_do_not_add_path_segments() {
+    let tree = Sequence::new((leaf().inverted().named("invert").tracked(),));
+
+    let mut found = Vec::new();
+    assert!(find(&tree, "root/invert/0", &mut |path, node| {
+        found.push((path.depth(), node.kind(), node.label()));
+    }));
+    assert_eq!(found, vec![(3, "ScriptedLeaf", None)]);
+
+    let mut labels = Vec::new();
+    assert!(find(&tree, "root/invert", &mut |_, node| {
+        labels.push((node.kind(), node.label()));
+    }));
+    assert_eq!(labels, vec![("Inverter", Some("invert"))]);
+}
+
+#[test]
+fn find_reports_missing_paths() {
+    let tree = Sequence::new((leaf(), leaf()));
+    let mut visited = 0;
+
+    assert!(!find(&tree, "root/2", &mut |_, _| visited += 1));
+    assert!(!find(&tree, "root/0/0", &mut |_, _| visited += 1));
+    assert!(!find(&tree, "other", &mut |_, _| visited += 1));
+    assert!(find(&tree, "root/1", &mut |_, _| visited += 1));
+    assert_eq!(visited, 1);
+}
+
+#[test]
+fn node_path_exposes_segments() {
+    let tree = Sequence::new((leaf().named("a"), leaf()));
+    let mut segments = Vec::new();
+    walk(&tree, &mut |path, _| {
+        segments.push(path.segments().to_vec())
+    });
+
+    assert_eq!(
+        segments,
+        vec![
+            vec![PathSegment::Label("root")],
+            vec![PathSegment::Label("root"), PathSegment::Label("a")],
+            vec![PathSegment::Label("root"), PathSegment::Index(1)],
+        ]
+    );
+    assert_eq!(NodePath::default().to_string(), "");
+}
+
+struct Chain(usize);
+
+impl Inspect for Chain {
+    fn kind(&self) -> &'static str {
+        "Chain"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        if self.0 > 0 {
+            visit(&Chain(self.0 - 1));
+        }
+    }
+}
+
+#[test]
+fn paths_deeper_than_the_fixed_capacity_are_marked_truncated() {
+    let tree = Chain(MAX_PATH_DEPTH + 1);
+
+    let mut deepest = None;
+    walk(&tree, &mut |path, _| deepest = Some(*path));
+    let deepest = deepest.unwrap();
+
+    assert_eq!(deepest.depth(), MAX_PATH_DEPTH + 2);
+    assert!(deepest.is_truncated());
+    assert_eq!(deepest.segments().len(), MAX_PATH_DEPTH);
+    assert!(deepest.to_string().ends_with("/0/..."));
+    assert!(!deepest.matches(&deepest.to_string()));
+}
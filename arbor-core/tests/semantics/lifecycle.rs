@@ -0,0 +1,191 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use arbor_core::{
+    Clock, ForceFailure, ForceSuccess, Fresh, Inverter, Node, NodeState, Precondition, Repeat,
+    Retry, Sequence, Status, Timeout, Tracked,
+};
+
+use crate::common::{MockClock, ScriptedLeaf, TickCtx};
+
+#[tokio::test]
+async fn tracked_node_starts_idle_and_follows_tick_results() {
+    let mut tree = Tracked::new(ScriptedLeaf::new(0, vec![Status::Running, Status::Success]));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.state(), NodeState::Idle);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.state(), NodeState::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.state(), NodeState::Success);
+
+    tree.reset();
+    assert_eq!(tree.state(), NodeState::Idle);
+}
+
+#[tokio::test]
+async fn precondition_skip_is_distinguishable_from_failure() {
+    let mut skipped = Tracked::new(Precondition::new(
+        |ctx: &TickCtx| ctx.ticks[1] > 0,
+        ScriptedLeaf::new(0, vec![Status::Success]),
+    ));
+    let mut failed = Tracked::new(ScriptedLeaf::new(1, vec![Status::Failure]));
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(skipped.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(failed.tick(&mut ctx).await, Status::Failure);
+
+    assert_eq!(skipped.state(), NodeState::Skipped);
+    assert_eq!(failed.state(), NodeState::Failure);
+    assert_eq!(ctx.ticks, vec![0, 1]);
+
+    assert_eq!(skipped.tick(&mut ctx).await, Status::Success);
+    assert_eq!(skipped.state(), NodeState::Success);
+    assert_eq!(ctx.ticks, vec![1, 1]);
+}
+
+#[tokio::test]
+async fn precondition_skip_status_lets_a_sequence_continue() {
+    let mut tree = Sequence::new((
+        Precondition::with_skip_status(
+            |_: &TickCtx| false,
+            ScriptedLeaf::new(0, vec![Status::Failure]),
+            Status::Success,
+        ),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![0, 1]);
+}
+
+#[tokio::test]
+async fn precondition_is_not_rechecked_while_child_runs() {
+    let mut allowed = true;
+    let mut tree = Precondition::new(
+        move |_: &TickCtx| std::mem::replace(&mut allowed, false),
+        ScriptedLeaf::new(0, vec![Status::Running, Status::Running, Status::Success]),
+    );
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert!(!tree.skipped());
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert!(tree.skipped());
+    assert_eq!(ctx.ticks, vec![3]);
+}
+
+#[tokio::test]
+async fn skipped_survives_parent_reset_until_next_tick() {
+    let mut tree = Inverter::new(Precondition::new(
+        |_: &TickCtx| false,
+        ScriptedLeaf::new(0, vec![Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    let precondition = tree.into_child();
+    assert!(precondition.skipped());
+}
+
+#[tokio::test]
+async fn decorators_forward_a_skipped_child() {
+    let gated = || {
+        Precondition::new(
+            |_: &TickCtx| false,
+            ScriptedLeaf::new(0, vec![Status::Success]),
+        )
+    };
+    let mut ctx = TickCtx::new(1);
+
+    let mut inverted = Tracked::new(Inverter::new(gated()));
+    assert_eq!(inverted.tick(&mut ctx).await, Status::Success);
+    assert_eq!(inverted.state(), NodeState::Skipped);
+
+    let mut retried = Tracked::new(ForceFailure::new(Retry::new(gated(), 2)));
+    assert_eq!(retried.tick(&mut ctx).await, Status::Running);
+    assert_eq!(retried.state(), NodeState::Skipped);
+
+    let mut repeated = Tracked::new(ForceSuccess::new(Repeat::new(gated(), 2)));
+    assert_eq!(repeated.tick(&mut ctx).await, Status::Success);
+    assert_eq!(repeated.state(), NodeState::Skipped);
+    assert_eq!(ctx.ticks, vec![0]);
+}
+
+#[tokio::test]
+async fn decorators_that_decide_alone_are_not_skipped() {
+    let clock = MockClock::new();
+    let mut tree = Tracked::new(Timeout::new(
+        Precondition::with_skip_status(
+            |_: &TickCtx| false,
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            Status::Running,
+        ),
+        clock.clone(),
+        Duration::from_millis(10),
+    ));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.state(), NodeState::Skipped);
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(tree.state(), NodeState::Failure);
+
+    let mut stamped = Some(clock.now());
+    let mut fresh = Tracked::new(Fresh::new(
+        Precondition::new(
+            |_: &TickCtx| false,
+            ScriptedLeaf::new(0, vec![Status::Success]),
+        ),
+        clock.clone(),
+        Duration::from_millis(5),
+        move |_: &TickCtx| stamped.take(),
+    ));
+    assert_eq!(fresh.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(fresh.state(), NodeState::Skipped);
+    assert_eq!(fresh.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(fresh.state(), NodeState::Failure);
+}
+
+#[tokio::test]
+async fn tracked_observer_sees_each_transition_including_reset_to_idle() {
+    let transitions = Rc::new(RefCell::new(Vec::new()));
+    let log = transitions.clone();
+    let mut tree = Sequence::new((
+        Tracked::with_observer(
+            Precondition::new(
+                |_: &TickCtx| true,
+                ScriptedLeaf::new(0, vec![Status::Running, Status::Success]),
+            ),
+            move |from, to| log.borrow_mut().push((from, to)),
+        ),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(
+        *transitions.borrow(),
+        vec![
+            (NodeState::Idle, NodeState::Running),
+            (NodeState::Running, NodeState::Success),
+            (NodeState::Success, NodeState::Idle),
+        ]
+    );
+}
+
+#[test]
+fn node_state_maps_to_tick_status() {
+    assert_eq!(NodeState::default(), NodeState::Idle);
+    assert_eq!(NodeState::from(Status::Running), NodeState::Running);
+    assert_eq!(NodeState::from(Status::Failure), NodeState::Failure);
+    assert_eq!(NodeState::Success.status(), Some(Status::Success));
+    assert_eq!(NodeState::Idle.status(), None);
+    assert_eq!(NodeState::Skipped.status(), None);
+}
@@ -0,0 +1,136 @@
+use arbor_core::{
+    ConcurrentParallel, Constant, Finding, Inspect, Inverter, NodeExt, Parallel, ParallelError,
+    ParallelPolicy, Params, ReactiveSelector, ReactiveSequence, Selector, Sequence, Severity,
+    Status, lint,
+};
+
+use crate::common::{action, condition};
+
+fn findings(root: &dyn Inspect) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let count = lint(root, &mut |finding| findings.push(*finding));
+    assert_eq!(count, findings.len());
+    findings
+}
+
+fn messages(root: &dyn Inspect) -> Vec<String> {
+    findings(root).iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn clean_trees_have_no_findings() {
+    let tree = Selector::new((
+        ReactiveSequence::new((condition(), action())),
+        Sequence::new((action().retry(3), Constant::new(Status::Success))),
+        Constant::new(Status::Failure),
+    ));
+    assert!(findings(&tree).is_empty());
+}
+
+#[test]
+fn children_after_a_child_that_cannot_give_way_are_unreachable() {
+    let tree = Sequence::new((
+        Selector::new((condition(), Constant::new(Status::Success), action())),
+        Sequence::new((action().force_failure().named("abort"), action(), action())),
+        ReactiveSelector::new((action().force_success().inverted().inverted(), action())),
+    ));
+
+    assert_eq!(
+        messages(&tree),
+        [
+            "warning: root/0: children after `1` never run because it never fails",
+            "warning: root/1: children after `abort` never run because it never succeeds",
+            "warning: root/2: children after `0` never run because it never fails",
+            "warning: root/2/0: inverter directly wraps an inverter",
+        ]
+    );
+}
+
+#[test]
+fn zero_count_decorators_are_errors() {
+    let tree = Sequence::new((action().retry(0).named("land"), action().repeat(0)));
+
+    let found = findings(&tree);
+    assert_eq!(found.len(), 3);
+    assert!(matches!(found[0], Finding::UnreachableChildren { .. }));
+    assert!(found[0].path().matches("root"));
+    assert_eq!(found[0].severity(), Severity::Warning);
+    assert_eq!(found[1].severity(), Severity::Error);
+    assert_eq!(
+        found[1].to_string(),
+        "error: root/land: retry with zero attempts fails without ticking its child"
+    );
+    assert_eq!(
+        found[2].to_string(),
+        "error: root/1: repeat with zero successes succeeds without ticking its child"
+    );
+    assert!(Severity::Error > Severity::Warning);
+}
+
+#[test]
+fn parallel_policies_are_checked_against_the_child_count() {
+    let empty: [Constant; 0] = [];
+    let tree = Sequence::new((ConcurrentParallel::new((), empty),));
+
+    assert_eq!(
+        findings(&tree),
+        [Finding::InvalidParallelPolicy {
+            path: *findings(&tree)[0].path(),
+            error: ParallelError::NoChildren,
+        }]
+    );
+    assert!(findings(&tree)[0].path().matches("root/0"));
+
+    let valid = Parallel::with_policy(
+        (condition(), condition()),
+        ParallelPolicy::SuccessThreshold(2),
+    )
+    .unwrap();
+    assert_eq!(
+        valid.params(),
+        Some(Params::Parallel(ParallelPolicy::SuccessThreshold(2)))
+    );
+    assert!(findings(&valid).is_empty());
+}
+
+#[test]
+fn reactive_composites_flag_actions_before_the_last_child() {
+    let tree = ReactiveSequence::new((
+        condition(),
+        action().named("approach"),
+        action().named("land"),
+    ));
+
+    assert_eq!(
+        messages(&tree),
+        [
+            "warning: root/approach: action is ticked again on every tick while a later sibling is running"
+        ]
+    );
+}
+
+#[test]
+fn params_see_through_transparent_wrappers() {
+    let inverter = Inverter::new(Inverter::new(condition()).named("double").tracked());
+    assert_eq!(
+        messages(&inverter),
+        ["warning: root: inverter directly wraps an inverter"]
+    );
+    assert_eq!(
+        Constant::new(Status::Running).named("hold").params(),
+        Some(Params::Constant(Status::Running))
+    );
+    assert_eq!(
+        action().repeat(2).with_ports(&[]).params(),
+        Some(Params::Repeat { max_successes: 2 })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn findings_collects_into_a_vec() {
+    let tree = Selector::new((Constant::new(Status::Success), action().repeat(0)));
+    let found = arbor_core::findings(&tree);
+    assert_eq!(found, findings(&tree));
+    assert_eq!(found.len(), 2);
+}
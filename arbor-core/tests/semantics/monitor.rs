@@ -0,0 +1,225 @@
+use arbor_core::{
+    Constant, Formula, Inspect, Monitor, MonitorContext, Monitored, Monitors, Node, NodeExt,
+    Observation, Precondition, Sequence, Status, Violation,
+};
+
+use crate::common::block_on;
+
+const DOCK: u8 = 0;
+const TAKEOFF: u8 = 1;
+const LOW_BATTERY: u8 = 0;
+
+// After docking succeeds, takeoff does not tick during the next three ticks.
+const DOCKED: Formula = Formula::Returned(DOCK, Status::Success);
+const RECENTLY_DOCKED: Formula = Formula::Previously(&Formula::Once {
+    formula: &DOCKED,
+    within: 2,
+});
+const COOLDOWN: Formula = Formula::Not(&Formula::And(&Formula::Ticked(TAKEOFF), &RECENTLY_DOCKED));
+
+// Takeoff only ticks while the battery has been fine since the last dock.
+const CHARGED: Formula = Formula::Since(&Formula::Not(&Formula::Prop(LOW_BATTERY)), &DOCKED);
+const SAFE_TAKEOFF: Formula = Formula::Or(&Formula::Not(&Formula::Ticked(TAKEOFF)), &CHARGED);
+
+const _: () = assert!(COOLDOWN.slots() == 2);
+static COOLDOWN_MONITOR: Monitor<{ COOLDOWN.slots() }> = Monitor::new("cooldown", &COOLDOWN);
+
+fn observe(entries: &[(u8, Status)], props: &[u8]) -> Observation {
+    let mut observation = Observation::EMPTY;
+    for &(node, status) in entries {
+        observation.record(node, status);
+    }
+    for &prop in props {
+        observation.set(prop, true);
+    }
+    observation
+}
+
+fn run<const N: usize>(monitor: &mut Monitor<N>, observations: &[Observation]) -> Vec<bool> {
+    observations
+        .iter()
+        .map(|observation| monitor.step(observation).is_ok())
+        .collect()
+}
+
+#[test]
+fn observations_keep_the_last_status_per_node() {
+    let mut observation = observe(&[(3, Status::Running), (3, Status::Failure)], &[5]);
+    assert_eq!(observation.status(3), Some(Status::Failure));
+    assert!(observation.ticked(3));
+    assert!(!observation.ticked(4));
+    assert!(observation.holds(5));
+    assert!(!observation.holds(200));
+
+    observation.set(5, false);
+    assert!(!observation.holds(5));
+    observation.clear();
+    assert_eq!(observation, Observation::EMPTY);
+}
+
+#[test]
+fn bounded_operators_count_the_current_tick() {
+    const PING: Formula = Formula::Prop(0);
+    const ONCE: Formula = Formula::Once {
+        formula: &PING,
+        within: 2,
+    };
+    const HISTORICALLY: Formula = Formula::Historically {
+        formula: &PING,
+        within: 1,
+    };
+    const PREVIOUSLY: Formula = Formula::Previously(&PING);
+
+    let on = observe(&[], &[0]);
+    let off = Observation::EMPTY;
+    let trace = [off, on, off, off, off, on, on];
+
+    assert_eq!(
+        run(&mut Monitor::<1>::new("once", &ONCE), &trace),
+        [false, true, true, true, false, true, true]
+    );
+    assert_eq!(
+        run(
+            &mut Monitor::<1>::new("historically", &HISTORICALLY),
+            &trace
+        ),
+        [false, false, false, false, false, false, true]
+    );
+    assert_eq!(
+        run(&mut Monitor::<1>::new("previously", &PREVIOUSLY), &trace),
+        [false, false, true, false, false, false, true]
+    );
+    // Ticks before the monitor started count as having held.
+    assert_eq!(
+        run(&mut Monitor::<1>::new("historically", &HISTORICALLY), &[on]),
+        [true]
+    );
+}
+
+#[test]
+fn since_holds_until_its_guard_breaks() {
+    let docked = observe(&[(DOCK, Status::Success)], &[]);
+    let takeoff = observe(&[(TAKEOFF, Status::Running)], &[]);
+    let low_takeoff = observe(&[(TAKEOFF, Status::Running)], &[LOW_BATTERY]);
+
+    let mut monitor = Monitor::<1>::new("safe takeoff", &SAFE_TAKEOFF);
+    assert_eq!(
+        run(
+            &mut monitor,
+            &[
+                takeoff,
+                docked,
+                takeoff,
+                low_takeoff,
+                takeoff,
+                docked,
+                takeoff
+            ]
+        ),
+        [false, true, true, false, false, true, true]
+    );
+    assert_eq!(monitor.ticks(), 7);
+    assert_eq!(monitor.violations(), 3);
+
+    monitor.reset();
+    assert_eq!(monitor.ticks(), 0);
+    assert_eq!(
+        monitor.step(&takeoff),
+        Err(Violation {
+            property: "safe takeoff",
+            tick: 1,
+        })
+    );
+}
+
+struct Rover {
+    observation: Observation,
+    battery: u8,
+}
+
+impl MonitorContext for Rover {
+    fn observation(&self) -> &Observation {
+        &self.observation
+    }
+
+    fn observation_mut(&mut self) -> &mut Observation {
+        &mut self.observation
+    }
+}
+
+#[test]
+fn monitored_trees_report_violations_per_tick() {
+    let tree = Sequence::new((
+        Constant::new(Status::Success).observed(DOCK),
+        Constant::new(Status::Success).observed(TAKEOFF),
+    ));
+    let mut violations = Vec::new();
+    let mut tree = Monitored::new(
+        tree,
+        [COOLDOWN_MONITOR.clone()],
+        |rover: &Rover, observation: &mut Observation| {
+            observation.set(LOW_BATTERY, rover.battery < 20);
+        },
+        |violation: &Violation| violations.push(*violation),
+    );
+    let mut rover = Rover {
+        observation: Observation::EMPTY,
+        battery: 50,
+    };
+
+    block_on(async {
+        for _ in 0..3 {
+            assert_eq!(tree.tick(&mut rover).await, Status::Success);
+        }
+        assert!(rover.observation.ticked(TAKEOFF));
+        rover.battery = 10;
+        tree.tick(&mut rover).await;
+        assert!(rover.observation.holds(LOW_BATTERY));
+    });
+    assert_eq!(tree.kind(), "Sequence");
+
+    // Resetting the tree keeps the monitor's history.
+    tree.reset();
+    assert_eq!(tree.monitors()[0].ticks(), 4);
+    tree.monitors_mut().reset();
+    assert_eq!(tree.monitors()[0].violations(), 0);
+
+    assert_eq!(
+        violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        [
+            "property `cooldown` violated at tick 2",
+            "property `cooldown` violated at tick 3",
+            "property `cooldown` violated at tick 4",
+        ]
+    );
+}
+
+#[test]
+fn skipped_children_are_not_observed() {
+    let mut observation = Observation::EMPTY;
+    let mut tree = Sequence::new((
+        Precondition::new(|_: &Observation| false, Constant::new(Status::Success))
+            .observed(2)
+            .named("gated"),
+        Constant::new(Status::Running).observed(3),
+    ));
+    assert_eq!(block_on(tree.tick(&mut observation)), Status::Failure);
+    assert!(!observation.ticked(2));
+    assert!(!observation.ticked(3));
+
+    let mut tree = Sequence::new((
+        Precondition::with_skip_status(
+            |_: &Observation| false,
+            Constant::new(Status::Failure),
+            Status::Success,
+        )
+        .observed(2),
+        Constant::new(Status::Running).observed(3),
+    ));
+    assert_eq!(block_on(tree.tick(&mut observation)), Status::Running);
+    assert!(!observation.ticked(2));
+    assert_eq!(observation.status(3), Some(Status::Running));
+}
@@ -1,8 +1,11 @@
-use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::{cell::RefCell, rc::Rc};
 
-use arbor_core::{Node, Parallel, ParallelPolicy, Status};
+use arbor_core::{
+    ConcurrentParallel, Node, Parallel, ParallelCounts, ParallelError, ParallelPolicy,
+    ParallelWithMemory, Sequence, SplitContext, Status,
+};
 
-use crate::common::{ScriptedLeaf, TickCtx, block_on};
+use crate::common::{ScriptedLeaf, TickCtx};
 
 #[tokio::test]
 async fn parallel_ticks_all_children_every_tick() {
@@ -30,7 +33,8 @@ async fn parallel_policy_thresholds_work() {
             ScriptedLeaf::new(2, vec![Status::Failure]),
         ),
         ParallelPolicy::SuccessOnAllFailureOnAny,
-    );
+    )
+    .unwrap();
 
     let mut any_all = Parallel::with_policy(
         (
@@ -39,7 +43,8 @@ async fn parallel_policy_thresholds_work() {
             ScriptedLeaf::new(2, vec![Status::Failure]),
         ),
         ParallelPolicy::SuccessOnAnyFailureOnAll,
-    );
+    )
+    .unwrap();
 
     let mut threshold_two = Parallel::with_policy(
         (
@@ -48,7 +53,8 @@ async fn parallel_policy_thresholds_work() {
             ScriptedLeaf::new(2, vec![Status::Failure]),
         ),
         ParallelPolicy::SuccessThreshold(2),
-    );
+    )
+    .unwrap();
 
     assert_eq!(all_any.tick(&mut ctx).await, Status::Failure);
     assert_eq!(any_all.tick(&mut ctx).await, Status::Success);
@@ -81,7 +87,8 @@ async fn parallel_threshold_boundary_values_are_respected() {
             ScriptedLeaf::new(2, vec![Status::Success]),
         ),
         ParallelPolicy::SuccessThreshold(1),
-    );
+    )
+    .unwrap();
 
     let mut threshold_three = Parallel::with_policy(
         (
@@ -90,27 +97,24 @@ async fn parallel_threshold_boundary_values_are_respected() {
             ScriptedLeaf::new(2, vec![Status::Failure]),
         ),
         ParallelPolicy::SuccessThreshold(3),
-    );
+    )
+    .unwrap();
 
     assert_eq!(threshold_one.tick(&mut ctx).await, Status::Success);
     assert_eq!(threshold_three.tick(&mut ctx).await, Status::Failure);
 }
 
 #[test]
-fn parallel_panics_with_zero_children() {
-    let mut tree: Parallel<[ScriptedLeaf; 0]> = Parallel::new([]);
-    let mut ctx = TickCtx::new(0);
-
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        block_on(async { tree.tick(&mut ctx).await })
-    }));
+fn parallel_rejects_zero_children_at_construction() {
+    let result =
+        Parallel::<[ScriptedLeaf; 0]>::with_policy([], ParallelPolicy::SuccessOnAllFailureOnAny);
 
-    assert!(result.is_err());
+    assert_eq!(result.err(), Some(ParallelError::NoChildren));
 }
 
 #[test]
-fn parallel_panics_with_zero_success_threshold() {
-    let mut tree = Parallel::with_policy(
+fn parallel_rejects_zero_success_threshold_at_construction() {
+    let result = Parallel::with_policy(
         (
             ScriptedLeaf::new(0, vec![Status::Success]),
             ScriptedLeaf::new(1, vec![Status::Success]),
@@ -118,18 +122,19 @@ fn parallel_panics_with_zero_success_threshold() {
         ),
         ParallelPolicy::SuccessThreshold(0),
     );
-    let mut ctx = TickCtx::new(3);
-
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        block_on(async { tree.tick(&mut ctx).await })
-    }));
 
-    assert!(result.is_err());
+    assert_eq!(
+        result.err(),
+        Some(ParallelError::InvalidSuccessThreshold {
+            threshold: 0,
+            children: 3,
+        })
+    );
 }
 
 #[test]
-fn parallel_panics_when_success_threshold_exceeds_child_count() {
-    let mut tree = Parallel::with_policy(
+fn parallel_rejects_success_threshold_exceeding_child_count_at_construction() {
+    let result = Parallel::with_policy(
         (
             ScriptedLeaf::new(0, vec![Status::Success]),
             ScriptedLeaf::new(1, vec![Status::Success]),
@@ -137,11 +142,466 @@ fn parallel_panics_when_success_threshold_exceeds_child_count() {
         ),
         ParallelPolicy::SuccessThreshold(4),
     );
+
+    assert_eq!(
+        result.err(),
+        Some(ParallelError::InvalidSuccessThreshold {
+            threshold: 4,
+            children: 3,
+        })
+    );
+}
+
+#[test]
+fn parallel_policy_validation_reports_each_invalid_configuration() {
+    assert_eq!(
+        ParallelPolicy::SuccessOnAllFailureOnAny.validate(0),
+        Err(ParallelError::NoChildren)
+    );
+    assert_eq!(
+        ParallelPolicy::Thresholds {
+            success: 2,
+            failure: 0,
+        }
+        .validate(3),
+        Err(ParallelError::InvalidFailureThreshold {
+            threshold: 0,
+            children: 3,
+        })
+    );
+    assert_eq!(
+        ParallelPolicy::Thresholds {
+            success: 4,
+            failure: 1,
+        }
+        .validate(3),
+        Err(ParallelError::InvalidSuccessThreshold {
+            threshold: 4,
+            children: 3,
+        })
+    );
+    assert_eq!(
+        ParallelPolicy::MainChild(3).validate(3),
+        Err(ParallelError::InvalidMainChild {
+            index: 3,
+            children: 3,
+        })
+    );
+    assert_eq!(
+        ParallelPolicy::Custom(|_| Status::Running).validate(1),
+        Ok(())
+    );
+    assert_eq!(
+        ParallelError::InvalidMainChild {
+            index: 3,
+            children: 3,
+        }
+        .to_string(),
+        "invalid main child 3 for parallel node with 3 children"
+    );
+}
+
+#[tokio::test]
+async fn parallel_independent_failure_threshold_fails_early() {
+    let mut tree = Parallel::with_policy(
+        (
+            ScriptedLeaf::new(0, vec![Status::Failure]),
+            ScriptedLeaf::new(1, vec![Status::Running, Status::Failure]),
+            ScriptedLeaf::new(2, vec![Status::Running]),
+            ScriptedLeaf::new(3, vec![Status::Success]),
+        ),
+        ParallelPolicy::Thresholds {
+            success: 2,
+            failure: 2,
+        },
+    )
+    .unwrap();
+    let mut ctx = TickCtx::new(4);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+}
+
+#[tokio::test]
+async fn parallel_thresholds_fail_once_success_is_unreachable() {
+    let mut tree = Parallel::with_policy(
+        (
+            ScriptedLeaf::new(0, vec![Status::Failure]),
+            ScriptedLeaf::new(1, vec![Status::Failure]),
+            ScriptedLeaf::new(2, vec![Status::Running]),
+        ),
+        ParallelPolicy::Thresholds {
+            success: 2,
+            failure: 3,
+        },
+    )
+    .unwrap();
     let mut ctx = TickCtx::new(3);
 
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        block_on(async { tree.tick(&mut ctx).await })
-    }));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+}
+
+#[tokio::test]
+async fn parallel_custom_policy_sees_counts() {
+    fn majority(counts: ParallelCounts) -> Status {
+        if counts.successes * 2 > counts.children {
+            Status::Success
+        } else if counts.running == 0 {
+            Status::Failure
+        } else {
+            Status::Running
+        }
+    }
+
+    let mut tree = Parallel::with_policy(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Running, Status::Failure]),
+            ScriptedLeaf::new(2, vec![Status::Running, Status::Failure]),
+        ),
+        ParallelPolicy::Custom(majority),
+    )
+    .unwrap();
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+}
+
+#[tokio::test]
+async fn parallel_main_child_result_wins_and_halts_helpers() {
+    let (main, main_probe) = ScriptedLeaf::with_probe(
+        0,
+        vec![Status::Running, Status::Running, Status::Success],
+        true,
+    );
+    let (helper, helper_probe) =
+        ScriptedLeaf::with_probe(1, vec![Status::Failure, Status::Running], true);
+    let mut tree = Parallel::with_policy((main, helper), ParallelPolicy::MainChild(0)).unwrap();
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.ticks, vec![3, 3]);
+    assert_eq!(main_probe.count(), 1);
+    assert_eq!(helper_probe.count(), 1);
+}
+
+#[tokio::test]
+async fn parallel_with_memory_does_not_retick_completed_children() {
+    let mut tree = ParallelWithMemory::new((
+        ScriptedLeaf::new(0, vec![Status::Success]),
+        ScriptedLeaf::new(1, vec![Status::Running, Status::Running, Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Running, Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.ticks, vec![1, 3, 2]);
+}
+
+#[tokio::test]
+async fn parallel_with_memory_counts_remembered_results_against_policy() {
+    let (leaf0, probe0) = ScriptedLeaf::with_probe(0, vec![Status::Failure], true);
+    let (leaf1, probe1) = ScriptedLeaf::with_probe(1, vec![Status::Running, Status::Failure], true);
+    let (leaf2, probe2) = ScriptedLeaf::with_probe(2, vec![Status::Running], true);
+    let mut tree =
+        ParallelWithMemory::with_policy((leaf0, leaf1, leaf2), ParallelPolicy::SuccessThreshold(2))
+            .unwrap();
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.policy(), ParallelPolicy::SuccessThreshold(2));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    assert_eq!(ctx.ticks, vec![1, 2, 2]);
+    let snapshot = ctx.snapshot_with_probes(&[probe0, probe1, probe2]);
+    assert_eq!(snapshot.resets, vec![1, 1, 1]);
+}
+
+#[tokio::test]
+async fn parallel_with_memory_forgets_results_after_deciding_or_reset() {
+    let mut tree = ParallelWithMemory::with_policy(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Running]),
+        ),
+        ParallelPolicy::SuccessOnAllFailureOnAny,
+    )
+    .unwrap();
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    tree.reset();
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+
+    assert_eq!(ctx.ticks, vec![2, 2]);
+}
+
+#[test]
+fn parallel_with_memory_rejects_zero_children_at_construction() {
+    let result = ParallelWithMemory::<[ScriptedLeaf; 0]>::with_policy(
+        [],
+        ParallelPolicy::SuccessOnAllFailureOnAny,
+    );
+
+    assert_eq!(result.err(), Some(ParallelError::NoChildren));
+}
+
+#[tokio::test]
+async fn parallel_with_memory_fails_when_a_custom_policy_waits_on_finished_children() {
+    let mut tree = ParallelWithMemory::with_policy(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Running, Status::Failure]),
+        ),
+        ParallelPolicy::Custom(|_| Status::Running),
+    )
+    .unwrap();
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(ctx.ticks, vec![1, 2]);
+}
+
+#[test]
+fn parallel_with_memory_rejects_more_than_64_children_at_construction() {
+    let result = ParallelWithMemory::with_policy(
+        core::array::from_fn::<_, 65, _>(|_| ScriptedLeaf::new(0, vec![Status::Running])),
+        ParallelPolicy::SuccessOnAnyFailureOnAll,
+    );
+
+    assert_eq!(
+        result.err(),
+        Some(ParallelError::TooManyChildren {
+            children: 65,
+            max: 64,
+        })
+    );
+}
+
+#[tokio::test]
+async fn parallel_with_memory_main_child_ignores_finished_helpers() {
+    let mut tree = ParallelWithMemory::with_policy(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Running, Status::Failure]),
+        ),
+        ParallelPolicy::MainChild(1),
+    )
+    .unwrap();
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(ctx.ticks, vec![1, 2]);
+}
+
+#[derive(Debug, Default)]
+struct Joint {
+    steps: usize,
+    done: bool,
+}
+
+#[derive(Debug, Default)]
+struct Rover {
+    arm: Joint,
+    base: Joint,
+}
+
+struct ByJoint;
+
+impl SplitContext<Rover> for ByJoint {
+    type Parts<'a> = (&'a mut Joint, &'a mut Joint);
 
-    assert!(result.is_err());
+    fn split<'a>(&mut self, ctx: &'a mut Rover) -> Self::Parts<'a> {
+        (&mut ctx.arm, &mut ctx.base)
+    }
+}
+
+struct MoveJoint {
+    id: usize,
+    yields: usize,
+    result: Status,
+    trace: Rc<RefCell<Vec<usize>>>,
+}
+
+impl Node<Joint> for MoveJoint {
+    async fn tick(&mut self, joint: &mut Joint) -> Status {
+        for _ in 0..self.yields {
+            self.trace.borrow_mut().push(self.id);
+            joint.steps += 1;
+            tokio::task::yield_now().await;
+        }
+        self.trace.borrow_mut().push(self.id);
+        joint.done = true;
+        self.result
+    }
+}
+
+#[derive(Debug, Default)]
+struct Pair {
+    left: TickCtx,
+    right: TickCtx,
+}
+
+struct Halves;
+
+impl SplitContext<Pair> for Halves {
+    type Parts<'a> = (&'a mut TickCtx, &'a mut TickCtx);
+
+    fn split<'a>(&mut self, ctx: &'a mut Pair) -> Self::Parts<'a> {
+        (&mut ctx.left, &mut ctx.right)
+    }
+}
+
+fn pair() -> Pair {
+    Pair {
+        left: TickCtx::new(1),
+        right: TickCtx::new(1),
+    }
+}
+
+#[tokio::test]
+async fn concurrent_parallel_polls_children_together_within_one_tick() {
+    let trace = Rc::new(RefCell::new(Vec::new()));
+    let mut tree = ConcurrentParallel::new(
+        ByJoint,
+        (
+            MoveJoint {
+                id: 0,
+                yields: 2,
+                result: Status::Success,
+                trace: trace.clone(),
+            },
+            MoveJoint {
+                id: 1,
+                yields: 2,
+                result: Status::Success,
+                trace: trace.clone(),
+            },
+        ),
+    );
+    let mut rover = Rover::default();
+
+    assert_eq!(tree.tick(&mut rover).await, Status::Success);
+
+    assert_eq!(*trace.borrow(), vec![0, 1, 0, 1, 0, 1]);
+    assert_eq!((rover.arm.steps, rover.base.steps), (2, 2));
+    assert!(rover.arm.done && rover.base.done);
+}
+
+#[tokio::test]
+async fn concurrent_parallel_waits_for_slowest_child_before_deciding() {
+    let trace = Rc::new(RefCell::new(Vec::new()));
+    let mut tree = ConcurrentParallel::new(
+        ByJoint,
+        (
+            MoveJoint {
+                id: 0,
+                yields: 0,
+                result: Status::Failure,
+                trace: trace.clone(),
+            },
+            MoveJoint {
+                id: 1,
+                yields: 3,
+                result: Status::Success,
+                trace: trace.clone(),
+            },
+        ),
+    );
+    let mut rover = Rover::default();
+
+    assert_eq!(tree.tick(&mut rover).await, Status::Failure);
+    assert_eq!(*trace.borrow(), vec![0, 1, 1, 1, 1]);
+    assert!(rover.base.done);
+}
+
+#[tokio::test]
+async fn concurrent_parallel_applies_policy_and_resets_on_completion() {
+    let (left, left_probe) =
+        ScriptedLeaf::with_probe(0, vec![Status::Running, Status::Success], true);
+    let (right, right_probe) =
+        ScriptedLeaf::with_probe(0, vec![Status::Running, Status::Running], true);
+    let mut tree = ConcurrentParallel::with_policy(
+        Halves,
+        (left, right),
+        ParallelPolicy::SuccessOnAnyFailureOnAll,
+    )
+    .unwrap();
+    let mut ctx = pair();
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!((left_probe.count(), right_probe.count()), (0, 0));
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!((left_probe.count(), right_probe.count()), (1, 1));
+    assert_eq!((ctx.left.ticks, ctx.right.ticks), (vec![2], vec![2]));
+}
+
+#[tokio::test]
+async fn concurrent_parallel_main_child_decides() {
+    let mut tree = ConcurrentParallel::with_policy(
+        Halves,
+        (
+            ScriptedLeaf::new(0, vec![Status::Failure]),
+            ScriptedLeaf::new(0, vec![Status::Running, Status::Success]),
+        ),
+        ParallelPolicy::MainChild(1),
+    )
+    .unwrap();
+    let mut ctx = pair();
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+}
+
+#[tokio::test]
+async fn concurrent_parallel_composes_with_sequential_nodes() {
+    let mut tree = Sequence::new((
+        ConcurrentParallel::new(
+            Halves,
+            (
+                ScriptedLeaf::new(0, vec![Status::Success]),
+                ScriptedLeaf::new(0, vec![Status::Success]),
+            ),
+        ),
+        ConcurrentParallel::new(
+            Halves,
+            (
+                ScriptedLeaf::new(0, vec![Status::Success]),
+                ScriptedLeaf::new(0, vec![Status::Failure]),
+            ),
+        ),
+    ));
+    let mut ctx = pair();
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!((ctx.left.ticks, ctx.right.ticks), (vec![2], vec![2]));
+}
+
+#[test]
+fn concurrent_parallel_validates_policy_at_construction() {
+    let result = ConcurrentParallel::with_policy(
+        Halves,
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(0, vec![Status::Success]),
+        ),
+        ParallelPolicy::MainChild(2),
+    );
+
+    assert_eq!(
+        result.err(),
+        Some(ParallelError::InvalidMainChild {
+            index: 2,
+            children: 2,
+        })
+    );
 }
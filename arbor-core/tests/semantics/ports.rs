@@ -0,0 +1,185 @@
+use arbor_core::{
+    Action, Blackboard, CheckEntry, Diagnostic, FixedStorage, Inspect, Key, MAX_SCOPE_DEPTH,
+    NodeExt, NodePath, Port, PortDirection, Ports, Scope, Sequence, SetEntry, Status, ValueKind,
+    validate,
+};
+
+const WAYPOINT: Key<u32> = Key::new("waypoint");
+const TARGET: Key<u32> = Key::new("target");
+const ARMED: Key<bool> = Key::new("armed");
+const WAYPOINT_NAME: Key<&'static str> = Key::new("waypoint");
+
+type Board = Blackboard<FixedStorage<8>>;
+
+fn diagnostics(root: &dyn Inspect, environment: &dyn Ports) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let count = validate(root, environment, &mut |diagnostic| {
+        diagnostics.push(*diagnostic);
+    });
+    assert_eq!(count, diagnostics.len());
+    diagnostics
+}
+
+fn messages(diagnostics: &[Diagnostic]) -> Vec<String> {
+    diagnostics.iter().map(ToString::to_string).collect()
+}
+
+fn at_waypoint(waypoint: &u32) -> bool {
+    *waypoint == 3
+}
+
+#[test]
+fn ports_record_name_kind_direction_and_requirement() {
+    let input = Port::input(WAYPOINT);
+    let output = Port::output(ARMED);
+    let in_out = Port::in_out(WAYPOINT_NAME).optional();
+
+    assert_eq!(
+        (input.name(), input.kind(), input.direction()),
+        ("waypoint", ValueKind::UInt, PortDirection::Input)
+    );
+    assert!(input.is_required());
+    assert_eq!(output.direction(), PortDirection::Output);
+    assert_eq!(in_out.kind(), ValueKind::Str);
+    assert!(!in_out.is_required());
+    assert!(in_out.direction().reads() && in_out.direction().writes());
+    assert!(!output.direction().reads());
+}
+
+#[test]
+fn connected_tree_validates_cleanly() {
+    let tree = Sequence::new((
+        SetEntry::new(WAYPOINT, 3),
+        CheckEntry::new(WAYPOINT, at_waypoint),
+    ));
+
+    assert!(diagnostics(&tree, &[]).is_empty());
+}
+
+#[test]
+fn reports_missing_inputs_and_unused_outputs_with_paths() {
+    let tree = Sequence::new((
+        SetEntry::new(ARMED, true).named("arm"),
+        CheckEntry::new(WAYPOINT, at_waypoint).named("check"),
+    ));
+
+    let found = diagnostics(&tree, &[]);
+    assert_eq!(
+        messages(&found),
+        [
+            "root/arm: output `armed` is never read",
+            "root/check: input `waypoint` is never written",
+        ]
+    );
+    assert!(found[1].path().matches("root/check"));
+}
+
+#[test]
+fn environment_provides_and_consumes_entries() {
+    let tree = Sequence::new((
+        CheckEntry::new(WAYPOINT, at_waypoint),
+        SetEntry::new(ARMED, true),
+    ));
+    let environment = [Port::output(WAYPOINT), Port::input(ARMED)];
+
+    assert!(diagnostics(&tree, &environment).is_empty());
+}
+
+#[test]
+fn optional_inputs_may_be_missing() {
+    static PORTS: [Port; 1] = [Port::input(WAYPOINT).optional()];
+    let tree = Action::new(|_: &mut Board| async { Status::Success }).with_ports(&PORTS);
+
+    assert!(diagnostics(&tree, &[]).is_empty());
+}
+
+#[test]
+fn reports_type_mismatches_against_earlier_ports() {
+    let tree = Sequence::new((
+        SetEntry::new(WAYPOINT_NAME, "home"),
+        CheckEntry::new(WAYPOINT, at_waypoint),
+    ));
+
+    let found = diagnostics(&tree, &[]);
+    assert_eq!(
+        found,
+        [Diagnostic::TypeMismatch {
+            path: *found[0].path(),
+            port: Port::input(WAYPOINT),
+            found: ValueKind::Str,
+        }]
+    );
+    assert_eq!(
+        found[0].to_string(),
+        "root/1: port `waypoint` expects unsigned integer but the entry is used as string"
+    );
+}
+
+#[test]
+fn scopes_keep_unmapped_keys_private_and_resolve_remaps() {
+    static REMAPS: [(&str, &str); 1] = [("target", "waypoint")];
+    let tree = Sequence::new((
+        SetEntry::new(WAYPOINT, 3),
+        Scope::new("approach", &REMAPS, CheckEntry::new(TARGET, at_waypoint)),
+        Scope::new("private", &[], CheckEntry::new(WAYPOINT, at_waypoint)).named("private"),
+    ));
+
+    assert_eq!(
+        messages(&diagnostics(&tree, &[])),
+        ["root/private/0: input `waypoint` is never written"]
+    );
+}
+
+#[test]
+fn declared_ports_attach_to_closure_leaves() {
+    static READS_TARGET: [Port; 1] = [Port::input(TARGET)];
+    static MOVES: [Port; 2] = [Port::in_out(WAYPOINT), Port::output(TARGET)];
+    let tree = Sequence::new((
+        Action::new(|_: &mut Board| async { Status::Success })
+            .with_ports(&MOVES)
+            .named("move"),
+        Action::new(|_: &mut Board| async { Status::Success }).with_ports(&READS_TARGET),
+    ));
+
+    assert_eq!(
+        messages(&diagnostics(&tree, &[])),
+        ["root/move: input `waypoint` is never written"]
+    );
+}
+
+#[test]
+fn reports_scopes_nested_beyond_the_blackboard_limit() {
+    struct Nested(usize);
+
+    impl Inspect for Nested {
+        fn kind(&self) -> &'static str {
+            "Nested"
+        }
+
+        fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+            if self.0 > 0 {
+                visit(&Nested(self.0 - 1));
+            }
+        }
+
+        fn scope(&self) -> Option<(&'static str, &'static [(&'static str, &'static str)])> {
+            Some(("nested", &[]))
+        }
+    }
+
+    assert!(diagnostics(&Nested(MAX_SCOPE_DEPTH - 1), &[]).is_empty());
+
+    let found = diagnostics(&Nested(MAX_SCOPE_DEPTH), &[]);
+    assert_eq!(found.len(), 1);
+    assert!(matches!(found[0], Diagnostic::ScopeTooDeep { .. }));
+    assert_eq!(found[0].path().depth(), MAX_SCOPE_DEPTH + 1);
+    assert_ne!(*found[0].path(), NodePath::new());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn diagnostics_collects_every_report() {
+    let tree = CheckEntry::new(WAYPOINT, at_waypoint);
+
+    assert_eq!(arbor_core::diagnostics(&tree, &[]), diagnostics(&tree, &[]));
+}
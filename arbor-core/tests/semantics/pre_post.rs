@@ -0,0 +1,124 @@
+use std::{cell::RefCell, rc::Rc};
+
+use arbor_core::{Conditioned, Node, NodeState, Sequence, Status, Tracked};
+
+use crate::common::{ScriptedLeaf, TickCtx};
+
+#[tokio::test]
+async fn pre_conditions_follow_btcpp_priority_order() {
+    let mut tree = Conditioned::new(ScriptedLeaf::new(0, vec![Status::Success]))
+        .skip_if(|_: &TickCtx| true)
+        .success_if(|_: &TickCtx| true)
+        .failure_if(|_: &TickCtx| true);
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert!(!tree.skipped());
+
+    let mut tree = Conditioned::new(ScriptedLeaf::new(0, vec![Status::Failure]))
+        .skip_if(|_: &TickCtx| true)
+        .success_if(|_: &TickCtx| true);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert!(!tree.skipped());
+    assert_eq!(ctx.ticks, vec![0]);
+}
+
+#[tokio::test]
+async fn skip_if_reports_skipped_with_configurable_status() {
+    let mut tree = Tracked::new(
+        Conditioned::new(ScriptedLeaf::new(0, vec![Status::Failure]))
+            .skip_if(|_: &TickCtx| true)
+            .skip_status(Status::Success),
+    );
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.state(), NodeState::Skipped);
+    assert_eq!(ctx.ticks, vec![0]);
+}
+
+#[tokio::test]
+async fn gate_conditions_are_not_rechecked_while_running() {
+    let mut tree = Conditioned::new(ScriptedLeaf::new(
+        0,
+        vec![Status::Running, Status::Running, Status::Success],
+    ))
+    .failure_if(|ctx: &TickCtx| ctx.ticks[0] > 0);
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert_eq!(ctx.ticks, vec![3]);
+}
+
+#[tokio::test]
+async fn while_turning_false_during_running_resets_child_and_skips() {
+    let (leaf, probe) = ScriptedLeaf::with_probe(0, vec![Status::Running], true);
+    let mut tree = Conditioned::new(leaf).while_true(|ctx: &TickCtx| ctx.ticks[0] < 2);
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(probe.count(), 0);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert!(tree.skipped());
+    assert_eq!(probe.count(), 1);
+    assert_eq!(ctx.ticks, vec![2]);
+}
+
+#[tokio::test]
+async fn while_false_before_start_skips_without_ticking() {
+    let mut tree = Sequence::new((
+        Conditioned::new(ScriptedLeaf::new(0, vec![Status::Failure]))
+            .while_true(|_: &TickCtx| false)
+            .skip_status(Status::Success),
+        ScriptedLeaf::new(1, vec![Status::Success]),
+    ));
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![0, 1]);
+}
+
+#[tokio::test]
+async fn post_callbacks_run_for_terminal_results_only() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (on_success, on_failure, post) = (log.clone(), log.clone(), log.clone());
+    let mut tree = Conditioned::new(ScriptedLeaf::with_reset_behavior(
+        0,
+        vec![Status::Running, Status::Success, Status::Failure],
+        false,
+    ))
+    .on_success(move |_: &mut TickCtx| on_success.borrow_mut().push("success"))
+    .on_failure(move |_: &mut TickCtx| on_failure.borrow_mut().push("failure"))
+    .post(move |_: &mut TickCtx| post.borrow_mut().push("post"));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert!(log.borrow().is_empty());
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+
+    assert_eq!(*log.borrow(), vec!["success", "post", "failure", "post"]);
+}
+
+#[tokio::test]
+async fn post_callbacks_see_pre_condition_results_but_not_skips() {
+    let mut tree = Conditioned::new(ScriptedLeaf::new(0, vec![Status::Success]))
+        .success_if(|ctx: &TickCtx| ctx.root_statuses.is_empty())
+        .skip_if(|_: &TickCtx| true)
+        .on_success(|ctx: &mut TickCtx| ctx.record_root(Status::Success))
+        .post(|ctx: &mut TickCtx| ctx.record_root(Status::Running));
+    let mut ctx = TickCtx::new(1);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    assert!(tree.skipped());
+
+    assert_eq!(ctx.root_statuses, vec![Status::Success, Status::Running]);
+    assert_eq!(ctx.ticks, vec![0]);
+}
@@ -1,4 +1,4 @@
-use arbor_core::{Node, Selector, Sequence, Status};
+use arbor_core::{Memory, Node, Retry, Selector, Sequence, Status};
 
 use crate::common::{ScriptedLeaf, TickCtx};
 
@@ -209,3 +209,120 @@ async fn sequence_terminal_failure_resets_all_children_once() {
     assert_eq!(snapshot.ticks, vec![1, 0, 0]);
     assert_eq!(snapshot.resets, vec![1, 1, 1]);
 }
+
+#[tokio::test]
+async fn persistent_sequence_retries_failed_child_without_restarting() {
+    let (leaf0, probe0) = ScriptedLeaf::with_probe(0, vec![Status::Success], false);
+    let (leaf1, probe1) =
+        ScriptedLeaf::with_probe(1, vec![Status::Failure, Status::Success], false);
+    let (leaf2, probe2) = ScriptedLeaf::with_probe(2, vec![Status::Success], false);
+
+    let mut tree = Sequence::with_memory((leaf0, leaf1, leaf2), Memory::Persistent);
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Failure);
+    let snapshot = ctx.snapshot_with_probes(&[probe0.clone(), probe1.clone(), probe2.clone()]);
+    assert_eq!(snapshot.ticks, vec![1, 1, 0]);
+    assert_eq!(snapshot.resets, vec![0, 1, 1]);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    let snapshot = ctx.snapshot_with_probes(&[probe0, probe1, probe2]);
+    assert_eq!(snapshot.ticks, vec![1, 2, 1]);
+    assert_eq!(snapshot.resets, vec![1, 2, 2]);
+}
+
+#[tokio::test]
+async fn persistent_sequence_keeps_progress_across_parent_reset() {
+    let mut tree = Retry::new(
+        Sequence::with_memory(
+            (
+                ScriptedLeaf::new(0, vec![Status::Success]),
+                ScriptedLeaf::with_reset_behavior(
+                    1,
+                    vec![Status::Failure, Status::Failure, Status::Success],
+                    false,
+                ),
+            ),
+            Memory::Persistent,
+        ),
+        3,
+    );
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![1, 3]);
+}
+
+#[tokio::test]
+async fn persistent_sequence_restarts_after_success() {
+    let mut tree = Sequence::with_memory(
+        (
+            ScriptedLeaf::new(0, vec![Status::Success]),
+            ScriptedLeaf::new(1, vec![Status::Success]),
+        ),
+        Memory::Persistent,
+    );
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![2, 2]);
+}
+
+#[tokio::test]
+async fn persistent_selector_restarts_after_success() {
+    let mut tree = Selector::with_memory(
+        (
+            ScriptedLeaf::new(0, vec![Status::Failure]),
+            ScriptedLeaf::with_reset_behavior(1, vec![Status::Success, Status::Failure], false),
+            ScriptedLeaf::new(2, vec![Status::Success]),
+        ),
+        Memory::Persistent,
+    );
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![2, 2, 1]);
+
+    assert_eq!(tree.memory(), Memory::Persistent);
+    assert_eq!(Selector::new(()).memory(), Memory::Resume);
+}
+
+#[tokio::test]
+async fn persistent_selector_skips_failed_children_after_halt() {
+    let (leaf1, probe1) =
+        ScriptedLeaf::with_probe(1, vec![Status::Running, Status::Success], false);
+    let mut tree = Selector::with_memory(
+        (ScriptedLeaf::new(0, vec![Status::Failure]), leaf1),
+        Memory::Persistent,
+    );
+    let mut ctx = TickCtx::new(2);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    tree.reset();
+    assert_eq!(probe1.count(), 1);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![1, 2]);
+
+    // Once finished, the selector tries its first child again.
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[tokio::test]
+async fn vec_children_behave_like_fixed_lists() {
+    let mut tree = Selector::new(vec![
+        ScriptedLeaf::new(0, vec![Status::Failure]),
+        ScriptedLeaf::new(1, vec![Status::Running, Status::Success]),
+        ScriptedLeaf::new(2, vec![Status::Success]),
+    ]);
+    let mut ctx = TickCtx::new(3);
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.ticks, vec![1, 2, 0]);
+}
@@ -0,0 +1,174 @@
+use std::{
+    fmt,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use arbor_core::{Node, Status};
+
+use crate::{Expr, Model, Recorder, build};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub ticks: Vec<usize>,
+    pub resets: Vec<usize>,
+    pub leaf_statuses: Vec<Vec<Status>>,
+    pub root_statuses: Vec<Status>,
+}
+
+impl Trace {
+    pub fn new(leaves: usize) -> Self {
+        Self {
+            ticks: vec![0; leaves],
+            resets: vec![0; leaves],
+            leaf_statuses: vec![Vec::new(); leaves],
+            root_statuses: Vec::new(),
+        }
+    }
+
+    pub fn leaves(&self) -> usize {
+        self.ticks.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    LeafCount {
+        runtime: usize,
+        model: usize,
+    },
+    Status {
+        cycle: usize,
+        runtime: Status,
+        model: Status,
+    },
+    Leaves {
+        cycle: usize,
+        runtime: Box<Trace>,
+        model: Box<Trace>,
+    },
+    // The runtime tick was still pending after `MAX_POLLS` polls.
+    Pending {
+        cycle: usize,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Divergence::LeafCount { runtime, model } => write!(
+                f,
+                "runtime tree has {runtime} recorded leaves but the expression has {model}"
+            ),
+            Divergence::Status {
+                cycle,
+                runtime,
+                model,
+            } => write!(
+                f,
+                "cycle {cycle}: runtime returned {runtime:?}, model returned {model:?}"
+            ),
+            Divergence::Leaves {
+                cycle,
+                runtime,
+                model,
+            } => {
+                write!(f, "cycle {cycle}: leaf activity differs")?;
+                for leaf in 0..runtime.leaves() {
+                    if runtime.ticks[leaf] != model.ticks[leaf]
+                        || runtime.resets[leaf] != model.resets[leaf]
+                    {
+                        write!(
+                            f,
+                            "; leaf {leaf} runtime {}/{} model {}/{} ticks/resets",
+                            runtime.ticks[leaf],
+                            runtime.resets[leaf],
+                            model.ticks[leaf],
+                            model.resets[leaf]
+                        )?;
+                    }
+                }
+                Ok(())
+            }
+            Divergence::Pending { cycle } => write!(
+                f,
+                "cycle {cycle}: runtime tick still pending after {MAX_POLLS} polls; trees checked against the model must not wait on timers or other wakers"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+// Ticks `tree` and the reference model of `expr` side by side for `cycles`
+// ticks. `tree` must take its leaves from `recorder` in the same depth-first
+// order as `expr`, which is what `build` does; hand-built trees can be
+// checked as long as they keep to that order. Each tick is polled with a
+// no-op waker, so a tree that waits on timers or I/O is reported as
+// `Divergence::Pending`.
+pub fn check<Ctx, N>(
+    tree: &mut N,
+    ctx: &mut Ctx,
+    recorder: &Recorder,
+    expr: &Expr,
+    cycles: usize,
+) -> Result<Trace, Divergence>
+where
+    N: Node<Ctx>,
+{
+    let mut model = Model::new(expr);
+    if recorder.leaves() != model.trace().leaves() {
+        return Err(Divergence::LeafCount {
+            runtime: recorder.leaves(),
+            model: model.trace().leaves(),
+        });
+    }
+
+    for cycle in 0..cycles {
+        let Some(runtime_status) = poll_to_completion(tree.tick(ctx)) else {
+            return Err(Divergence::Pending { cycle });
+        };
+        recorder.record_root(runtime_status);
+        let model_status = model.tick();
+
+        if runtime_status != model_status {
+            return Err(Divergence::Status {
+                cycle,
+                runtime: runtime_status,
+                model: model_status,
+            });
+        }
+
+        let runtime = recorder.trace();
+        if runtime != *model.trace() {
+            return Err(Divergence::Leaves {
+                cycle,
+                runtime: Box::new(runtime),
+                model: Box::new(model.trace().clone()),
+            });
+        }
+    }
+
+    Ok(recorder.trace())
+}
+
+pub fn compare(expr: &Expr, cycles: usize) -> Result<Trace, Divergence> {
+    let recorder = Recorder::new();
+    let mut tree = build(expr, &recorder);
+    check(&mut tree, &mut (), &recorder, expr, cycles)
+}
+
+// Nothing ever wakes the no-op waker, so a tick that waits on a timer or
+// channel would otherwise spin forever.
+const MAX_POLLS: usize = 1024;
+
+fn poll_to_completion<F: Future>(future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    for _ in 0..MAX_POLLS {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Some(output);
+        }
+    }
+    None
+}
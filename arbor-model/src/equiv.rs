@@ -0,0 +1,285 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use arbor_core::Status;
+
+use crate::{
+    Expr, Trace,
+    model::{Cx, ModelNode},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquivalenceError {
+    DuplicateLabel(&'static str),
+    AllowedMismatch(&'static str),
+    UnlabeledChoice,
+}
+
+impl fmt::Display for EquivalenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EquivalenceError::DuplicateLabel(label) => {
+                write!(f, "leaf label `{label}` is used more than once in one tree")
+            }
+            EquivalenceError::AllowedMismatch(label) => {
+                write!(
+                    f,
+                    "leaf `{label}` allows different statuses in the two trees"
+                )
+            }
+            EquivalenceError::UnlabeledChoice => {
+                write!(
+                    f,
+                    "choice leaves must be labeled to be matched across trees"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EquivalenceError {}
+
+// What one tree did during a tick: its root status and the labeled leaves
+// it ticked, sorted by label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Observation {
+    pub status: Status,
+    pub ticked: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickOutcome {
+    pub outcomes: Vec<(&'static str, Status)>,
+    pub left: Observation,
+    pub right: Observation,
+}
+
+// The shortest run after which the trees disagree. Every tick but the last
+// has matching observations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    pub ticks: Vec<TickOutcome>,
+}
+
+impl Counterexample {
+    pub fn divergent(&self) -> &TickOutcome {
+        self.ticks
+            .last()
+            .expect("a counterexample has at least one tick")
+    }
+}
+
+impl fmt::Display for Observation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} ticking [{}]", self.status, self.ticked.join(", "))
+    }
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "trees diverge at tick {}", self.ticks.len() - 1)?;
+        for (index, tick) in self.ticks.iter().enumerate() {
+            write!(f, "\n  tick {index}:")?;
+            for (label, status) in &tick.outcomes {
+                write!(f, " {label}={status:?}")?;
+            }
+            write!(f, "\n    left:  {}\n    right: {}", tick.left, tick.right)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Counterexample {}
+
+struct Side {
+    root: ModelNode,
+    labels: Vec<Option<&'static str>>,
+}
+
+impl Side {
+    fn new(expr: &Expr) -> Result<(Self, HashMap<&'static str, Vec<Status>>), EquivalenceError> {
+        let labels = expr.leaf_labels();
+        let mut allowed = HashMap::new();
+        let mut seen = HashSet::new();
+        let mut leaves = Vec::new();
+        collect_leaves(expr, &mut leaves);
+
+        for (label, leaf) in labels.iter().zip(leaves) {
+            match (label, leaf) {
+                (Some(label), _) if !seen.insert(*label) => {
+                    return Err(EquivalenceError::DuplicateLabel(label));
+                }
+                (Some(label), Expr::Choice(statuses)) => {
+                    allowed.insert(*label, statuses.clone());
+                }
+                (None, Expr::Choice(_)) => return Err(EquivalenceError::UnlabeledChoice),
+                _ => {}
+            }
+        }
+
+        let mut count = 0;
+        let root = ModelNode::new(expr, &mut count);
+        Ok((Self { root, labels }, allowed))
+    }
+
+    fn observe(&self, status: Status, trace: &Trace) -> Observation {
+        let mut ticked = Vec::new();
+        for (label, ticks) in self.labels.iter().zip(&trace.ticks) {
+            if let Some(label) = label {
+                ticked.extend((0..*ticks).map(|_| *label));
+            }
+        }
+        ticked.sort_unstable();
+        Observation { status, ticked }
+    }
+}
+
+fn collect_leaves<'a>(expr: &'a Expr, leaves: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::Leaf { .. } | Expr::Choice(_) => leaves.push(expr),
+        expr => expr
+            .children()
+            .iter()
+            .for_each(|child| collect_leaves(child, leaves)),
+    }
+}
+
+struct Visit {
+    left: ModelNode,
+    right: ModelNode,
+    parent: Option<(usize, TickOutcome)>,
+}
+
+// Checks two trees against each other over every outcome of their labeled
+// choice leaves. A label ticked by both trees in the same tick gets the same
+// status in both, since both stand for the same condition or action.
+pub struct Equivalence {
+    left: Side,
+    right: Side,
+}
+
+impl Equivalence {
+    pub fn new(left: &Expr, right: &Expr) -> Result<Self, EquivalenceError> {
+        let (left, left_allowed) = Side::new(left)?;
+        let (right, right_allowed) = Side::new(right)?;
+        for (label, statuses) in &left_allowed {
+            if right_allowed
+                .get(label)
+                .is_some_and(|other| other != statuses)
+            {
+                return Err(EquivalenceError::AllowedMismatch(label));
+            }
+        }
+        Ok(Self { left, right })
+    }
+
+    // Searches breadth-first over pairs of tree states, so the first
+    // divergence found is one with the fewest ticks. Pairs already reached
+    // at an earlier or equal depth are not expanded again.
+    pub fn check(&self, ticks: usize) -> Result<(), Counterexample> {
+        let mut visits = vec![Visit {
+            left: self.left.root.clone(),
+            right: self.right.root.clone(),
+            parent: None,
+        }];
+        let mut seen = HashSet::from([(self.left.root.clone(), self.right.root.clone())]);
+        let mut frontier = vec![0];
+
+        for _ in 0..ticks {
+            let mut next_frontier = Vec::new();
+            for index in frontier {
+                for (tick, left, right) in self.successors(&visits[index]) {
+                    if tick.left != tick.right {
+                        return Err(self.counterexample(&visits, index, tick));
+                    }
+                    if seen.insert((left.clone(), right.clone())) {
+                        next_frontier.push(visits.len());
+                        visits.push(Visit {
+                            left,
+                            right,
+                            parent: Some((index, tick)),
+                        });
+                    }
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        Ok(())
+    }
+
+    fn counterexample(
+        &self,
+        visits: &[Visit],
+        mut index: usize,
+        last: TickOutcome,
+    ) -> Counterexample {
+        let mut ticks = vec![last];
+        while let Some((parent, tick)) = &visits[index].parent {
+            ticks.push(tick.clone());
+            index = *parent;
+        }
+        ticks.reverse();
+        Counterexample { ticks }
+    }
+
+    // Replays one tick of both trees per combination of fresh choices, in
+    // the same prefix order the explorer uses. The right tree reuses any
+    // outcome the left tree already chose for a label.
+    fn successors(&self, visit: &Visit) -> Vec<(TickOutcome, ModelNode, ModelNode)> {
+        let mut found = Vec::new();
+        let mut prefix: Vec<usize> = Vec::new();
+
+        loop {
+            let mut made: Vec<(usize, usize)> = Vec::new();
+            let mut outcomes: Vec<(&'static str, Status)> = Vec::new();
+            let mut choose = |labels: &[Option<&'static str>], leaf: usize, allowed: &[Status]| {
+                let label = labels[leaf].expect("choice leaves are labeled");
+                if let Some((_, status)) = outcomes.iter().find(|(seen, _)| *seen == label) {
+                    return *status;
+                }
+                let index = prefix.get(made.len()).copied().unwrap_or(0);
+                made.push((index, allowed.len()));
+                outcomes.push((label, allowed[index]));
+                allowed[index]
+            };
+
+            let mut left = visit.left.clone();
+            let mut left_trace = Trace::new(self.left.labels.len());
+            let left_status = left.tick(&mut Cx {
+                trace: &mut left_trace,
+                choose: &mut |leaf, allowed| choose(&self.left.labels, leaf, allowed),
+            });
+
+            let mut right = visit.right.clone();
+            let mut right_trace = Trace::new(self.right.labels.len());
+            let right_status = right.tick(&mut Cx {
+                trace: &mut right_trace,
+                choose: &mut |leaf, allowed| choose(&self.right.labels, leaf, allowed),
+            });
+
+            found.push((
+                TickOutcome {
+                    outcomes,
+                    left: self.left.observe(left_status, &left_trace),
+                    right: self.right.observe(right_status, &right_trace),
+                },
+                left,
+                right,
+            ));
+
+            match made
+                .iter()
+                .rposition(|(index, options)| index + 1 < *options)
+            {
+                Some(last) => {
+                    prefix = made[..=last].iter().map(|(index, _)| *index).collect();
+                    prefix[last] += 1;
+                }
+                None => return found,
+            }
+        }
+    }
+}
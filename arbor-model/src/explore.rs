@@ -0,0 +1,236 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use arbor_core::Status;
+
+use crate::{
+    Expr, Trace,
+    model::{Cx, ModelNode},
+};
+
+// One decision made by a choice leaf during a tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Step {
+    pub leaf: usize,
+    pub status: Status,
+}
+
+// Choice-leaf decisions per tick that lead to a root status trace.
+pub type Witness = Vec<Vec<Step>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExploreStats {
+    pub states: usize,
+    pub expanded: usize,
+    pub revisited: usize,
+    pub transitions: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Exploration {
+    ticks: usize,
+    traces: Vec<(Vec<Status>, Witness)>,
+    stats: ExploreStats,
+}
+
+impl Exploration {
+    pub const fn ticks(&self) -> usize {
+        self.ticks
+    }
+
+    // Every reachable root status trace of exactly `ticks` ticks, in a
+    // stable order (success before failure before running, tick by tick).
+    pub fn traces(&self) -> impl Iterator<Item = &[Status]> {
+        self.traces.iter().map(|(trace, _)| trace.as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.traces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.traces.is_empty()
+    }
+
+    // Shorter traces match as prefixes: every tick can follow any state, so
+    // a reachable prefix always extends to a full trace.
+    pub fn contains(&self, trace: &[Status]) -> bool {
+        self.witness(trace).is_some()
+    }
+
+    // The choice-leaf decisions of one run that produces `trace`.
+    pub fn witness(&self, trace: &[Status]) -> Option<&[Vec<Step>]> {
+        self.traces
+            .iter()
+            .find(|(candidate, _)| candidate.starts_with(trace))
+            .map(|(_, witness)| &witness[..trace.len()])
+    }
+
+    pub fn statuses_at(&self, tick: usize) -> Vec<Status> {
+        let mut statuses: Vec<Status> = Vec::new();
+        for (trace, _) in &self.traces {
+            if let Some(status) = trace.get(tick)
+                && !statuses.contains(status)
+            {
+                statuses.push(*status);
+            }
+        }
+        statuses.sort_by_key(|status| rank(*status));
+        statuses
+    }
+
+    pub const fn stats(&self) -> ExploreStats {
+        self.stats
+    }
+}
+
+type Suffixes = Rc<HashMap<Vec<Status>, Witness>>;
+
+// Enumerates every outcome of the choice leaves in `expr`, tick by tick.
+// It runs the reference model, not a runtime tree: the states it hashes are
+// model states, and `compare` is what ties a runtime tree to the model.
+// Tree states are hashed and a state seen again with the same number of
+// ticks left reuses the traces found the first time, so the work grows with
+// the number of distinct states rather than the number of outcome
+// sequences. The traces themselves can still grow exponentially with
+// `ticks`.
+pub struct Explorer {
+    root: ModelNode,
+    leaves: usize,
+}
+
+impl Explorer {
+    pub fn new(expr: &Expr) -> Self {
+        let mut leaves = 0;
+        let root = ModelNode::new(expr, &mut leaves);
+        Self { root, leaves }
+    }
+
+    pub fn explore(&self, ticks: usize) -> Exploration {
+        let mut search = Search {
+            leaves: self.leaves,
+            memo: HashMap::new(),
+            seen: HashSet::new(),
+            stats: ExploreStats::default(),
+        };
+        search.seen.insert(self.root.clone());
+
+        let suffixes = search.suffixes(&self.root, ticks);
+        let mut traces: Vec<(Vec<Status>, Witness)> = suffixes
+            .iter()
+            .map(|(trace, witness)| (trace.clone(), witness.clone()))
+            .collect();
+        traces.sort_by(|(a, _), (b, _)| {
+            let key = |trace: &Vec<Status>| trace.iter().map(|s| rank(*s)).collect::<Vec<_>>();
+            key(a).cmp(&key(b))
+        });
+
+        search.stats.states = search.seen.len();
+        Exploration {
+            ticks,
+            traces,
+            stats: search.stats,
+        }
+    }
+}
+
+struct Search {
+    leaves: usize,
+    memo: HashMap<(ModelNode, usize), Suffixes>,
+    seen: HashSet<ModelNode>,
+    stats: ExploreStats,
+}
+
+impl Search {
+    fn suffixes(&mut self, state: &ModelNode, remaining: usize) -> Suffixes {
+        if remaining == 0 {
+            return Rc::new(HashMap::from([(Vec::new(), Vec::new())]));
+        }
+
+        let key = (state.clone(), remaining);
+        if let Some(found) = self.memo.get(&key) {
+            self.stats.revisited += 1;
+            return found.clone();
+        }
+        self.stats.expanded += 1;
+
+        let mut out: HashMap<Vec<Status>, Witness> = HashMap::new();
+        for (status, next, steps) in self.successors(state) {
+            self.seen.insert(next.clone());
+            let rest = self.suffixes(&next, remaining - 1);
+            for (suffix, witness) in rest.iter() {
+                let mut trace = Vec::with_capacity(remaining);
+                trace.push(status);
+                trace.extend_from_slice(suffix);
+                out.entry(trace).or_insert_with(|| {
+                    let mut full = Vec::with_capacity(remaining);
+                    full.push(steps.clone());
+                    full.extend_from_slice(witness);
+                    full
+                });
+            }
+        }
+
+        let out = Rc::new(out);
+        self.memo.insert(key, out.clone());
+        out
+    }
+
+    // Replays one tick from `state` once per combination of choices. Each
+    // run follows a prefix of earlier decisions and takes the first option
+    // past it; the next prefix bumps the last decision that has options
+    // left.
+    fn successors(&mut self, state: &ModelNode) -> Vec<(Status, ModelNode, Vec<Step>)> {
+        let mut found: Vec<(Status, ModelNode, Vec<Step>)> = Vec::new();
+        let mut prefix: Vec<usize> = Vec::new();
+
+        loop {
+            let mut next = state.clone();
+            let mut trace = Trace::new(self.leaves);
+            let mut made: Vec<(usize, usize)> = Vec::new();
+            let mut steps = Vec::new();
+            let mut choose = |leaf: usize, allowed: &[Status]| {
+                let index = prefix.get(made.len()).copied().unwrap_or(0);
+                made.push((index, allowed.len()));
+                steps.push(Step {
+                    leaf,
+                    status: allowed[index],
+                });
+                allowed[index]
+            };
+            let status = next.tick(&mut Cx {
+                trace: &mut trace,
+                choose: &mut choose,
+            });
+            self.stats.transitions += 1;
+
+            if !found
+                .iter()
+                .any(|(seen, state, _)| *seen == status && *state == next)
+            {
+                found.push((status, next, steps));
+            }
+
+            match made
+                .iter()
+                .rposition(|(index, options)| index + 1 < *options)
+            {
+                Some(last) => {
+                    prefix = made[..=last].iter().map(|(index, _)| *index).collect();
+                    prefix[last] += 1;
+                }
+                None => return found,
+            }
+        }
+    }
+}
+
+fn rank(status: Status) -> u8 {
+    match status {
+        Status::Success => 0,
+        Status::Failure => 1,
+        Status::Running => 2,
+    }
+}
@@ -0,0 +1,186 @@
+use arbor_core::{Memory, ParallelError, ParallelPolicy, Status};
+
+// The parallel policies the model implements. `ParallelPolicy::Custom` is
+// left out: an arbitrary function has no reference semantics to check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Policy {
+    SuccessOnAllFailureOnAny,
+    SuccessOnAnyFailureOnAll,
+    SuccessThreshold(usize),
+    Thresholds { success: usize, failure: usize },
+    MainChild(usize),
+}
+
+impl From<Policy> for ParallelPolicy {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::SuccessOnAllFailureOnAny => ParallelPolicy::SuccessOnAllFailureOnAny,
+            Policy::SuccessOnAnyFailureOnAll => ParallelPolicy::SuccessOnAnyFailureOnAll,
+            Policy::SuccessThreshold(success) => ParallelPolicy::SuccessThreshold(success),
+            Policy::Thresholds { success, failure } => {
+                ParallelPolicy::Thresholds { success, failure }
+            }
+            Policy::MainChild(index) => ParallelPolicy::MainChild(index),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Leaf {
+        script: Vec<Status>,
+        rewind_on_reset: bool,
+    },
+    // A leaf that may return any of the listed statuses on each tick. The
+    // explorer branches on every option; `Model::tick` and `build` take the
+    // first. An empty list always fails.
+    Choice(Vec<Status>),
+    Sequence {
+        children: Vec<Expr>,
+        memory: Memory,
+    },
+    Selector {
+        children: Vec<Expr>,
+        memory: Memory,
+    },
+    ReactiveSequence(Vec<Expr>),
+    ReactiveSelector(Vec<Expr>),
+    Parallel {
+        policy: Policy,
+        children: Vec<Expr>,
+    },
+    ParallelWithMemory {
+        policy: Policy,
+        children: Vec<Expr>,
+    },
+    Inverter(Box<Expr>),
+    Retry {
+        child: Box<Expr>,
+        max_failures: usize,
+    },
+    Repeat {
+        child: Box<Expr>,
+        max_successes: usize,
+    },
+    ForceSuccess(Box<Expr>),
+    ForceFailure(Box<Expr>),
+    // Transparent, like arbor-core's `Named`. A name directly around a leaf
+    // labels it, which is how `Equivalence` matches leaves across trees.
+    Named {
+        label: &'static str,
+        child: Box<Expr>,
+    },
+}
+
+impl Expr {
+    pub fn leaf(script: impl Into<Vec<Status>>) -> Self {
+        Expr::Leaf {
+            script: script.into(),
+            rewind_on_reset: true,
+        }
+    }
+
+    pub fn choice(allowed: impl Into<Vec<Status>>) -> Self {
+        Expr::Choice(allowed.into())
+    }
+
+    pub fn any() -> Self {
+        Expr::Choice(vec![Status::Success, Status::Failure, Status::Running])
+    }
+
+    pub fn named(self, label: &'static str) -> Self {
+        Expr::Named {
+            label,
+            child: Box::new(self),
+        }
+    }
+
+    pub fn sequence(children: impl Into<Vec<Expr>>) -> Self {
+        Expr::Sequence {
+            children: children.into(),
+            memory: Memory::Resume,
+        }
+    }
+
+    pub fn selector(children: impl Into<Vec<Expr>>) -> Self {
+        Expr::Selector {
+            children: children.into(),
+            memory: Memory::Resume,
+        }
+    }
+
+    pub fn children(&self) -> &[Expr] {
+        match self {
+            Expr::Leaf { .. } | Expr::Choice(_) => &[],
+            Expr::Sequence { children, .. }
+            | Expr::Selector { children, .. }
+            | Expr::ReactiveSequence(children)
+            | Expr::ReactiveSelector(children)
+            | Expr::Parallel { children, .. }
+            | Expr::ParallelWithMemory { children, .. } => children,
+            Expr::Inverter(child)
+            | Expr::Retry { child, .. }
+            | Expr::Repeat { child, .. }
+            | Expr::ForceSuccess(child)
+            | Expr::ForceFailure(child)
+            | Expr::Named { child, .. } => core::slice::from_ref(child),
+        }
+    }
+
+    // Leaves are numbered in this depth-first order by both the model and
+    // the runtime builder.
+    pub fn leaf_count(&self) -> usize {
+        match self {
+            Expr::Leaf { .. } | Expr::Choice(_) => 1,
+            expr => expr.children().iter().map(Expr::leaf_count).sum(),
+        }
+    }
+
+    // The label of each leaf, indexed like `leaf_count` numbers them.
+    pub fn leaf_labels(&self) -> Vec<Option<&'static str>> {
+        let mut labels = Vec::with_capacity(self.leaf_count());
+        self.collect_labels(None, &mut labels);
+        labels
+    }
+
+    fn collect_labels(&self, label: Option<&'static str>, labels: &mut Vec<Option<&'static str>>) {
+        match self {
+            Expr::Leaf { .. } | Expr::Choice(_) => labels.push(label),
+            Expr::Named { label, child } => child.collect_labels(Some(label), labels),
+            expr => expr
+                .children()
+                .iter()
+                .for_each(|child| child.collect_labels(None, labels)),
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        1 + self.children().iter().map(Expr::node_count).sum::<usize>()
+    }
+
+    pub fn depth(&self) -> usize {
+        1 + self.children().iter().map(Expr::depth).max().unwrap_or(0)
+    }
+
+    // The same checks `Parallel::with_policy` and
+    // `ParallelWithMemory::with_policy` make; the model and the runtime
+    // builder both panic on expressions that fail them.
+    pub fn validate(&self) -> Result<(), ParallelError> {
+        match self {
+            Expr::Parallel { policy, children } => {
+                ParallelPolicy::from(*policy).validate(children.len())?;
+            }
+            Expr::ParallelWithMemory { policy, children } => {
+                if children.len() > u64::BITS as usize {
+                    return Err(ParallelError::TooManyChildren {
+                        children: children.len(),
+                        max: u64::BITS as usize,
+                    });
+                }
+                ParallelPolicy::from(*policy).validate(children.len())?;
+            }
+            _ => {}
+        }
+        self.children().iter().try_for_each(Expr::validate)
+    }
+}
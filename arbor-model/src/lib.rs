@@ -0,0 +1,19 @@
+mod compare;
+mod equiv;
+mod explore;
+mod expr;
+mod model;
+mod runtime;
+mod sensitivity;
+mod smv;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub use compare::{Divergence, Trace, check, compare};
+pub use equiv::{Counterexample, Equivalence, EquivalenceError, Observation, TickOutcome};
+pub use explore::{Exploration, ExploreStats, Explorer, Step, Witness};
+pub use expr::{Expr, Policy};
+pub use model::Model;
+pub use runtime::{Recorder, Runtime, ScriptLeaf, build};
+pub use sensitivity::{Flip, Recording, Sensitivity, SensitivityError};
+pub use smv::{ExportError, Simulation, Smv};
@@ -0,0 +1,496 @@
+use arbor_core::{Memory, Status};
+
+use crate::{Expr, Policy, Trace};
+
+// A direct, allocation-happy restatement of Arbor's tick and reset rules,
+// kept deliberately separate from the runtime so the two can disagree.
+pub struct Model {
+    root: ModelNode,
+    trace: Trace,
+}
+
+impl Model {
+    pub fn new(expr: &Expr) -> Self {
+        let mut next_leaf = 0;
+        let root = ModelNode::new(expr, &mut next_leaf);
+        Self {
+            root,
+            trace: Trace::new(next_leaf),
+        }
+    }
+
+    // Choice leaves return their first allowed status.
+    pub fn tick(&mut self) -> Status {
+        self.tick_with(|_, allowed| allowed[0])
+    }
+
+    // `choose` is called with the leaf id and allowed statuses each time a
+    // choice leaf is ticked, and must return one of them.
+    pub fn tick_with(&mut self, mut choose: impl FnMut(usize, &[Status]) -> Status) -> Status {
+        let mut cx = Cx {
+            trace: &mut self.trace,
+            choose: &mut choose,
+        };
+        let status = self.root.tick(&mut cx);
+        self.trace.root_statuses.push(status);
+        status
+    }
+
+    pub fn reset(&mut self) {
+        self.root.reset(&mut Cx {
+            trace: &mut self.trace,
+            choose: &mut |_, allowed| allowed[0],
+        });
+    }
+
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+}
+
+pub(crate) struct Cx<'a> {
+    pub(crate) trace: &'a mut Trace,
+    pub(crate) choose: &'a mut dyn FnMut(usize, &[Status]) -> Status,
+}
+
+// Everything a tree carries between ticks. Two equal nodes behave the same
+// from here on, which is what the explorer's pruning relies on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum ModelNode {
+    Leaf {
+        id: usize,
+        script: Vec<Status>,
+        cursor: usize,
+        rewind_on_reset: bool,
+    },
+    Choice {
+        id: usize,
+        allowed: Vec<Status>,
+    },
+    Sequence {
+        children: Vec<ModelNode>,
+        memory: Memory,
+        running_index: usize,
+    },
+    Selector {
+        children: Vec<ModelNode>,
+        memory: Memory,
+        running_index: usize,
+    },
+    ReactiveSequence(Vec<ModelNode>),
+    ReactiveSelector(Vec<ModelNode>),
+    Parallel {
+        policy: Policy,
+        children: Vec<ModelNode>,
+    },
+    ParallelWithMemory {
+        policy: Policy,
+        children: Vec<ModelNode>,
+        completed: Vec<Option<Status>>,
+    },
+    Inverter(Box<ModelNode>),
+    Retry {
+        child: Box<ModelNode>,
+        max_failures: usize,
+        failures: usize,
+    },
+    Repeat {
+        child: Box<ModelNode>,
+        max_successes: usize,
+        successes: usize,
+    },
+    ForceSuccess(Box<ModelNode>),
+    ForceFailure(Box<ModelNode>),
+}
+
+impl ModelNode {
+    pub(crate) fn new(expr: &Expr, next_leaf: &mut usize) -> Self {
+        if let Err(error) = expr.validate() {
+            panic!("invalid expression: {error}");
+        }
+        Self::build(expr, next_leaf)
+    }
+
+    fn build(expr: &Expr, next_leaf: &mut usize) -> Self {
+        let mut all = |children: &[Expr]| -> Vec<ModelNode> {
+            children
+                .iter()
+                .map(|child| ModelNode::build(child, next_leaf))
+                .collect()
+        };
+
+        match expr {
+            Expr::Leaf {
+                script,
+                rewind_on_reset,
+            } => {
+                let id = *next_leaf;
+                *next_leaf += 1;
+                ModelNode::Leaf {
+                    id,
+                    script: script.clone(),
+                    cursor: 0,
+                    rewind_on_reset: *rewind_on_reset,
+                }
+            }
+            Expr::Choice(allowed) => {
+                let id = *next_leaf;
+                *next_leaf += 1;
+                ModelNode::Choice {
+                    id,
+                    allowed: allowed.clone(),
+                }
+            }
+            Expr::Sequence { children, memory } => ModelNode::Sequence {
+                children: all(children),
+                memory: *memory,
+                running_index: 0,
+            },
+            Expr::Selector { children, memory } => ModelNode::Selector {
+                children: all(children),
+                memory: *memory,
+                running_index: 0,
+            },
+            Expr::ReactiveSequence(children) => ModelNode::ReactiveSequence(all(children)),
+            Expr::ReactiveSelector(children) => ModelNode::ReactiveSelector(all(children)),
+            Expr::Parallel { policy, children } => ModelNode::Parallel {
+                policy: *policy,
+                children: all(children),
+            },
+            Expr::ParallelWithMemory { policy, children } => ModelNode::ParallelWithMemory {
+                policy: *policy,
+                completed: vec![None; children.len()],
+                children: all(children),
+            },
+            Expr::Inverter(child) => {
+                ModelNode::Inverter(Box::new(ModelNode::build(child, next_leaf)))
+            }
+            Expr::Retry {
+                child,
+                max_failures,
+            } => ModelNode::Retry {
+                child: Box::new(ModelNode::build(child, next_leaf)),
+                max_failures: *max_failures,
+                failures: 0,
+            },
+            Expr::Repeat {
+                child,
+                max_successes,
+            } => ModelNode::Repeat {
+                child: Box::new(ModelNode::build(child, next_leaf)),
+                max_successes: *max_successes,
+                successes: 0,
+            },
+            Expr::ForceSuccess(child) => {
+                ModelNode::ForceSuccess(Box::new(ModelNode::build(child, next_leaf)))
+            }
+            Expr::ForceFailure(child) => {
+                ModelNode::ForceFailure(Box::new(ModelNode::build(child, next_leaf)))
+            }
+            Expr::Named { child, .. } => ModelNode::build(child, next_leaf),
+        }
+    }
+
+    pub(crate) fn tick(&mut self, cx: &mut Cx<'_>) -> Status {
+        match self {
+            ModelNode::Leaf {
+                id, script, cursor, ..
+            } => {
+                let status = script
+                    .get(*cursor)
+                    .or(script.last())
+                    .copied()
+                    .unwrap_or(Status::Failure);
+                if *cursor + 1 < script.len() {
+                    *cursor += 1;
+                }
+                cx.trace.ticks[*id] += 1;
+                cx.trace.leaf_statuses[*id].push(status);
+                status
+            }
+            ModelNode::Choice { id, allowed } => {
+                let status = if allowed.is_empty() {
+                    Status::Failure
+                } else {
+                    (cx.choose)(*id, allowed)
+                };
+                cx.trace.ticks[*id] += 1;
+                cx.trace.leaf_statuses[*id].push(status);
+                status
+            }
+            ModelNode::Sequence {
+                children,
+                memory,
+                running_index,
+            } => ordered(children, *memory, running_index, Status::Failure, cx),
+            ModelNode::Selector {
+                children,
+                memory,
+                running_index,
+            } => ordered(children, *memory, running_index, Status::Success, cx),
+            ModelNode::ReactiveSequence(children) => reactive(children, Status::Failure, cx),
+            ModelNode::ReactiveSelector(children) => reactive(children, Status::Success, cx),
+            ModelNode::Parallel { policy, children } => {
+                let statuses: Vec<Status> =
+                    children.iter_mut().map(|child| child.tick(cx)).collect();
+
+                let status = decide(*policy, &statuses);
+                if status != Status::Running {
+                    reset_from(children, 0, cx);
+                }
+                status
+            }
+            ModelNode::ParallelWithMemory {
+                policy,
+                children,
+                completed,
+            } => {
+                for (slot, child) in completed.iter_mut().zip(children.iter_mut()) {
+                    if slot.is_none() {
+                        let status = child.tick(cx);
+                        if status != Status::Running {
+                            *slot = Some(status);
+                        }
+                    }
+                }
+
+                let statuses: Vec<Status> = completed
+                    .iter()
+                    .map(|slot| slot.unwrap_or(Status::Running))
+                    .collect();
+                let status = decide(*policy, &statuses);
+                if status != Status::Running {
+                    self.reset(cx);
+                }
+                status
+            }
+            ModelNode::Inverter(child) => match child.tick(cx) {
+                Status::Running => Status::Running,
+                status => {
+                    child.reset(cx);
+                    if status == Status::Success {
+                        Status::Failure
+                    } else {
+                        Status::Success
+                    }
+                }
+            },
+            ModelNode::Retry {
+                child,
+                max_failures,
+                failures,
+            } => {
+                if *max_failures == 0 {
+                    child.reset(cx);
+                    return Status::Failure;
+                }
+
+                match child.tick(cx) {
+                    Status::Running => Status::Running,
+                    Status::Success => {
+                        *failures = 0;
+                        child.reset(cx);
+                        Status::Success
+                    }
+                    Status::Failure => {
+                        *failures += 1;
+                        child.reset(cx);
+                        if *failures < *max_failures {
+                            return Status::Running;
+                        }
+                        *failures = 0;
+                        Status::Failure
+                    }
+                }
+            }
+            ModelNode::Repeat {
+                child,
+                max_successes,
+                successes,
+            } => {
+                if *max_successes == 0 {
+                    child.reset(cx);
+                    return Status::Success;
+                }
+
+                match child.tick(cx) {
+                    Status::Running => Status::Running,
+                    Status::Failure => {
+                        *successes = 0;
+                        child.reset(cx);
+                        Status::Failure
+                    }
+                    Status::Success => {
+                        *successes += 1;
+                        child.reset(cx);
+                        if *successes < *max_successes {
+                            return Status::Running;
+                        }
+                        *successes = 0;
+                        Status::Success
+                    }
+                }
+            }
+            ModelNode::ForceSuccess(child) => forced(child, Status::Success, cx),
+            ModelNode::ForceFailure(child) => forced(child, Status::Failure, cx),
+        }
+    }
+
+    pub(crate) fn reset(&mut self, cx: &mut Cx<'_>) {
+        match self {
+            ModelNode::Leaf {
+                id,
+                cursor,
+                rewind_on_reset,
+                ..
+            } => {
+                cx.trace.resets[*id] += 1;
+                if *rewind_on_reset {
+                    *cursor = 0;
+                }
+            }
+            ModelNode::Choice { id, .. } => cx.trace.resets[*id] += 1,
+            ModelNode::Sequence {
+                children,
+                memory,
+                running_index,
+            }
+            | ModelNode::Selector {
+                children,
+                memory,
+                running_index,
+            } => match memory {
+                Memory::Resume => {
+                    *running_index = 0;
+                    reset_from(children, 0, cx);
+                }
+                Memory::Persistent => reset_from(children, *running_index, cx),
+            },
+            ModelNode::ReactiveSequence(children)
+            | ModelNode::ReactiveSelector(children)
+            | ModelNode::Parallel { children, .. } => reset_from(children, 0, cx),
+            ModelNode::ParallelWithMemory {
+                children,
+                completed,
+                ..
+            } => {
+                completed.iter_mut().for_each(|slot| *slot = None);
+                reset_from(children, 0, cx);
+            }
+            ModelNode::Retry {
+                child, failures, ..
+            } => {
+                *failures = 0;
+                child.reset(cx);
+            }
+            ModelNode::Repeat {
+                child, successes, ..
+            } => {
+                *successes = 0;
+                child.reset(cx);
+            }
+            ModelNode::Inverter(child)
+            | ModelNode::ForceSuccess(child)
+            | ModelNode::ForceFailure(child) => child.reset(cx),
+        }
+    }
+}
+
+fn reset_from(children: &mut [ModelNode], start: usize, cx: &mut Cx<'_>) {
+    for child in children.iter_mut().skip(start) {
+        child.reset(cx);
+    }
+}
+
+// Sequence stops at the first failure, selector at the first success; both
+// resume a running child on the next tick.
+fn ordered(
+    children: &mut [ModelNode],
+    memory: Memory,
+    running_index: &mut usize,
+    stop: Status,
+    cx: &mut Cx<'_>,
+) -> Status {
+    let mut index = *running_index;
+    while index < children.len() {
+        let status = children[index].tick(cx);
+        if status == Status::Running {
+            *running_index = index;
+            return Status::Running;
+        }
+        if status == stop {
+            // Only a persistent sequence keeps its place after stopping; a
+            // selector that found a success is finished.
+            if memory == Memory::Persistent && stop == Status::Failure {
+                *running_index = index;
+                reset_from(children, index, cx);
+            } else {
+                *running_index = 0;
+                reset_from(children, 0, cx);
+            }
+            return stop;
+        }
+        index += 1;
+    }
+
+    *running_index = 0;
+    reset_from(children, 0, cx);
+    opposite(stop)
+}
+
+// Reactive composites restart from the first child every tick and halt the
+// children after the one that decided.
+fn reactive(children: &mut [ModelNode], stop: Status, cx: &mut Cx<'_>) -> Status {
+    for index in 0..children.len() {
+        let status = children[index].tick(cx);
+        if status == Status::Running || status == stop {
+            reset_from(children, index + 1, cx);
+            return status;
+        }
+    }
+
+    reset_from(children, 0, cx);
+    opposite(stop)
+}
+
+fn forced(child: &mut ModelNode, result: Status, cx: &mut Cx<'_>) -> Status {
+    match child.tick(cx) {
+        Status::Running => Status::Running,
+        _ => {
+            child.reset(cx);
+            result
+        }
+    }
+}
+
+fn opposite(status: Status) -> Status {
+    match status {
+        Status::Success => Status::Failure,
+        Status::Failure => Status::Success,
+        Status::Running => Status::Running,
+    }
+}
+
+fn decide(policy: Policy, statuses: &[Status]) -> Status {
+    let n = statuses.len();
+    let successes = statuses.iter().filter(|s| **s == Status::Success).count();
+    let failures = statuses.iter().filter(|s| **s == Status::Failure).count();
+
+    // Success wins ties; failure is declared once success can no longer be
+    // reached or the failure threshold is met.
+    let (success, failure) = match policy {
+        Policy::SuccessOnAllFailureOnAny => (n, 1),
+        Policy::SuccessOnAnyFailureOnAll => (1, n),
+        Policy::SuccessThreshold(success) => (success, n - success + 1),
+        Policy::Thresholds { success, failure } => (success, failure),
+        Policy::MainChild(index) => return statuses[index],
+    };
+
+    if successes >= success {
+        Status::Success
+    } else if failures >= failure || failures > n - success {
+        Status::Failure
+    } else {
+        Status::Running
+    }
+}
@@ -0,0 +1,293 @@
+use std::{cell::RefCell, future::Future, pin::Pin, rc::Rc};
+
+use arbor_core::{
+    ForceFailure, ForceSuccess, Inspect, Inverter, Named, Node, Parallel, ParallelWithMemory,
+    Params, ReactiveSelector, ReactiveSequence, Repeat, Retry, Selector, Sequence, Status,
+};
+
+use crate::{Expr, Trace};
+
+// Collects what the leaves handed out by `leaf` see. Leaves are numbered in
+// creation order, which `build` keeps depth-first to match the model.
+// `ScriptLeaf` is only meant for trees checked against an `Expr`; tests of
+// your own trees should use arbor-test's `ScriptedLeaf` and `Harness`.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    trace: Rc<RefCell<Trace>>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn leaf(&self, script: impl Into<Vec<Status>>, rewind_on_reset: bool) -> ScriptLeaf {
+        let mut trace = self.trace.borrow_mut();
+        let id = trace.ticks.len();
+        trace.ticks.push(0);
+        trace.resets.push(0);
+        trace.leaf_statuses.push(Vec::new());
+        ScriptLeaf {
+            id,
+            script: script.into(),
+            cursor: 0,
+            rewind_on_reset,
+            trace: self.trace.clone(),
+        }
+    }
+
+    pub fn leaves(&self) -> usize {
+        self.trace.borrow().ticks.len()
+    }
+
+    pub fn trace(&self) -> Trace {
+        self.trace.borrow().clone()
+    }
+
+    pub(crate) fn record_root(&self, status: Status) {
+        self.trace.borrow_mut().root_statuses.push(status);
+    }
+}
+
+// Plays its script one status per tick and repeats the last entry once the
+// script runs out. An empty script fails.
+#[derive(Debug)]
+pub struct ScriptLeaf {
+    id: usize,
+    script: Vec<Status>,
+    cursor: usize,
+    rewind_on_reset: bool,
+    trace: Rc<RefCell<Trace>>,
+}
+
+impl ScriptLeaf {
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+}
+
+impl<Ctx> Node<Ctx> for ScriptLeaf {
+    async fn tick(&mut self, _ctx: &mut Ctx) -> Status {
+        let status = self
+            .script
+            .get(self.cursor)
+            .or(self.script.last())
+            .copied()
+            .unwrap_or(Status::Failure);
+        if self.cursor + 1 < self.script.len() {
+            self.cursor += 1;
+        }
+
+        let mut trace = self.trace.borrow_mut();
+        trace.ticks[self.id] += 1;
+        trace.leaf_statuses[self.id].push(status);
+        status
+    }
+
+    fn reset(&mut self) {
+        self.trace.borrow_mut().resets[self.id] += 1;
+        if self.rewind_on_reset {
+            self.cursor = 0;
+        }
+    }
+}
+
+impl Inspect for ScriptLeaf {
+    fn kind(&self) -> &'static str {
+        "ScriptLeaf"
+    }
+}
+
+pub struct Boxed(Box<Runtime>);
+
+impl<Ctx> Node<Ctx> for Boxed {
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.0.tick_boxed(ctx).await
+    }
+
+    fn reset(&mut self) {
+        Node::<()>::reset(&mut *self.0);
+    }
+}
+
+impl Inspect for Boxed {
+    fn kind(&self) -> &'static str {
+        self.0.kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.0.label()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.0.params()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.0.inspect_children(visit);
+    }
+}
+
+// An `Expr` built from the real arbor-core nodes, with `ScriptLeaf`s at the
+// leaves. It runs over any context since the leaves ignore it.
+pub enum Runtime {
+    Leaf(ScriptLeaf),
+    Sequence(Sequence<Vec<Runtime>>),
+    Selector(Selector<Vec<Runtime>>),
+    ReactiveSequence(ReactiveSequence<Vec<Runtime>>),
+    ReactiveSelector(ReactiveSelector<Vec<Runtime>>),
+    Parallel(Parallel<Vec<Runtime>>),
+    ParallelWithMemory(ParallelWithMemory<Vec<Runtime>>),
+    Inverter(Inverter<Boxed>),
+    Retry(Retry<Boxed>),
+    Repeat(Repeat<Boxed>),
+    ForceSuccess(ForceSuccess<Boxed>),
+    ForceFailure(ForceFailure<Boxed>),
+    Named(Named<Boxed>),
+}
+
+impl Runtime {
+    // Nested runtimes would otherwise make an infinitely sized future.
+    fn tick_boxed<'a, Ctx>(
+        &'a mut self,
+        ctx: &'a mut Ctx,
+    ) -> Pin<Box<dyn Future<Output = Status> + 'a>> {
+        Box::pin(async move {
+            match self {
+                Runtime::Leaf(node) => node.tick(ctx).await,
+                Runtime::Sequence(node) => node.tick(ctx).await,
+                Runtime::Selector(node) => node.tick(ctx).await,
+                Runtime::ReactiveSequence(node) => node.tick(ctx).await,
+                Runtime::ReactiveSelector(node) => node.tick(ctx).await,
+                Runtime::Parallel(node) => node.tick(ctx).await,
+                Runtime::ParallelWithMemory(node) => node.tick(ctx).await,
+                Runtime::Inverter(node) => node.tick(ctx).await,
+                Runtime::Retry(node) => node.tick(ctx).await,
+                Runtime::Repeat(node) => node.tick(ctx).await,
+                Runtime::ForceSuccess(node) => node.tick(ctx).await,
+                Runtime::ForceFailure(node) => node.tick(ctx).await,
+                Runtime::Named(node) => node.tick(ctx).await,
+            }
+        })
+    }
+}
+
+impl<Ctx> Node<Ctx> for Runtime {
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.tick_boxed(ctx).await
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Runtime::Leaf(node) => Node::<Ctx>::reset(node),
+            Runtime::Sequence(node) => Node::<Ctx>::reset(node),
+            Runtime::Selector(node) => Node::<Ctx>::reset(node),
+            Runtime::ReactiveSequence(node) => Node::<Ctx>::reset(node),
+            Runtime::ReactiveSelector(node) => Node::<Ctx>::reset(node),
+            Runtime::Parallel(node) => Node::<Ctx>::reset(node),
+            Runtime::ParallelWithMemory(node) => Node::<Ctx>::reset(node),
+            Runtime::Inverter(node) => Node::<Ctx>::reset(node),
+            Runtime::Retry(node) => Node::<Ctx>::reset(node),
+            Runtime::Repeat(node) => Node::<Ctx>::reset(node),
+            Runtime::ForceSuccess(node) => Node::<Ctx>::reset(node),
+            Runtime::ForceFailure(node) => Node::<Ctx>::reset(node),
+            Runtime::Named(node) => Node::<Ctx>::reset(node),
+        }
+    }
+}
+
+impl Inspect for Runtime {
+    fn kind(&self) -> &'static str {
+        self.inspect().kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        self.inspect().label()
+    }
+
+    fn params(&self) -> Option<Params> {
+        self.inspect().params()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        self.inspect().inspect_children(visit);
+    }
+}
+
+impl Runtime {
+    fn inspect(&self) -> &dyn Inspect {
+        match self {
+            Runtime::Leaf(node) => node,
+            Runtime::Sequence(node) => node,
+            Runtime::Selector(node) => node,
+            Runtime::ReactiveSequence(node) => node,
+            Runtime::ReactiveSelector(node) => node,
+            Runtime::Parallel(node) => node,
+            Runtime::ParallelWithMemory(node) => node,
+            Runtime::Inverter(node) => node,
+            Runtime::Retry(node) => node,
+            Runtime::Repeat(node) => node,
+            Runtime::ForceSuccess(node) => node,
+            Runtime::ForceFailure(node) => node,
+            Runtime::Named(node) => node,
+        }
+    }
+}
+
+pub fn build(expr: &Expr, recorder: &Recorder) -> Runtime {
+    if let Err(error) = expr.validate() {
+        panic!("invalid expression: {error}");
+    }
+    build_node(expr, recorder)
+}
+
+fn build_node(expr: &Expr, recorder: &Recorder) -> Runtime {
+    let all = |children: &[Expr]| -> Vec<Runtime> {
+        children
+            .iter()
+            .map(|child| build_node(child, recorder))
+            .collect()
+    };
+    let boxed = |child: &Expr| Boxed(Box::new(build_node(child, recorder)));
+
+    match expr {
+        Expr::Leaf {
+            script,
+            rewind_on_reset,
+        } => Runtime::Leaf(recorder.leaf(script.clone(), *rewind_on_reset)),
+        Expr::Choice(allowed) => {
+            Runtime::Leaf(recorder.leaf(&allowed[..allowed.len().min(1)], true))
+        }
+        Expr::Sequence { children, memory } => {
+            Runtime::Sequence(Sequence::with_memory(all(children), *memory))
+        }
+        Expr::Selector { children, memory } => {
+            Runtime::Selector(Selector::with_memory(all(children), *memory))
+        }
+        Expr::ReactiveSequence(children) => {
+            Runtime::ReactiveSequence(ReactiveSequence::new(all(children)))
+        }
+        Expr::ReactiveSelector(children) => {
+            Runtime::ReactiveSelector(ReactiveSelector::new(all(children)))
+        }
+        Expr::Parallel { policy, children } => Runtime::Parallel(
+            Parallel::with_policy(all(children), (*policy).into()).expect("validated above"),
+        ),
+        Expr::ParallelWithMemory { policy, children } => Runtime::ParallelWithMemory(
+            ParallelWithMemory::with_policy(all(children), (*policy).into())
+                .expect("validated above"),
+        ),
+        Expr::Inverter(child) => Runtime::Inverter(Inverter::new(boxed(child))),
+        Expr::Retry {
+            child,
+            max_failures,
+        } => Runtime::Retry(Retry::new(boxed(child), *max_failures)),
+        Expr::Repeat {
+            child,
+            max_successes,
+        } => Runtime::Repeat(Repeat::new(boxed(child), *max_successes)),
+        Expr::ForceSuccess(child) => Runtime::ForceSuccess(ForceSuccess::new(boxed(child))),
+        Expr::ForceFailure(child) => Runtime::ForceFailure(ForceFailure::new(boxed(child))),
+        Expr::Named { label, child } => Runtime::Named(Named::new(boxed(child), label)),
+    }
+}
@@ -0,0 +1,370 @@
+use std::fmt;
+
+use arbor_core::{ParallelError, Status};
+
+use crate::{Expr, Model, Step};
+
+// Every leaf the tree ticked, with the status it returned, per tick.
+pub type Recording = Vec<Vec<Step>>;
+
+const STATUSES: [Status; 3] = [Status::Success, Status::Failure, Status::Running];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SensitivityError {
+    InvalidExpr(ParallelError),
+    UnknownLeaf { tick: usize, leaf: usize },
+    UnknownAction(usize),
+    // The recording and the tree disagree about which leaves ran.
+    NotTicked { tick: usize, leaf: usize },
+    NotRecorded { tick: usize, leaf: usize },
+}
+
+impl fmt::Display for SensitivityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SensitivityError::InvalidExpr(error) => write!(f, "invalid expression: {error}"),
+            SensitivityError::UnknownLeaf { tick, leaf } => {
+                write!(f, "tick {tick}: the tree has no leaf {leaf}")
+            }
+            SensitivityError::UnknownAction(leaf) => {
+                write!(f, "the tree has no leaf {leaf} to count as an action")
+            }
+            SensitivityError::NotTicked { tick, leaf } => {
+                write!(
+                    f,
+                    "tick {tick}: leaf {leaf} is recorded but the tree does not tick it"
+                )
+            }
+            SensitivityError::NotRecorded { tick, leaf } => {
+                write!(
+                    f,
+                    "tick {tick}: the tree ticks leaf {leaf} but the recording has no status for it"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SensitivityError {}
+
+// One recorded leaf outcome replaced by another, and what the tree did
+// differently over the rest of the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flip {
+    pub tick: usize,
+    pub leaf: usize,
+    pub label: Option<&'static str>,
+    pub from: Status,
+    pub to: Status,
+    pub root: Vec<Status>,
+    pub root_changed: bool,
+    pub actions_changed: bool,
+    // The flipped run ticked a leaf at a tick the recording has no status
+    // for, so part of it rests on held values.
+    pub extrapolated: bool,
+}
+
+impl Flip {
+    pub fn matters(&self) -> bool {
+        self.root_changed || self.actions_changed
+    }
+}
+
+impl fmt::Display for Flip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tick {}: ", self.tick)?;
+        match self.label {
+            Some(label) => write!(f, "`{label}`")?,
+            None => write!(f, "leaf {}", self.leaf)?,
+        }
+        write!(f, " {:?} -> {:?} ", self.from, self.to)?;
+        match (self.root_changed, self.actions_changed) {
+            (true, true) => f.write_str("changes the root outcome and the executed actions")?,
+            (true, false) => f.write_str("changes the root outcome")?,
+            (false, true) => f.write_str("changes the executed actions")?,
+            (false, false) => f.write_str("changes nothing")?,
+        }
+        if self.extrapolated {
+            f.write_str(" (extrapolated)")?;
+        }
+        Ok(())
+    }
+}
+
+struct Run {
+    root: Vec<Status>,
+    // Leaves ticked per tick, sorted.
+    ticked: Vec<Vec<usize>>,
+    extrapolated: bool,
+}
+
+// Replays a recording through a tree with one leaf outcome flipped at a
+// time. Leaves return what the recording says for that tick. When a flip
+// makes the tree tick a leaf at a tick the recording does not cover, the
+// leaf repeats its latest earlier recorded status, or its first one if it
+// has none yet; a leaf the recording never ticked fails.
+pub struct Sensitivity {
+    expr: Expr,
+    labels: Vec<Option<&'static str>>,
+    allowed: Vec<Vec<Status>>,
+    recording: Recording,
+    statuses: Vec<Vec<Option<Status>>>,
+    actions: Vec<bool>,
+    baseline: Run,
+}
+
+impl Sensitivity {
+    // Runs `expr` through the model for `ticks` ticks and records every leaf
+    // outcome. Choice leaves take their first allowed status.
+    pub fn record(expr: &Expr, ticks: usize) -> Recording {
+        let mut model = Model::new(expr);
+        (0..ticks)
+            .map(|_| {
+                let before = model.trace().ticks.clone();
+                model.tick();
+                let trace = model.trace();
+                before
+                    .iter()
+                    .enumerate()
+                    .filter(|&(leaf, count)| trace.ticks[leaf] > *count)
+                    .map(|(leaf, _)| Step {
+                        leaf,
+                        status: *trace.leaf_statuses[leaf].last().unwrap(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn new(expr: &Expr, recording: &[Vec<Step>]) -> Result<Self, SensitivityError> {
+        expr.validate().map_err(SensitivityError::InvalidExpr)?;
+
+        let leaves = expr.leaf_count();
+        let mut statuses = vec![vec![None; leaves]; recording.len()];
+        for (tick, steps) in recording.iter().enumerate() {
+            for step in steps {
+                if step.leaf >= leaves {
+                    return Err(SensitivityError::UnknownLeaf {
+                        tick,
+                        leaf: step.leaf,
+                    });
+                }
+                statuses[tick][step.leaf] = Some(step.status);
+            }
+        }
+
+        let mut allowed = Vec::with_capacity(leaves);
+        let expr = open(expr, &mut allowed);
+        let mut sensitivity = Self {
+            expr,
+            labels: Vec::new(),
+            allowed,
+            recording: recording.to_vec(),
+            statuses,
+            actions: vec![true; leaves],
+            baseline: Run {
+                root: Vec::new(),
+                ticked: Vec::new(),
+                extrapolated: false,
+            },
+        };
+        sensitivity.labels = sensitivity.expr.leaf_labels();
+        sensitivity.baseline = sensitivity.replay(None);
+
+        for (tick, ticked) in sensitivity.baseline.ticked.iter().enumerate() {
+            for &leaf in ticked {
+                if sensitivity.statuses[tick][leaf].is_none() {
+                    return Err(SensitivityError::NotRecorded { tick, leaf });
+                }
+            }
+            for step in &recording[tick] {
+                if !ticked.contains(&step.leaf) {
+                    return Err(SensitivityError::NotTicked {
+                        tick,
+                        leaf: step.leaf,
+                    });
+                }
+            }
+        }
+        Ok(sensitivity)
+    }
+
+    // Only these leaves count as actions when comparing what each run
+    // executed. Every leaf counts by default.
+    pub fn actions(
+        mut self,
+        leaves: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, SensitivityError> {
+        self.actions.fill(false);
+        for leaf in leaves {
+            *self
+                .actions
+                .get_mut(leaf)
+                .ok_or(SensitivityError::UnknownAction(leaf))? = true;
+        }
+        Ok(self)
+    }
+
+    pub fn baseline(&self) -> &[Status] {
+        &self.baseline.root
+    }
+
+    // Every recorded outcome replaced by each other status its leaf can
+    // return, in recording order. A choice leaf only flips to statuses it
+    // allows; scripted leaves stand for real nodes and flip to any status.
+    pub fn analyze(&self) -> Vec<Flip> {
+        let mut flips = Vec::new();
+        for (tick, steps) in self.recording.iter().enumerate() {
+            for step in steps {
+                for &to in &self.allowed[step.leaf] {
+                    if to == step.status {
+                        continue;
+                    }
+                    let run = self.replay(Some((tick, step.leaf, to)));
+                    flips.push(Flip {
+                        tick,
+                        leaf: step.leaf,
+                        label: self.labels[step.leaf],
+                        from: step.status,
+                        to,
+                        root_changed: run.root != self.baseline.root,
+                        actions_changed: self.executed(&run) != self.executed(&self.baseline),
+                        extrapolated: run.extrapolated,
+                        root: run.root,
+                    });
+                }
+            }
+        }
+        flips
+    }
+
+    fn executed(&self, run: &Run) -> Vec<Vec<usize>> {
+        run.ticked
+            .iter()
+            .map(|ticked| {
+                ticked
+                    .iter()
+                    .copied()
+                    .filter(|leaf| self.actions[*leaf])
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn replay(&self, flip: Option<(usize, usize, Status)>) -> Run {
+        let mut model = Model::new(&self.expr);
+        let mut run = Run {
+            root: Vec::new(),
+            ticked: Vec::new(),
+            extrapolated: false,
+        };
+
+        for tick in 0..self.recording.len() {
+            let mut ticked = Vec::new();
+            let status = model.tick_with(|leaf, _| {
+                ticked.push(leaf);
+                match flip {
+                    Some((at, flipped, to)) if at == tick && flipped == leaf => to,
+                    _ => self.status(tick, leaf, &mut run.extrapolated),
+                }
+            });
+            ticked.sort_unstable();
+            ticked.dedup();
+            run.root.push(status);
+            run.ticked.push(ticked);
+        }
+        run
+    }
+
+    fn status(&self, tick: usize, leaf: usize, extrapolated: &mut bool) -> Status {
+        if let Some(status) = self.statuses[tick][leaf] {
+            return status;
+        }
+        *extrapolated = true;
+        let held = self.statuses[..tick]
+            .iter()
+            .rev()
+            .chain(&self.statuses[tick + 1..])
+            .find_map(|statuses| statuses[leaf]);
+        held.unwrap_or(Status::Failure)
+    }
+}
+
+// Turns every leaf into a choice over all statuses, so the replay decides
+// each outcome, and collects the statuses each leaf may be flipped to.
+fn open(expr: &Expr, allowed: &mut Vec<Vec<Status>>) -> Expr {
+    let children = |children: &[Expr], allowed: &mut Vec<Vec<Status>>| {
+        children.iter().map(|child| open(child, allowed)).collect()
+    };
+    let child = |child: &Expr, allowed: &mut Vec<Vec<Status>>| Box::new(open(child, allowed));
+
+    match expr {
+        Expr::Leaf { .. } => {
+            allowed.push(STATUSES.to_vec());
+            Expr::any()
+        }
+        Expr::Choice(statuses) => {
+            allowed.push(
+                STATUSES
+                    .into_iter()
+                    .filter(|status| statuses.contains(status))
+                    .collect(),
+            );
+            Expr::any()
+        }
+        Expr::Sequence {
+            children: kids,
+            memory,
+        } => Expr::Sequence {
+            children: children(kids, allowed),
+            memory: *memory,
+        },
+        Expr::Selector {
+            children: kids,
+            memory,
+        } => Expr::Selector {
+            children: children(kids, allowed),
+            memory: *memory,
+        },
+        Expr::ReactiveSequence(kids) => Expr::ReactiveSequence(children(kids, allowed)),
+        Expr::ReactiveSelector(kids) => Expr::ReactiveSelector(children(kids, allowed)),
+        Expr::Parallel {
+            policy,
+            children: kids,
+        } => Expr::Parallel {
+            policy: *policy,
+            children: children(kids, allowed),
+        },
+        Expr::ParallelWithMemory {
+            policy,
+            children: kids,
+        } => Expr::ParallelWithMemory {
+            policy: *policy,
+            children: children(kids, allowed),
+        },
+        Expr::Inverter(inner) => Expr::Inverter(child(inner, allowed)),
+        Expr::Retry {
+            child: inner,
+            max_failures,
+        } => Expr::Retry {
+            child: child(inner, allowed),
+            max_failures: *max_failures,
+        },
+        Expr::Repeat {
+            child: inner,
+            max_successes,
+        } => Expr::Repeat {
+            child: child(inner, allowed),
+            max_successes: *max_successes,
+        },
+        Expr::ForceSuccess(inner) => Expr::ForceSuccess(child(inner, allowed)),
+        Expr::ForceFailure(inner) => Expr::ForceFailure(child(inner, allowed)),
+        Expr::Named {
+            label,
+            child: inner,
+        } => Expr::Named {
+            label,
+            child: child(inner, allowed),
+        },
+    }
+}
@@ -0,0 +1,979 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use arbor_core::{Memory, ParallelError, Status};
+
+use crate::{Expr, Policy};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportError {
+    InvalidExpr(ParallelError),
+    DuplicateLabel(&'static str),
+    InvalidLabel(&'static str),
+    // Would clash with a generated name such as `n3_ticked` or `root_status`.
+    ReservedLabel(&'static str),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::InvalidExpr(error) => write!(f, "invalid expression: {error}"),
+            ExportError::DuplicateLabel(label) => {
+                write!(f, "label `{label}` is used more than once in one tree")
+            }
+            ExportError::InvalidLabel(label) => {
+                write!(f, "label `{label}` is not a valid NuSMV identifier")
+            }
+            ExportError::ReservedLabel(label) => {
+                write!(f, "label `{label}` is reserved for generated names")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Bool(bool),
+    Int(usize),
+    Sym(&'static str),
+    Name(String),
+    Not(Box<Term>),
+    And(Vec<Term>),
+    Or(Vec<Term>),
+    Eq(Box<Term>, Box<Term>),
+    AtLeast(Vec<Term>, usize),
+    Case(Vec<(Term, Term)>, Box<Term>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Value {
+    Bool(bool),
+    Int(usize),
+    Sym(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Domain {
+    Range(usize),
+    Symbols(Vec<&'static str>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Assign {
+    var: String,
+    init: Term,
+    next: Term,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Input {
+    leaf: usize,
+    var: String,
+    allowed: Vec<Status>,
+}
+
+// A NuSMV `main` module where one transition is one root tick. Each state
+// holds the tree's memory before the tick plus a free input variable per
+// choice leaf; DEFINEs give what that tick does:
+//
+// - `root_status`
+// - `leafN_ticked` and `leafN_status` for each leaf, numbered like
+//   `Expr::leaf_count`
+// - `<label>_ticked` and `<label>_status` for each `Expr::Named` node
+//
+// A status is only meaningful in states where the node is ticked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Smv {
+    vars: Vec<(String, Domain)>,
+    inputs: Vec<Input>,
+    defines: Vec<(String, Term)>,
+    assigns: Vec<Assign>,
+    specs: Vec<String>,
+    leaves: usize,
+}
+
+impl Smv {
+    pub fn new(expr: &Expr) -> Result<Self, ExportError> {
+        expr.validate().map_err(ExportError::InvalidExpr)?;
+
+        let mut builder = Builder {
+            smv: Smv {
+                vars: Vec::new(),
+                inputs: Vec::new(),
+                defines: Vec::new(),
+                assigns: Vec::new(),
+                specs: Vec::new(),
+                leaves: 0,
+            },
+            nodes: 0,
+            labels: HashSet::new(),
+        };
+        let root = builder.node(expr)?;
+        builder.define(ticked(root), Term::Bool(true));
+        builder.define(reset(root), Term::Bool(false));
+        builder.define("root_status".into(), name(status(root)));
+        Ok(builder.smv)
+    }
+
+    pub fn ltl(mut self, spec: &str) -> Self {
+        self.specs.push(format!("LTLSPEC {spec}"));
+        self
+    }
+
+    pub fn invariant(mut self, spec: &str) -> Self {
+        self.specs.push(format!("INVARSPEC {spec}"));
+        self
+    }
+
+    pub fn leaves(&self) -> usize {
+        self.leaves
+    }
+
+    pub fn simulate(&self) -> Simulation<'_> {
+        let mut state = HashMap::new();
+        for assign in &self.assigns {
+            let value = Step::new(self, &state).eval(&assign.init);
+            state.insert(assign.var.clone(), value);
+        }
+        Simulation {
+            smv: self,
+            state,
+            last: HashMap::new(),
+        }
+    }
+}
+
+impl fmt::Display for Smv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "MODULE main")?;
+        if !self.vars.is_empty() || !self.inputs.is_empty() {
+            writeln!(f, "VAR")?;
+        }
+        for (var, domain) in &self.vars {
+            writeln!(f, "  {var} : {domain};")?;
+        }
+        for input in &self.inputs {
+            let symbols = input.allowed.iter().map(|status| symbol(*status)).collect();
+            writeln!(f, "  {} : {};", input.var, Domain::Symbols(symbols))?;
+        }
+        writeln!(f, "DEFINE")?;
+        for (name, term) in &self.defines {
+            writeln!(f, "  {name} := {term};")?;
+        }
+        if !self.assigns.is_empty() {
+            writeln!(f, "ASSIGN")?;
+        }
+        for assign in &self.assigns {
+            writeln!(f, "  init({}) := {};", assign.var, assign.init)?;
+            writeln!(f, "  next({}) := {};", assign.var, assign.next)?;
+        }
+        for spec in &self.specs {
+            writeln!(f, "{spec}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Domain::Range(max) => write!(f, "0..{max}"),
+            Domain::Symbols(symbols) => write!(f, "{{{}}}", symbols.join(", ")),
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut fmt::Formatter<'_>, terms: &[Term], separator: &str| {
+            for (index, term) in terms.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(separator)?;
+                }
+                write!(f, "{term}")?;
+            }
+            Ok(())
+        };
+
+        match self {
+            Term::Bool(true) => f.write_str("TRUE"),
+            Term::Bool(false) => f.write_str("FALSE"),
+            Term::Int(value) => write!(f, "{value}"),
+            Term::Sym(symbol) => f.write_str(symbol),
+            Term::Name(name) => f.write_str(name),
+            Term::Not(term) => write!(f, "!{term}"),
+            Term::And(terms) => {
+                f.write_str("(")?;
+                list(f, terms, " & ")?;
+                f.write_str(")")
+            }
+            Term::Or(terms) => {
+                f.write_str("(")?;
+                list(f, terms, " | ")?;
+                f.write_str(")")
+            }
+            Term::Eq(left, right) => write!(f, "({left} = {right})"),
+            Term::AtLeast(terms, count) => {
+                f.write_str("(count(")?;
+                list(f, terms, ", ")?;
+                write!(f, ") >= {count})")
+            }
+            Term::Case(arms, default) if arms.is_empty() => write!(f, "{default}"),
+            Term::Case(arms, default) => {
+                f.write_str("case ")?;
+                for (condition, value) in arms {
+                    write!(f, "{condition} : {value}; ")?;
+                }
+                write!(f, "TRUE : {default}; esac")
+            }
+        }
+    }
+}
+
+// Runs the exported module directly, one transition per tick, so tests can
+// hold it against `Model` without a NuSMV binary.
+pub struct Simulation<'a> {
+    smv: &'a Smv,
+    state: HashMap<String, Value>,
+    last: HashMap<String, Value>,
+}
+
+impl Simulation<'_> {
+    // Unlike `Model::tick_with`, `choose` is called for every choice leaf on
+    // every tick, ticked or not, in leaf order: the module picks all of its
+    // inputs before each transition.
+    pub fn tick_with(&mut self, mut choose: impl FnMut(usize, &[Status]) -> Status) -> Status {
+        for input in &self.smv.inputs {
+            let status = choose(input.leaf, &input.allowed);
+            assert!(
+                input.allowed.contains(&status),
+                "leaf {} chose {status:?}, which it does not allow",
+                input.leaf
+            );
+            self.state
+                .insert(input.var.clone(), Value::Sym(symbol(status)));
+        }
+
+        let mut step = Step::new(self.smv, &self.state);
+        let root = step.status(&name("root_status".into()));
+        for leaf in 0..self.smv.leaves {
+            step.eval(&name(format!("leaf{leaf}_ticked")));
+            step.eval(&name(format!("leaf{leaf}_status")));
+        }
+        let next: Vec<_> = self
+            .smv
+            .assigns
+            .iter()
+            .map(|assign| (assign.var.clone(), step.eval(&assign.next)))
+            .collect();
+
+        self.last = step.memo;
+        self.state.extend(next);
+        root
+    }
+
+    pub fn ticked(&self, leaf: usize) -> bool {
+        self.last.get(&format!("leaf{leaf}_ticked")) == Some(&Value::Bool(true))
+    }
+
+    pub fn leaf_status(&self, leaf: usize) -> Option<Status> {
+        if !self.ticked(leaf) {
+            return None;
+        }
+        match self.last.get(&format!("leaf{leaf}_status")) {
+            Some(Value::Sym(symbol)) => Some(from_symbol(symbol)),
+            _ => None,
+        }
+    }
+}
+
+struct Step<'a> {
+    defines: HashMap<&'a str, &'a Term>,
+    state: &'a HashMap<String, Value>,
+    memo: HashMap<String, Value>,
+}
+
+impl<'a> Step<'a> {
+    fn new(smv: &'a Smv, state: &'a HashMap<String, Value>) -> Self {
+        Self {
+            defines: smv
+                .defines
+                .iter()
+                .map(|(name, term)| (name.as_str(), term))
+                .collect(),
+            state,
+            memo: HashMap::new(),
+        }
+    }
+
+    fn eval(&mut self, term: &Term) -> Value {
+        match term {
+            Term::Bool(value) => Value::Bool(*value),
+            Term::Int(value) => Value::Int(*value),
+            Term::Sym(symbol) => Value::Sym(symbol),
+            Term::Name(name) => {
+                if let Some(value) = self.memo.get(name).or(self.state.get(name)) {
+                    return *value;
+                }
+                let define = self
+                    .defines
+                    .get(name.as_str())
+                    .copied()
+                    .unwrap_or_else(|| panic!("undefined name `{name}`"));
+                let value = self.eval(define);
+                self.memo.insert(name.clone(), value);
+                value
+            }
+            Term::Not(term) => Value::Bool(!self.holds(term)),
+            Term::And(terms) => Value::Bool(terms.iter().all(|term| self.holds(term))),
+            Term::Or(terms) => Value::Bool(terms.iter().any(|term| self.holds(term))),
+            Term::Eq(left, right) => Value::Bool(self.eval(left) == self.eval(right)),
+            Term::AtLeast(terms, count) => {
+                let holding = terms.iter().filter(|term| self.holds(term)).count();
+                Value::Bool(holding >= *count)
+            }
+            Term::Case(arms, default) => {
+                for (condition, value) in arms {
+                    if self.holds(condition) {
+                        return self.eval(value);
+                    }
+                }
+                self.eval(default)
+            }
+        }
+    }
+
+    fn holds(&mut self, term: &Term) -> bool {
+        match self.eval(term) {
+            Value::Bool(value) => value,
+            value => panic!("expected a boolean, found {value:?}"),
+        }
+    }
+
+    fn status(&mut self, term: &Term) -> Status {
+        match self.eval(term) {
+            Value::Sym(symbol) => from_symbol(symbol),
+            value => panic!("expected a status, found {value:?}"),
+        }
+    }
+}
+
+struct Builder {
+    smv: Smv,
+    nodes: usize,
+    labels: HashSet<&'static str>,
+}
+
+// Each node `nK` gets `nK_ticked` and `nK_reset` from its parent and defines
+// `nK_status` plus its children's flags. Nodes are only reset after their
+// own tick, so a node's next state is its reset state if `nK_reset` holds,
+// else its post-tick state if `nK_ticked` holds, else unchanged.
+impl Builder {
+    fn node(&mut self, expr: &Expr) -> Result<usize, ExportError> {
+        if let Expr::Named { label, child } = expr {
+            let id = self.node(child)?;
+            if !is_identifier(label) {
+                return Err(ExportError::InvalidLabel(label));
+            }
+            if is_reserved(label) {
+                return Err(ExportError::ReservedLabel(label));
+            }
+            if !self.labels.insert(label) {
+                return Err(ExportError::DuplicateLabel(label));
+            }
+            self.define(format!("{label}_ticked"), name(ticked(id)));
+            self.define(format!("{label}_status"), name(status(id)));
+            return Ok(id);
+        }
+
+        let id = self.nodes;
+        self.nodes += 1;
+        match expr {
+            Expr::Leaf {
+                script,
+                rewind_on_reset,
+            } => {
+                let term = match script.as_slice() {
+                    [] => Term::Sym(symbol(Status::Failure)),
+                    [status] => Term::Sym(symbol(*status)),
+                    [.., last] => {
+                        let cursor = format!("n{id}_cursor");
+                        let max = script.len() - 1;
+                        let at = |index| is_int(&cursor, index);
+                        self.var(cursor.clone(), Domain::Range(max));
+
+                        let mut arms = Vec::new();
+                        if *rewind_on_reset {
+                            arms.push((name(reset(id)), Term::Int(0)));
+                        }
+                        arms.push((name(ticked(id)), successor(&cursor, max)));
+                        self.assign(
+                            cursor.clone(),
+                            Term::Case(arms, Box::new(name(cursor.clone()))),
+                        );
+                        Term::Case(
+                            (0..max)
+                                .map(|index| (at(index), Term::Sym(symbol(script[index]))))
+                                .collect(),
+                            Box::new(Term::Sym(symbol(*last))),
+                        )
+                    }
+                };
+                self.define(status(id), term);
+                self.leaf(id);
+            }
+            Expr::Choice(allowed) => {
+                let leaf = self.smv.leaves;
+                let term = if allowed.is_empty() {
+                    Term::Sym(symbol(Status::Failure))
+                } else {
+                    let var = format!("leaf{leaf}_choice");
+                    let mut distinct = Vec::new();
+                    for status in allowed {
+                        if !distinct.contains(status) {
+                            distinct.push(*status);
+                        }
+                    }
+                    self.smv.inputs.push(Input {
+                        leaf,
+                        var: var.clone(),
+                        allowed: distinct,
+                    });
+                    name(var)
+                };
+                self.define(status(id), term);
+                self.leaf(id);
+            }
+            Expr::Sequence { children, memory } => {
+                self.ordered(id, children, *memory, Status::Success)?;
+            }
+            Expr::Selector { children, memory } => {
+                self.ordered(id, children, *memory, Status::Failure)?;
+            }
+            Expr::ReactiveSequence(children) => self.reactive(id, children, Status::Success)?,
+            Expr::ReactiveSelector(children) => self.reactive(id, children, Status::Failure)?,
+            Expr::Parallel { policy, children } => self.parallel(id, *policy, children, false)?,
+            Expr::ParallelWithMemory { policy, children } => {
+                self.parallel(id, *policy, children, true)?;
+            }
+            Expr::Inverter(child) => {
+                let child = self.decorated(id, child)?;
+                self.define(
+                    status(id),
+                    Term::Case(
+                        vec![
+                            (is(&status(child), Status::Success), sym(Status::Failure)),
+                            (is(&status(child), Status::Failure), sym(Status::Success)),
+                        ],
+                        Box::new(sym(Status::Running)),
+                    ),
+                );
+            }
+            Expr::Retry {
+                child,
+                max_failures,
+            } => self.counted(id, child, *max_failures, Status::Failure)?,
+            Expr::Repeat {
+                child,
+                max_successes,
+            } => self.counted(id, child, *max_successes, Status::Success)?,
+            Expr::ForceSuccess(child) => self.forced(id, child, Status::Success)?,
+            Expr::ForceFailure(child) => self.forced(id, child, Status::Failure)?,
+            Expr::Named { .. } => unreachable!("handled above"),
+        }
+        Ok(id)
+    }
+
+    fn children(&mut self, children: &[Expr]) -> Result<Vec<usize>, ExportError> {
+        children.iter().map(|child| self.node(child)).collect()
+    }
+
+    fn ordered(
+        &mut self,
+        id: usize,
+        children: &[Expr],
+        memory: Memory,
+        next: Status,
+    ) -> Result<(), ExportError> {
+        let kids = self.children(children)?;
+        let stop = opposite(next);
+        let (t, r) = (name(ticked(id)), name(reset(id)));
+        let index = format!("n{id}_index");
+        let after = format!("n{id}_index_after");
+        let single = kids.len() <= 1;
+        // Only a persistent sequence keeps its place after stopping.
+        let keeps = memory == Memory::Persistent && stop == Status::Failure;
+        let at = |var: &str, position: usize| {
+            if single {
+                Term::Bool(position == 0)
+            } else {
+                is_int(var, position)
+            }
+        };
+
+        for (position, kid) in kids.iter().enumerate() {
+            let started = and(vec![t.clone(), at(&index, position)]);
+            let term = match position {
+                0 => started,
+                _ => {
+                    let previous = kids[position - 1];
+                    or(vec![
+                        started,
+                        and(vec![name(ticked(previous)), is(&status(previous), next)]),
+                    ])
+                }
+            };
+            self.define(ticked(*kid), term);
+        }
+        self.define(status(id), decided(&kids, next));
+
+        if !single {
+            let mut arms = Vec::new();
+            for (position, kid) in kids.iter().enumerate() {
+                let ran = |outcome| and(vec![name(ticked(*kid)), is(&status(*kid), outcome)]);
+                arms.push((ran(Status::Running), Term::Int(position)));
+                let kept = if keeps { position } else { 0 };
+                arms.push((ran(stop), Term::Int(kept)));
+            }
+            let ticked_index = Term::Case(arms, Box::new(Term::Int(0)));
+            self.define(
+                after.clone(),
+                Term::Case(
+                    vec![(t.clone(), ticked_index)],
+                    Box::new(name(index.clone())),
+                ),
+            );
+            self.var(index.clone(), Domain::Range(kids.len() - 1));
+            let mut arms = Vec::new();
+            if memory == Memory::Resume {
+                arms.push((r.clone(), Term::Int(0)));
+            }
+            arms.push((t.clone(), name(after.clone())));
+            self.assign(index.clone(), Term::Case(arms, Box::new(name(index))));
+        }
+
+        for (position, kid) in kids.iter().enumerate() {
+            let stopped = if keeps {
+                or(kids[..=position]
+                    .iter()
+                    .map(|earlier| and(vec![name(ticked(*earlier)), is(&status(*earlier), stop)]))
+                    .collect())
+            } else {
+                is(&status(id), stop)
+            };
+            let halted = match memory {
+                Memory::Resume => r.clone(),
+                Memory::Persistent => and(vec![
+                    r.clone(),
+                    or((0..=position).map(|from| at(&after, from)).collect()),
+                ]),
+            };
+            let finished = and(vec![t.clone(), or(vec![is(&status(id), next), stopped])]);
+            self.define(reset(*kid), or(vec![finished, halted]));
+        }
+        Ok(())
+    }
+
+    fn reactive(&mut self, id: usize, children: &[Expr], next: Status) -> Result<(), ExportError> {
+        let kids = self.children(children)?;
+        let (t, r) = (name(ticked(id)), name(reset(id)));
+
+        for (position, kid) in kids.iter().enumerate() {
+            let term = match position {
+                0 => t.clone(),
+                _ => {
+                    let previous = kids[position - 1];
+                    and(vec![name(ticked(previous)), is(&status(previous), next)])
+                }
+            };
+            self.define(ticked(*kid), term);
+        }
+        self.define(status(id), decided(&kids, next));
+
+        for (position, kid) in kids.iter().enumerate() {
+            let decided_before = or(kids[..position]
+                .iter()
+                .map(|earlier| {
+                    and(vec![
+                        name(ticked(*earlier)),
+                        Term::Not(Box::new(is(&status(*earlier), next))),
+                    ])
+                })
+                .collect());
+            let finished = and(vec![
+                t.clone(),
+                or(vec![is(&status(id), next), decided_before]),
+            ]);
+            self.define(reset(*kid), or(vec![finished, r.clone()]));
+        }
+        Ok(())
+    }
+
+    fn parallel(
+        &mut self,
+        id: usize,
+        policy: Policy,
+        children: &[Expr],
+        memory: bool,
+    ) -> Result<(), ExportError> {
+        let kids = self.children(children)?;
+        let (t, r) = (name(ticked(id)), name(reset(id)));
+        let done = |position: usize| format!("n{id}_done{position}");
+        const NONE: &str = "none";
+
+        let mut outcomes = [Vec::new(), Vec::new()];
+        let mut mains = Vec::new();
+        for (position, kid) in kids.iter().enumerate() {
+            if memory {
+                let done = done(position);
+                let symbols = vec![NONE, symbol(Status::Success), symbol(Status::Failure)];
+                self.var(done.clone(), Domain::Symbols(symbols));
+                let open = eq(name(done.clone()), Term::Sym(NONE));
+                self.define(ticked(*kid), and(vec![t.clone(), open.clone()]));
+                for (slot, outcome) in [Status::Success, Status::Failure].into_iter().enumerate() {
+                    outcomes[slot].push(or(vec![
+                        eq(name(done.clone()), sym(outcome)),
+                        and(vec![open.clone(), is(&status(*kid), outcome)]),
+                    ]));
+                }
+                mains.push(Term::Case(
+                    vec![
+                        (
+                            eq(name(done.clone()), sym(Status::Success)),
+                            sym(Status::Success),
+                        ),
+                        (
+                            eq(name(done.clone()), sym(Status::Failure)),
+                            sym(Status::Failure),
+                        ),
+                    ],
+                    Box::new(name(status(*kid))),
+                ));
+            } else {
+                self.define(ticked(*kid), t.clone());
+                outcomes[0].push(is(&status(*kid), Status::Success));
+                outcomes[1].push(is(&status(*kid), Status::Failure));
+                mains.push(name(status(*kid)));
+            }
+        }
+
+        let n = kids.len();
+        let term = match policy {
+            Policy::MainChild(index) => mains.swap_remove(index),
+            _ => {
+                let (success, failure) = match policy {
+                    Policy::SuccessOnAllFailureOnAny => (n, 1),
+                    Policy::SuccessOnAnyFailureOnAll => (1, n),
+                    Policy::SuccessThreshold(success) => (success, n - success + 1),
+                    Policy::Thresholds { success, failure } => (success, failure),
+                    Policy::MainChild(_) => unreachable!("handled above"),
+                };
+                let [successes, failures] = outcomes;
+                Term::Case(
+                    vec![
+                        (Term::AtLeast(successes, success), sym(Status::Success)),
+                        (
+                            or(vec![
+                                Term::AtLeast(failures.clone(), failure),
+                                Term::AtLeast(failures, n - success + 1),
+                            ]),
+                            sym(Status::Failure),
+                        ),
+                    ],
+                    Box::new(sym(Status::Running)),
+                )
+            }
+        };
+        self.define(status(id), term);
+
+        let finished = and(vec![
+            t,
+            Term::Not(Box::new(is(&status(id), Status::Running))),
+        ]);
+        let cleared = or(vec![finished, r]);
+        for (position, kid) in kids.iter().enumerate() {
+            self.define(reset(*kid), cleared.clone());
+            if memory {
+                let done = done(position);
+                let ran = |outcome| and(vec![name(ticked(*kid)), is(&status(*kid), outcome)]);
+                let next = Term::Case(
+                    vec![
+                        (cleared.clone(), Term::Sym(NONE)),
+                        (ran(Status::Success), sym(Status::Success)),
+                        (ran(Status::Failure), sym(Status::Failure)),
+                    ],
+                    Box::new(name(done.clone())),
+                );
+                self.smv.assigns.push(Assign {
+                    var: done,
+                    init: Term::Sym(NONE),
+                    next,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // Ticks the child whenever the decorator is ticked and resets it when it
+    // finishes or the decorator is reset.
+    fn decorated(&mut self, id: usize, child: &Expr) -> Result<usize, ExportError> {
+        let child = self.node(child)?;
+        self.define(ticked(child), name(ticked(id)));
+        self.define(
+            reset(child),
+            or(vec![
+                and(vec![
+                    name(ticked(id)),
+                    Term::Not(Box::new(is(&status(child), Status::Running))),
+                ]),
+                name(reset(id)),
+            ]),
+        );
+        Ok(child)
+    }
+
+    fn forced(&mut self, id: usize, child: &Expr, result: Status) -> Result<(), ExportError> {
+        let child = self.decorated(id, child)?;
+        self.define(
+            status(id),
+            Term::Case(
+                vec![(is(&status(child), Status::Running), sym(Status::Running))],
+                Box::new(sym(result)),
+            ),
+        );
+        Ok(())
+    }
+
+    // `Retry` counts failures and `Repeat` successes; the other finished
+    // status passes straight through.
+    fn counted(
+        &mut self,
+        id: usize,
+        child: &Expr,
+        max: usize,
+        counted: Status,
+    ) -> Result<(), ExportError> {
+        if max == 0 {
+            let child = self.node(child)?;
+            self.define(ticked(child), Term::Bool(false));
+            self.define(reset(child), or(vec![name(ticked(id)), name(reset(id))]));
+            self.define(status(id), sym(counted));
+            return Ok(());
+        }
+
+        let child = self.decorated(id, child)?;
+        let count = format!("n{id}_count");
+        let last = if max == 1 {
+            Term::Bool(true)
+        } else {
+            is_int(&count, max - 1)
+        };
+        self.define(
+            status(id),
+            Term::Case(
+                vec![
+                    (is(&status(child), Status::Running), sym(Status::Running)),
+                    (
+                        is(&status(child), opposite(counted)),
+                        sym(opposite(counted)),
+                    ),
+                    (last.clone(), sym(counted)),
+                ],
+                Box::new(sym(Status::Running)),
+            ),
+        );
+
+        if max > 1 {
+            self.var(count.clone(), Domain::Range(max - 1));
+            let t = name(ticked(id));
+            let next = Term::Case(
+                vec![
+                    (name(reset(id)), Term::Int(0)),
+                    (
+                        and(vec![
+                            t.clone(),
+                            is(&status(child), counted),
+                            Term::Not(Box::new(last)),
+                        ]),
+                        successor(&count, max - 1),
+                    ),
+                    (
+                        and(vec![
+                            t,
+                            Term::Not(Box::new(is(&status(child), Status::Running))),
+                        ]),
+                        Term::Int(0),
+                    ),
+                ],
+                Box::new(name(count.clone())),
+            );
+            self.assign(count, next);
+        }
+        Ok(())
+    }
+
+    fn leaf(&mut self, id: usize) {
+        let leaf = self.smv.leaves;
+        self.smv.leaves += 1;
+        self.define(format!("leaf{leaf}_ticked"), name(ticked(id)));
+        self.define(format!("leaf{leaf}_status"), name(status(id)));
+    }
+
+    fn var(&mut self, var: String, domain: Domain) {
+        self.smv.vars.push((var, domain));
+    }
+
+    fn assign(&mut self, var: String, next: Term) {
+        self.smv.assigns.push(Assign {
+            var,
+            init: Term::Int(0),
+            next,
+        });
+    }
+
+    fn define(&mut self, name: String, term: Term) {
+        self.smv.defines.push((name, term));
+    }
+}
+
+// The status of the first ticked child that did not hand over to its next
+// sibling, or `next` if every child did.
+fn decided(kids: &[usize], next: Status) -> Term {
+    Term::Case(
+        kids.iter()
+            .map(|kid| {
+                (
+                    and(vec![
+                        name(ticked(*kid)),
+                        Term::Not(Box::new(is(&status(*kid), next))),
+                    ]),
+                    name(status(*kid)),
+                )
+            })
+            .collect(),
+        Box::new(sym(next)),
+    )
+}
+
+// Spelled out case by case rather than as `var + 1`, so every assigned value
+// is visibly inside the variable's range.
+fn successor(var: &str, max: usize) -> Term {
+    Term::Case(
+        (0..max.saturating_sub(1))
+            .map(|value| (is_int(var, value), Term::Int(value + 1)))
+            .collect(),
+        Box::new(Term::Int(max)),
+    )
+}
+
+fn and(terms: Vec<Term>) -> Term {
+    let mut kept = Vec::new();
+    for term in terms {
+        match term {
+            Term::Bool(true) => {}
+            Term::Bool(false) => return Term::Bool(false),
+            term => kept.push(term),
+        }
+    }
+    match kept.len() {
+        0 => Term::Bool(true),
+        1 => kept.remove(0),
+        _ => Term::And(kept),
+    }
+}
+
+fn or(terms: Vec<Term>) -> Term {
+    let mut kept = Vec::new();
+    for term in terms {
+        match term {
+            Term::Bool(false) => {}
+            Term::Bool(true) => return Term::Bool(true),
+            term => kept.push(term),
+        }
+    }
+    match kept.len() {
+        0 => Term::Bool(false),
+        1 => kept.remove(0),
+        _ => Term::Or(kept),
+    }
+}
+
+fn eq(left: Term, right: Term) -> Term {
+    Term::Eq(Box::new(left), Box::new(right))
+}
+
+fn is(define: &str, status: Status) -> Term {
+    eq(name(define.into()), sym(status))
+}
+
+fn is_int(var: &str, value: usize) -> Term {
+    eq(name(var.into()), Term::Int(value))
+}
+
+fn name(name: String) -> Term {
+    Term::Name(name)
+}
+
+fn sym(status: Status) -> Term {
+    Term::Sym(symbol(status))
+}
+
+fn ticked(id: usize) -> String {
+    format!("n{id}_ticked")
+}
+
+fn reset(id: usize) -> String {
+    format!("n{id}_reset")
+}
+
+fn status(id: usize) -> String {
+    format!("n{id}_status")
+}
+
+fn symbol(status: Status) -> &'static str {
+    match status {
+        Status::Success => "success",
+        Status::Failure => "failure",
+        Status::Running => "running",
+    }
+}
+
+fn from_symbol(symbol: &str) -> Status {
+    match symbol {
+        "success" => Status::Success,
+        "failure" => Status::Failure,
+        "running" => Status::Running,
+        symbol => panic!("`{symbol}` is not a status"),
+    }
+}
+
+fn opposite(status: Status) -> Status {
+    match status {
+        Status::Success => Status::Failure,
+        Status::Failure => Status::Success,
+        Status::Running => Status::Running,
+    }
+}
+
+// `root`, `n<digits>` and `leaf<digits>` prefix every generated name that
+// ends in `_ticked` or `_status`, the suffixes label aliases use.
+fn is_reserved(label: &str) -> bool {
+    let numbered = |prefix| {
+        label.strip_prefix(prefix).is_some_and(|digits: &str| {
+            !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+        })
+    };
+    label == "root" || numbered("n") || numbered("leaf")
+}
+
+fn is_identifier(label: &str) -> bool {
+    let mut chars = label.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '#' | '-'))
+}
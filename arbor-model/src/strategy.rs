@@ -0,0 +1,87 @@
+use arbor_core::{Memory, Status};
+use proptest::prelude::*;
+
+use crate::{Expr, Policy};
+
+pub fn status() -> impl Strategy<Value = Status> {
+    prop_oneof![
+        Just(Status::Success),
+        Just(Status::Failure),
+        Just(Status::Running),
+    ]
+}
+
+pub fn script() -> impl Strategy<Value = Vec<Status>> {
+    prop::collection::vec(status(), 1..=4)
+}
+
+pub fn leaf() -> impl Strategy<Value = Expr> {
+    (script(), any::<bool>()).prop_map(|(script, rewind_on_reset)| Expr::Leaf {
+        script,
+        rewind_on_reset,
+    })
+}
+
+pub fn memory() -> impl Strategy<Value = Memory> {
+    prop_oneof![Just(Memory::Resume), Just(Memory::Persistent)]
+}
+
+// Only policies that `ParallelPolicy::validate` accepts for `children`.
+pub fn policy(children: usize) -> BoxedStrategy<Policy> {
+    assert!(children > 0, "parallel nodes require at least one child");
+    prop_oneof![
+        Just(Policy::SuccessOnAllFailureOnAny),
+        Just(Policy::SuccessOnAnyFailureOnAll),
+        (1..=children).prop_map(Policy::SuccessThreshold),
+        (1..=children, 1..=children)
+            .prop_map(|(success, failure)| Policy::Thresholds { success, failure }),
+        (0..children).prop_map(Policy::MainChild),
+    ]
+    .boxed()
+}
+
+pub fn expr() -> impl Strategy<Value = Expr> {
+    expr_with(4, 64, 3)
+}
+
+// `depth`, `size` and `max_children` are passed to `prop_recursive`;
+// composites get between one and `max_children` children.
+pub fn expr_with(depth: u32, size: u32, max_children: usize) -> impl Strategy<Value = Expr> {
+    let max_children = max_children.max(1);
+    leaf().prop_recursive(depth, size, max_children as u32, move |inner| {
+        let children = prop::collection::vec(inner.clone(), 1..=max_children);
+        let parallel = children.clone().prop_flat_map(|children| {
+            let count = children.len();
+            (Just(children), policy(count), any::<bool>())
+        });
+
+        prop_oneof![
+            (children.clone(), memory())
+                .prop_map(|(children, memory)| Expr::Sequence { children, memory }),
+            (children.clone(), memory())
+                .prop_map(|(children, memory)| Expr::Selector { children, memory }),
+            children.clone().prop_map(Expr::ReactiveSequence),
+            children.prop_map(Expr::ReactiveSelector),
+            parallel.prop_map(|(children, policy, memory)| if memory {
+                Expr::ParallelWithMemory { policy, children }
+            } else {
+                Expr::Parallel { policy, children }
+            }),
+            inner
+                .clone()
+                .prop_map(|child| Expr::Inverter(Box::new(child))),
+            (inner.clone(), 0usize..=3).prop_map(|(child, max_failures)| Expr::Retry {
+                child: Box::new(child),
+                max_failures,
+            }),
+            (inner.clone(), 0usize..=3).prop_map(|(child, max_successes)| Expr::Repeat {
+                child: Box::new(child),
+                max_successes,
+            }),
+            inner
+                .clone()
+                .prop_map(|child| Expr::ForceSuccess(Box::new(child))),
+            inner.prop_map(|child| Expr::ForceFailure(Box::new(child))),
+        ]
+    })
+}
@@ -0,0 +1,71 @@
+use arbor_core::{Memory, Status};
+use arbor_model::{Expr, Policy, compare, strategy};
+use proptest::prelude::*;
+
+// The reference interpreter and generators live in `arbor-model`; this
+// suite keeps driving them against the real nodes from arbor-core.
+
+proptest! {
+    #[test]
+    fn runtime_matches_reference_model_over_multitick_traces(
+        expr in strategy::expr(),
+        cycles in 1usize..12,
+    ) {
+        if let Err(divergence) = compare(&expr, cycles) {
+            prop_assert!(false, "{divergence}\n{expr:?}");
+        }
+    }
+}
+
+#[test]
+fn differential_smoke_deterministic() {
+    let expr = Expr::sequence([
+        Expr::leaf([Status::Success]),
+        Expr::Retry {
+            child: Box::new(Expr::Leaf {
+                script: vec![Status::Failure, Status::Success],
+                rewind_on_reset: false,
+            }),
+            max_failures: 2,
+        },
+    ]);
+
+    let trace = compare(&expr, 6).unwrap();
+    assert_eq!(trace.root_statuses.len(), 6);
+}
+
+#[test]
+fn differential_parallel_with_memory_smoke_deterministic() {
+    let expr = Expr::ParallelWithMemory {
+        policy: Policy::SuccessThreshold(2),
+        children: vec![
+            Expr::leaf([Status::Success]),
+            Expr::leaf([Status::Running, Status::Failure]),
+            Expr::leaf([Status::Running, Status::Running, Status::Success]),
+        ],
+    };
+
+    let trace = compare(&expr, 6).unwrap();
+    assert_eq!(trace.ticks, vec![2, 4, 6]);
+}
+
+#[test]
+fn differential_persistent_memory_smoke_deterministic() {
+    let expr = Expr::Sequence {
+        children: vec![
+            Expr::leaf([Status::Success]),
+            Expr::Leaf {
+                script: vec![Status::Failure, Status::Success],
+                rewind_on_reset: false,
+            },
+        ],
+        memory: Memory::Persistent,
+    };
+
+    let trace = compare(&expr, 3).unwrap();
+    assert_eq!(
+        trace.root_statuses,
+        [Status::Failure, Status::Success, Status::Success]
+    );
+    assert_eq!(trace.ticks, vec![2, 3]);
+}
@@ -0,0 +1,120 @@
+use arbor_core::Status::{Failure, Running, Success};
+use arbor_model::{Equivalence, EquivalenceError, Expr, Observation, TickOutcome};
+
+fn leaf(label: &'static str) -> Expr {
+    Expr::any().named(label)
+}
+
+#[test]
+fn flattening_nested_sequences_preserves_behavior() {
+    let nested = Expr::sequence([
+        Expr::sequence([leaf("takeoff"), leaf("climb")]),
+        Expr::sequence([leaf("survey"), leaf("land")]),
+    ]);
+    let flat = Expr::sequence([leaf("takeoff"), leaf("climb"), leaf("survey"), leaf("land")]);
+
+    let checker = Equivalence::new(&nested, &flat).unwrap();
+    assert_eq!(checker.check(6), Ok(()));
+}
+
+#[test]
+fn reactive_rewrites_are_caught_by_the_tick_pattern() {
+    let left = Expr::sequence([leaf("armed"), leaf("fly")]);
+    let right = Expr::ReactiveSequence(vec![leaf("armed"), leaf("fly")]);
+
+    let counterexample = Equivalence::new(&left, &right)
+        .unwrap()
+        .check(4)
+        .unwrap_err();
+    assert_eq!(counterexample.ticks.len(), 2);
+    assert_eq!(
+        counterexample.ticks[0].outcomes,
+        [("armed", Success), ("fly", Running)]
+    );
+    assert_eq!(
+        *counterexample.divergent(),
+        TickOutcome {
+            outcomes: vec![("fly", Success), ("armed", Success)],
+            left: Observation {
+                status: Success,
+                ticked: vec!["fly"],
+            },
+            right: Observation {
+                status: Success,
+                ticked: vec!["armed", "fly"],
+            },
+        }
+    );
+    assert_eq!(
+        counterexample.to_string(),
+        "trees diverge at tick 1
+  tick 0: armed=Success fly=Running
+    left:  Running ticking [armed, fly]
+    right: Running ticking [armed, fly]
+  tick 1: fly=Success armed=Success
+    left:  Success ticking [fly]
+    right: Success ticking [armed, fly]"
+    );
+}
+
+#[test]
+fn status_divergence_uses_the_first_differing_outcome() {
+    let left = Expr::sequence([leaf("gps")]);
+    let right = Expr::ForceSuccess(Box::new(leaf("gps")));
+
+    let counterexample = Equivalence::new(&left, &right)
+        .unwrap()
+        .check(3)
+        .unwrap_err();
+    assert_eq!(counterexample.ticks.len(), 1);
+    assert_eq!(counterexample.divergent().outcomes, [("gps", Failure)]);
+    assert_eq!(counterexample.divergent().left.status, Failure);
+    assert_eq!(counterexample.divergent().right.status, Success);
+}
+
+#[test]
+fn counterexamples_are_as_short_as_possible() {
+    let retry = |max_failures| Expr::Retry {
+        child: Box::new(Expr::choice([Success, Failure]).named("link")),
+        max_failures,
+    };
+    let checker = Equivalence::new(&retry(2), &retry(3)).unwrap();
+
+    assert_eq!(checker.check(1), Ok(()));
+    let counterexample = checker.check(5).unwrap_err();
+    assert_eq!(counterexample.ticks.len(), 2);
+    assert_eq!(
+        counterexample
+            .ticks
+            .iter()
+            .map(|tick| tick.outcomes.clone())
+            .collect::<Vec<_>>(),
+        [vec![("link", Failure)], vec![("link", Failure)]]
+    );
+}
+
+#[test]
+fn leaves_must_be_labeled_consistently() {
+    let error = |left: Expr, right: Expr| Equivalence::new(&left, &right).err().unwrap();
+
+    assert_eq!(
+        error(Expr::sequence([leaf("a"), leaf("a")]), leaf("a")),
+        EquivalenceError::DuplicateLabel("a")
+    );
+    assert_eq!(
+        error(leaf("a"), Expr::any()),
+        EquivalenceError::UnlabeledChoice
+    );
+    assert_eq!(
+        error(leaf("a"), Expr::choice([Success]).named("a")).to_string(),
+        "leaf `a` allows different statuses in the two trees"
+    );
+
+    let scripted = Expr::sequence([Expr::leaf([Success]), leaf("a")]);
+    assert!(
+        Equivalence::new(&scripted, &leaf("a"))
+            .unwrap()
+            .check(2)
+            .is_ok()
+    );
+}
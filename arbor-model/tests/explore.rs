@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use arbor_core::Status::{self, Failure, Running, Success};
+use arbor_model::{Explorer, Expr, Model, Policy, Step, compare};
+
+fn guarded_mission() -> Expr {
+    // battery_ok guards a mission step that may still be running.
+    Expr::ReactiveSequence(vec![
+        Expr::choice([Success, Failure]),
+        Expr::leaf([Running, Success]),
+    ])
+}
+
+#[test]
+fn enumerates_every_root_trace_with_a_witness() {
+    let exploration = Explorer::new(&guarded_mission()).explore(2);
+
+    assert_eq!(
+        exploration.traces().collect::<Vec<_>>(),
+        [
+            &[Failure, Failure][..],
+            &[Failure, Running],
+            &[Running, Success],
+            &[Running, Failure],
+        ]
+    );
+    assert!(!exploration.contains(&[Success, Success]));
+    assert!(exploration.contains(&[Failure]));
+    assert_eq!(exploration.statuses_at(1), [Success, Failure, Running]);
+
+    assert_eq!(
+        exploration.witness(&[Failure, Running]).unwrap(),
+        [
+            vec![Step {
+                leaf: 0,
+                status: Failure,
+            }],
+            vec![Step {
+                leaf: 0,
+                status: Success,
+            }],
+        ]
+    );
+}
+
+#[test]
+fn revisited_states_are_pruned() {
+    let expr = Expr::Parallel {
+        policy: Policy::SuccessThreshold(2),
+        children: vec![Expr::any(), Expr::any(), Expr::any()],
+    };
+    let exploration = Explorer::new(&expr).explore(6);
+    let stats = exploration.stats();
+
+    assert_eq!(exploration.len(), 3usize.pow(6));
+    assert_eq!(stats.states, 1);
+    assert_eq!(stats.expanded, 6);
+    assert_eq!(stats.transitions, 6 * 27);
+    // Three root statuses lead to the same state; two of them hit the memo
+    // at each of the five levels below the root.
+    assert_eq!(stats.revisited, 2 * 5);
+
+    let expr = Expr::sequence([
+        Expr::any(),
+        Expr::Retry {
+            child: Box::new(Expr::any()),
+            max_failures: 2,
+        },
+    ]);
+    let stats = Explorer::new(&expr).explore(5).stats();
+    assert!(stats.revisited > 0);
+    assert!(stats.states < stats.expanded);
+}
+
+fn stateful_tree() -> Expr {
+    Expr::Selector {
+        children: vec![
+            Expr::Sequence {
+                children: vec![
+                    Expr::choice([Success, Running]),
+                    Expr::Repeat {
+                        child: Box::new(Expr::choice([Success, Failure])),
+                        max_successes: 2,
+                    },
+                ],
+                memory: arbor_core::Memory::Persistent,
+            },
+            Expr::leaf([Running, Failure]),
+        ],
+        memory: arbor_core::Memory::Resume,
+    }
+}
+
+// Replays every possible tape of choices through the model with no pruning.
+fn brute_force(expr: &Expr, ticks: usize, max_choices: usize) -> HashSet<Vec<Status>> {
+    let mut traces = HashSet::new();
+    for mut tape in 0..2usize.pow(max_choices as u32) {
+        let mut model = Model::new(expr);
+        for _ in 0..ticks {
+            model.tick_with(|_, allowed| {
+                let status = allowed[tape % 2 % allowed.len()];
+                tape /= 2;
+                status
+            });
+        }
+        traces.insert(model.trace().root_statuses.clone());
+    }
+    traces
+}
+
+#[test]
+fn pruned_search_matches_brute_force() {
+    let ticks = 4;
+    let exploration = Explorer::new(&stateful_tree()).explore(ticks);
+    let explored: HashSet<Vec<Status>> = exploration.traces().map(<[Status]>::to_vec).collect();
+
+    assert_eq!(explored, brute_force(&stateful_tree(), ticks, 2 * ticks));
+    assert_eq!(exploration.ticks(), ticks);
+}
+
+#[test]
+fn witnesses_replay_to_their_traces() {
+    let exploration = Explorer::new(&stateful_tree()).explore(4);
+
+    for trace in exploration.traces() {
+        let witness = exploration.witness(trace).unwrap();
+        let mut model = Model::new(&stateful_tree());
+        for steps in witness {
+            let mut steps = steps.iter();
+            model.tick_with(|leaf, _| {
+                let step = steps.next().unwrap();
+                assert_eq!(step.leaf, leaf);
+                step.status
+            });
+        }
+        assert_eq!(model.trace().root_statuses, trace);
+    }
+}
+
+#[test]
+fn choice_leaves_take_their_first_status_outside_the_explorer() {
+    let expr = Expr::sequence([Expr::choice([Running, Success]), Expr::choice([])]);
+    let trace = compare(&expr, 2).unwrap();
+    assert_eq!(trace.root_statuses, [Running, Running]);
+
+    let mut model = Model::new(&Expr::sequence([Expr::any(), Expr::choice([])]));
+    assert_eq!(model.tick(), Failure);
+}
@@ -0,0 +1,191 @@
+use arbor_core::{Inspect, Inverter, Node, Retry, Selector, Sequence, Status, walk};
+use arbor_model::{Divergence, Expr, Model, Recorder, build, check, compare};
+
+#[derive(Default)]
+struct Rover {
+    battery: u8,
+}
+
+fn patrol() -> Expr {
+    Expr::selector([
+        Expr::sequence([
+            Expr::leaf([Status::Success]),
+            Expr::leaf([Status::Running, Status::Failure]),
+        ]),
+        Expr::Inverter(Box::new(Expr::leaf([Status::Failure]))),
+    ])
+}
+
+#[test]
+fn hand_built_trees_are_checked_against_the_model() {
+    let recorder = Recorder::new();
+    let mut tree = Selector::new((
+        Sequence::new((
+            recorder.leaf([Status::Success], true),
+            recorder.leaf([Status::Running, Status::Failure], true),
+        )),
+        Inverter::new(recorder.leaf([Status::Failure], true)),
+    ));
+    let mut rover = Rover { battery: 80 };
+
+    let trace = check(&mut tree, &mut rover, &recorder, &patrol(), 4).unwrap();
+    assert_eq!(
+        trace.root_statuses,
+        [
+            Status::Running,
+            Status::Success,
+            Status::Running,
+            Status::Success
+        ]
+    );
+    assert_eq!(trace.ticks, [2, 4, 2]);
+    assert_eq!(trace.resets, [4, 4, 4]);
+    assert_eq!(rover.battery, 80);
+}
+
+#[test]
+fn structural_mismatches_are_reported_at_the_first_diverging_cycle() {
+    let recorder = Recorder::new();
+    let mut tree = Selector::new((
+        Sequence::new((
+            recorder.leaf([Status::Success], true),
+            recorder.leaf([Status::Running, Status::Failure], true),
+        )),
+        Retry::new(recorder.leaf([Status::Failure], true), 1),
+    ));
+
+    let divergence = check(&mut tree, &mut Rover::default(), &recorder, &patrol(), 4).unwrap_err();
+    assert_eq!(
+        divergence,
+        Divergence::Status {
+            cycle: 1,
+            runtime: Status::Failure,
+            model: Status::Success,
+        }
+    );
+    assert_eq!(
+        divergence.to_string(),
+        "cycle 1: runtime returned Failure, model returned Success"
+    );
+
+    let recorder = Recorder::new();
+    let mut tree = recorder.leaf([Status::Success], true);
+    assert_eq!(
+        check(&mut tree, &mut (), &recorder, &patrol(), 1).unwrap_err(),
+        Divergence::LeafCount {
+            runtime: 1,
+            model: 3,
+        }
+    );
+}
+
+#[test]
+fn leaf_activity_is_compared_even_when_statuses_agree() {
+    let expr = Expr::sequence([Expr::leaf([Status::Success]), Expr::leaf([Status::Success])]);
+    let recorder = Recorder::new();
+    let first = recorder.leaf([Status::Success], true);
+    let _second = recorder.leaf([Status::Success], true);
+    let mut tree = Sequence::new((first,));
+
+    let Divergence::Leaves {
+        cycle,
+        runtime,
+        model,
+    } = check(&mut tree, &mut (), &recorder, &expr, 2).unwrap_err()
+    else {
+        panic!("expected a leaf divergence");
+    };
+    assert_eq!(cycle, 0);
+    assert_eq!(runtime.ticks, [1, 0]);
+    assert_eq!(model.ticks, [1, 1]);
+}
+
+#[test]
+fn model_runs_standalone_and_counts_resets() {
+    let mut model = Model::new(&patrol());
+    assert_eq!(model.tick(), Status::Running);
+    model.reset();
+    assert_eq!(model.tick(), Status::Running);
+
+    let trace = model.trace();
+    assert_eq!(trace.root_statuses, [Status::Running, Status::Running]);
+    assert_eq!(trace.ticks, [2, 2, 0]);
+    assert_eq!(trace.resets, [1, 1, 1]);
+}
+
+#[test]
+fn built_runtimes_mirror_the_expression_shape() {
+    let recorder = Recorder::new();
+    let tree = build(&patrol(), &recorder);
+
+    let mut kinds = Vec::new();
+    walk(&tree, &mut |path, node: &dyn Inspect| {
+        kinds.push(format!("{path} {}", node.kind()));
+    });
+    assert_eq!(
+        kinds,
+        [
+            "root Selector",
+            "root/0 Sequence",
+            "root/0/0 ScriptLeaf",
+            "root/0/1 ScriptLeaf",
+            "root/1 Inverter",
+            "root/1/0 ScriptLeaf",
+        ]
+    );
+    assert_eq!(recorder.leaves(), 3);
+    assert!(compare(&patrol(), 8).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "invalid expression")]
+fn invalid_parallel_policies_are_rejected() {
+    Model::new(&Expr::Parallel {
+        policy: arbor_model::Policy::SuccessThreshold(3),
+        children: vec![Expr::leaf([Status::Success])],
+    });
+}
+
+struct Stalled;
+
+impl Node<()> for Stalled {
+    async fn tick(&mut self, _ctx: &mut ()) -> Status {
+        core::future::pending().await
+    }
+
+    fn reset(&mut self) {}
+}
+
+#[test]
+fn ticks_that_never_complete_are_reported() {
+    let recorder = Recorder::new();
+    let mut tree = Sequence::new((recorder.leaf([Status::Success], true), Stalled));
+    let expr = Expr::sequence([Expr::leaf([Status::Success])]);
+
+    let divergence = check(&mut tree, &mut (), &recorder, &expr, 1).unwrap_err();
+    assert_eq!(divergence, Divergence::Pending { cycle: 0 });
+    assert!(
+        divergence
+            .to_string()
+            .starts_with("cycle 0: runtime tick still pending after 1024 polls")
+    );
+}
+
+#[test]
+fn named_expressions_label_runtime_nodes_and_leaves() {
+    let expr = Expr::sequence([
+        Expr::leaf([Status::Success]).named("arm"),
+        Expr::leaf([Status::Running]),
+    ])
+    .named("preflight");
+    assert_eq!(expr.leaf_labels(), [Some("arm"), None]);
+
+    let recorder = Recorder::new();
+    let tree = build(&expr, &recorder);
+    let mut paths = Vec::new();
+    walk(&tree, &mut |path, _: &dyn Inspect| {
+        paths.push(path.to_string())
+    });
+    assert_eq!(paths, ["preflight", "preflight/arm", "preflight/1"]);
+    assert!(compare(&expr, 3).is_ok());
+}
@@ -0,0 +1,181 @@
+use arbor_core::Status::{self, Failure, Running, Success};
+use arbor_model::{Expr, Policy, Sensitivity, SensitivityError, Step};
+
+// too_hot, kill, ice_failed, rtl and patrol are leaves 0 to 4.
+fn safety() -> Expr {
+    Expr::ReactiveSelector(vec![
+        Expr::sequence([
+            Expr::leaf([Failure]).named("too_hot"),
+            Expr::leaf([Success]).named("kill"),
+        ]),
+        Expr::sequence([
+            Expr::Leaf {
+                script: vec![Failure, Success],
+                rewind_on_reset: false,
+            }
+            .named("ice_failed"),
+            Expr::leaf([Running]).named("rtl"),
+        ]),
+        Expr::leaf([Running]).named("patrol"),
+    ])
+}
+
+fn step(leaf: usize, status: Status) -> Step {
+    Step { leaf, status }
+}
+
+#[test]
+fn records_every_leaf_outcome_per_tick() {
+    assert_eq!(
+        Sensitivity::record(&safety(), 2),
+        [
+            vec![step(0, Failure), step(2, Failure), step(4, Running)],
+            vec![step(0, Failure), step(2, Success), step(3, Running)],
+        ]
+    );
+}
+
+#[test]
+fn flips_are_reported_with_their_effect() {
+    let recording = Sensitivity::record(&safety(), 2);
+    let sensitivity = Sensitivity::new(&safety(), &recording).unwrap();
+    assert_eq!(sensitivity.baseline(), [Running, Running]);
+
+    let flips = sensitivity.analyze();
+    assert_eq!(flips.len(), 12);
+    let messages: Vec<_> = flips.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages[..6],
+        [
+            "tick 0: `too_hot` Failure -> Success changes the executed actions (extrapolated)",
+            "tick 0: `too_hot` Failure -> Running changes the executed actions",
+            "tick 0: `ice_failed` Failure -> Success changes the executed actions (extrapolated)",
+            "tick 0: `ice_failed` Failure -> Running changes the executed actions",
+            "tick 0: `patrol` Running -> Success changes the root outcome",
+            "tick 0: `patrol` Running -> Failure changes the root outcome",
+        ]
+    );
+
+    // The recording never saw kill run, so in the flipped run it fails and
+    // the selector moves on to the icing check.
+    let kill = &flips[0];
+    assert_eq!(kill.root, [Running, Running]);
+    assert!(kill.matters() && kill.extrapolated);
+    assert!(flips[4].root_changed && !flips[4].actions_changed);
+    assert_eq!(flips[4].root, [Success, Running]);
+    assert_eq!(
+        messages[10],
+        "tick 1: `rtl` Running -> Success changes the root outcome"
+    );
+}
+
+#[test]
+fn only_selected_leaves_count_as_actions() {
+    let recording = Sensitivity::record(&safety(), 2);
+    let sensitivity = Sensitivity::new(&safety(), &recording)
+        .unwrap()
+        .actions([1, 3])
+        .unwrap();
+    let flips = sensitivity.analyze();
+
+    // Running instead of failing stops the selector, but no action changes.
+    assert_eq!(
+        flips[1].to_string(),
+        "tick 0: `too_hot` Failure -> Running changes nothing"
+    );
+    // At tick 1 the icing check decides whether to return to launch.
+    let rtl: Vec<_> = flips
+        .iter()
+        .filter(|flip| flip.tick == 1 && flip.actions_changed)
+        .map(|flip| (flip.label, flip.to))
+        .collect();
+    assert_eq!(
+        rtl,
+        [
+            (Some("too_hot"), Success),
+            (Some("too_hot"), Running),
+            (Some("ice_failed"), Failure),
+            (Some("ice_failed"), Running),
+        ]
+    );
+}
+
+#[test]
+fn choice_leaves_only_flip_to_allowed_statuses() {
+    let expr = Expr::sequence([Expr::choice([Success, Failure]), Expr::leaf([Success])]);
+    let recording = [vec![step(0, Success), step(1, Success)]];
+    let flips = Sensitivity::new(&expr, &recording).unwrap().analyze();
+
+    let flipped: Vec<_> = flips.iter().map(|flip| (flip.leaf, flip.to)).collect();
+    assert_eq!(flipped, [(0, Failure), (1, Failure), (1, Running)]);
+    assert_eq!(
+        flips[0].to_string(),
+        "tick 0: leaf 0 Success -> Failure changes the root outcome and the executed actions"
+    );
+}
+
+#[test]
+fn recordings_must_match_the_tree() {
+    let expr = Expr::sequence([Expr::any(), Expr::any()]);
+
+    let missing = [vec![step(0, Success)]];
+    assert_eq!(
+        Sensitivity::new(&expr, &missing).err(),
+        Some(SensitivityError::NotRecorded { tick: 0, leaf: 1 })
+    );
+    let extra = [vec![step(0, Failure), step(1, Success)]];
+    assert_eq!(
+        Sensitivity::new(&expr, &extra).err(),
+        Some(SensitivityError::NotTicked { tick: 0, leaf: 1 })
+    );
+    let unknown = [vec![step(0, Failure)], vec![step(5, Success)]];
+    let error = Sensitivity::new(&expr, &unknown).err().unwrap();
+    assert_eq!(error.to_string(), "tick 1: the tree has no leaf 5");
+
+    let recording = [vec![step(0, Failure)]];
+    let sensitivity = Sensitivity::new(&expr, &recording).unwrap();
+    assert_eq!(
+        sensitivity.actions([1, 2]).err(),
+        Some(SensitivityError::UnknownAction(2))
+    );
+
+    let invalid = Expr::Parallel {
+        policy: Policy::MainChild(1),
+        children: vec![Expr::any()],
+    };
+    assert!(matches!(
+        Sensitivity::new(&invalid, &recording).err(),
+        Some(SensitivityError::InvalidExpr(_))
+    ));
+}
+
+#[cfg(feature = "proptest")]
+mod generated {
+    use arbor_model::{Model, strategy};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn flips_replay_the_recording_up_to_the_flipped_tick(
+            expr in strategy::expr_with(3, 24, 3),
+            ticks in 1usize..6,
+        ) {
+            let recording = Sensitivity::record(&expr, ticks);
+            let sensitivity = Sensitivity::new(&expr, &recording).unwrap();
+
+            let mut model = Model::new(&expr);
+            let root: Vec<_> = (0..ticks).map(|_| model.tick()).collect();
+            prop_assert_eq!(sensitivity.baseline(), &root[..]);
+
+            for flip in sensitivity.analyze() {
+                prop_assert_ne!(flip.from, flip.to);
+                prop_assert_eq!(&flip.root[..flip.tick], &root[..flip.tick]);
+                if flip.root_changed {
+                    prop_assert!(flip.matters());
+                }
+            }
+        }
+    }
+}
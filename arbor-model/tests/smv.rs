@@ -0,0 +1,232 @@
+use arbor_core::{
+    Memory,
+    Status::{self, Failure, Running, Success},
+};
+use arbor_model::{ExportError, Expr, Model, Policy, Smv};
+
+fn cooling() -> Expr {
+    Expr::selector([
+        Expr::sequence([
+            Expr::choice([Success, Failure]).named("too_hot"),
+            Expr::leaf([Running, Success]).named("fan"),
+        ]),
+        Expr::leaf([Success]).named("idle"),
+    ])
+}
+
+// Ticks the model and the exported module side by side with the same
+// choices, checking the root status and which leaves ran with what result.
+fn assert_agrees(expr: &Expr, choices: &[Vec<usize>]) {
+    let smv = Smv::new(expr).unwrap();
+    let mut model = Model::new(expr);
+    let mut simulation = smv.simulate();
+    assert_eq!(smv.leaves(), expr.leaf_count());
+
+    for (tick, picks) in choices.iter().enumerate() {
+        let choose = |leaf: usize, allowed: &[Status]| allowed[picks[leaf] % allowed.len()];
+        let before = model.trace().ticks.clone();
+        let expected = model.tick_with(choose);
+        let actual = simulation.tick_with(choose);
+        assert_eq!(actual, expected, "tick {tick}: root status of {expr:?}");
+
+        let trace = model.trace();
+        for (leaf, ticks) in before.iter().enumerate() {
+            let ticked = trace.ticks[leaf] > *ticks;
+            assert_eq!(
+                simulation.ticked(leaf),
+                ticked,
+                "tick {tick}: leaf {leaf} of {expr:?}"
+            );
+            let status = ticked.then(|| *trace.leaf_statuses[leaf].last().unwrap());
+            assert_eq!(
+                simulation.leaf_status(leaf),
+                status,
+                "tick {tick}: leaf {leaf} of {expr:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn renders_a_nusmv_module() {
+    let smv = Smv::new(&cooling())
+        .unwrap()
+        .ltl("G (too_hot_ticked & too_hot_status = success -> fan_ticked)");
+    let text = smv.to_string();
+
+    assert!(text.starts_with("MODULE main\nVAR\n"));
+    for line in [
+        "  n0_index : 0..1;",
+        "  n1_index : 0..1;",
+        "  n3_cursor : 0..1;",
+        "  leaf0_choice : {success, failure};",
+        "  n2_ticked := (n1_ticked & (n1_index = 0));",
+        "  too_hot_status := n2_status;",
+        "  root_status := n0_status;",
+        "  init(n1_index) := 0;",
+        "  next(n3_cursor) := case n3_reset : 0; n3_ticked : 1; TRUE : n3_cursor; esac;",
+        "LTLSPEC G (too_hot_ticked & too_hot_status = success -> fan_ticked)",
+    ] {
+        assert!(
+            text.lines().any(|rendered| rendered == line),
+            "{line}\n{text}"
+        );
+    }
+}
+
+#[test]
+fn simulation_follows_the_module() {
+    let smv = Smv::new(&cooling()).unwrap();
+    let mut simulation = smv.simulate();
+    let hot = |_: usize, _: &[Status]| Success;
+
+    assert_eq!(simulation.tick_with(hot), Running);
+    assert_eq!(simulation.leaf_status(1), Some(Running));
+    assert!(!simulation.ticked(2));
+    // The sequence resumes at the fan without asking the sensor again.
+    assert_eq!(simulation.tick_with(|_, _| Failure), Success);
+    assert!(!simulation.ticked(0));
+    assert_eq!(simulation.leaf_status(1), Some(Success));
+
+    assert_eq!(simulation.tick_with(|_, _| Failure), Success);
+    assert_eq!(simulation.leaf_status(0), Some(Failure));
+    assert_eq!(simulation.leaf_status(2), Some(Success));
+    assert_eq!(simulation.leaf_status(1), None);
+}
+
+#[test]
+fn labels_must_be_unique_identifiers() {
+    let twice = Expr::sequence([
+        Expr::leaf([Success]).named("dock"),
+        Expr::leaf([Success]).named("dock"),
+    ]);
+    assert_eq!(Smv::new(&twice), Err(ExportError::DuplicateLabel("dock")));
+
+    let spaced = Expr::leaf([Success]).named("go home");
+    assert_eq!(Smv::new(&spaced), Err(ExportError::InvalidLabel("go home")));
+    assert_eq!(
+        ExportError::InvalidLabel("go home").to_string(),
+        "label `go home` is not a valid NuSMV identifier"
+    );
+}
+
+#[test]
+fn labels_cannot_shadow_generated_names() {
+    for label in ["n0", "root", "leaf0", "n12", "leaf3"] {
+        let expr = Expr::sequence([Expr::leaf([Success]).named(label)]);
+        assert_eq!(Smv::new(&expr), Err(ExportError::ReservedLabel(label)));
+    }
+    for label in ["n", "node0", "leaf", "leaf_0", "rooted"] {
+        assert!(
+            Smv::new(&Expr::leaf([Success]).named(label)).is_ok(),
+            "{label}"
+        );
+    }
+    assert_eq!(
+        ExportError::ReservedLabel("root").to_string(),
+        "label `root` is reserved for generated names"
+    );
+}
+
+#[test]
+fn invalid_expressions_are_errors() {
+    let expr = Expr::Parallel {
+        policy: Policy::SuccessThreshold(3),
+        children: vec![Expr::any()],
+    };
+    let error = Smv::new(&expr).unwrap_err();
+    assert!(matches!(error, ExportError::InvalidExpr(_)));
+    assert!(error.to_string().starts_with("invalid expression: "));
+}
+
+#[test]
+fn memory_semantics_match_the_model() {
+    let choices: Vec<Vec<usize>> = (0..12).map(|tick| vec![tick, tick / 2, tick / 3]).collect();
+
+    for memory in [Memory::Resume, Memory::Persistent] {
+        let expr = Expr::Selector {
+            children: vec![
+                Expr::Sequence {
+                    children: vec![Expr::any(), Expr::leaf([Success, Running, Failure])],
+                    memory,
+                },
+                Expr::Repeat {
+                    child: Box::new(Expr::any()),
+                    max_successes: 2,
+                },
+            ],
+            memory,
+        };
+        assert_agrees(&expr, &choices);
+    }
+
+    let expr = Expr::ParallelWithMemory {
+        policy: Policy::Thresholds {
+            success: 2,
+            failure: 2,
+        },
+        children: vec![
+            Expr::any(),
+            Expr::ReactiveSelector(vec![Expr::any(), Expr::leaf([Running, Failure])]),
+            Expr::Retry {
+                child: Box::new(Expr::leaf([Failure, Failure, Success])),
+                max_failures: 3,
+            },
+        ],
+    };
+    assert_agrees(&expr, &choices);
+}
+
+#[cfg(feature = "proptest")]
+mod generated {
+    use arbor_model::strategy;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // The generators only produce scripted leaves, so turn every other one
+    // into a choice between the distinct statuses its script would have
+    // returned.
+    fn with_choices(expr: &mut Expr, flip: &mut bool) {
+        if let Expr::Leaf { script, .. } = expr {
+            *flip = !*flip;
+            if *flip {
+                let mut allowed = script.clone();
+                allowed.sort_by_key(|status| *status as u8);
+                allowed.dedup();
+                *expr = Expr::choice(allowed);
+            }
+            return;
+        }
+        match expr {
+            Expr::Sequence { children, .. }
+            | Expr::Selector { children, .. }
+            | Expr::ReactiveSequence(children)
+            | Expr::ReactiveSelector(children)
+            | Expr::Parallel { children, .. }
+            | Expr::ParallelWithMemory { children, .. } => {
+                for child in children {
+                    with_choices(child, flip);
+                }
+            }
+            Expr::Inverter(child)
+            | Expr::Retry { child, .. }
+            | Expr::Repeat { child, .. }
+            | Expr::ForceSuccess(child)
+            | Expr::ForceFailure(child)
+            | Expr::Named { child, .. } => with_choices(child, flip),
+            Expr::Leaf { .. } | Expr::Choice(_) => {}
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn exported_modules_agree_with_the_model(
+            mut expr in strategy::expr_with(4, 48, 4),
+            choices in prop::collection::vec(prop::collection::vec(0usize..3, 64), 1..12),
+        ) {
+            with_choices(&mut expr, &mut false);
+            assert_agrees(&expr, &choices);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "proptest")]
+
+use arbor_core::Status;
+use arbor_model::{Expr, Model, Recorder, build, check, strategy};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn generated_trees_are_valid_and_agree_with_the_runtime(
+        expr in strategy::expr_with(3, 32, 4),
+        cycles in 1usize..10,
+    ) {
+        prop_assert!(expr.validate().is_ok());
+        prop_assert!(expr.depth() <= 4);
+
+        let recorder = Recorder::new();
+        let mut tree = build(&expr, &recorder);
+        prop_assert_eq!(recorder.leaves(), expr.leaf_count());
+
+        let trace = check(&mut tree, &mut (), &recorder, &expr, cycles).unwrap();
+        prop_assert_eq!(trace.root_statuses.len(), cycles);
+        let mut model = Model::new(&expr);
+        for _ in 0..cycles {
+            model.tick();
+        }
+        prop_assert_eq!(model.trace(), &trace);
+    }
+}
+
+proptest! {
+    #[test]
+    fn parallel_policies_are_valid_for_their_child_count(
+        (children, policy) in (1usize..=6).prop_flat_map(|n| (Just(n), strategy::policy(n))),
+    ) {
+        let expr = Expr::Parallel {
+            policy,
+            children: vec![Expr::leaf([Status::Success]); children],
+        };
+        prop_assert!(expr.validate().is_ok());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::TickTrace;
+
+#[track_caller]
+pub fn assert_ticked(trace: &TickTrace, leaf: usize, cycles: &[usize]) {
+    let ticked = trace.ticked_in(leaf);
+    assert!(
+        ticked == cycles,
+        "leaf {leaf} was expected to tick in cycles {cycles:?} but ticked in {ticked:?}\nstatuses: {:?}",
+        trace.statuses()
+    );
+}
+
+#[track_caller]
+pub fn assert_reset_between(trace: &TickTrace, leaf: usize, from: usize, to: usize) {
+    assert!(from < to, "cycle {from} is not before cycle {to}");
+    assert!(
+        to < trace.cycles.len(),
+        "cycle {to} has not run; the trace has {} cycles",
+        trace.cycles.len()
+    );
+    let resets = trace.resets_between(leaf, from, to);
+    assert!(
+        resets > 0,
+        "leaf {leaf} was not reset between cycle {from} and cycle {to}\nstatuses: {:?}",
+        trace.statuses()
+    );
+}
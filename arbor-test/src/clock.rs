@@ -0,0 +1,36 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use arbor_core::Clock;
+
+// A clock that only moves when told to. Clones share the same time, so the
+// copy handed to a `Timeout` or `DebounceFor` sees every `advance`.
+#[derive(Clone, Debug, Default)]
+pub struct MockClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance(&self, delta: Duration) {
+        self.now.set(self.now.get().saturating_add(delta));
+    }
+
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for MockClock {
+    type Instant = Duration;
+
+    fn now(&self) -> Self::Instant {
+        self.now.get()
+    }
+
+    fn elapsed(&self, since: Self::Instant) -> Duration {
+        self.now.get().saturating_sub(since)
+    }
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use arbor_core::{Node, Status};
+
+use crate::{Cycle, LeafProbe, LeafSet, MockClock, TickCtx, TickTrace, block_on};
+
+// Owns a tree of scripted leaves and its context, ticks it synchronously
+// and keeps a per-cycle trace for the assertion helpers.
+pub struct Harness<N> {
+    tree: N,
+    ctx: TickCtx,
+    probes: Vec<LeafProbe>,
+    clock: Option<MockClock>,
+    trace: TickTrace,
+    last_ticks: Vec<usize>,
+    last_resets: Vec<usize>,
+}
+
+impl<N> Harness<N>
+where
+    N: Node<TickCtx>,
+{
+    pub fn new(tree: N, leaves: LeafSet) -> Self {
+        let count = leaves.len();
+        Self {
+            tree,
+            ctx: TickCtx::new(count),
+            probes: leaves.into_probes(),
+            clock: None,
+            trace: TickTrace::default(),
+            last_ticks: vec![0; count],
+            last_resets: vec![0; count],
+        }
+    }
+
+    // Gives `advance` and `tick_after` a clock to move; pass a clone of the
+    // one the tree's timed nodes were built with.
+    pub fn with_clock(mut self, clock: MockClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn tick(&mut self) -> Status {
+        let status = block_on(self.tree.tick(&mut self.ctx));
+        self.ctx.record_root(status);
+
+        let ticks = self.ctx.ticks.clone();
+        let resets: Vec<usize> = self.probes.iter().map(LeafProbe::count).collect();
+        self.trace.cycles.push(Cycle {
+            status,
+            ticks: ticks
+                .iter()
+                .zip(&self.last_ticks)
+                .map(|(now, before)| now - before)
+                .collect(),
+            resets: resets
+                .iter()
+                .zip(&self.last_resets)
+                .map(|(now, before)| now - before)
+                .collect(),
+        });
+        self.last_ticks = ticks;
+        self.last_resets = resets;
+
+        status
+    }
+
+    pub fn run(&mut self, cycles: usize) -> Vec<Status> {
+        (0..cycles).map(|_| self.tick()).collect()
+    }
+
+    pub fn advance(&mut self, delta: Duration) {
+        self.clock
+            .as_ref()
+            .expect("harness has no clock; call `with_clock` first")
+            .advance(delta);
+    }
+
+    pub fn tick_after(&mut self, delta: Duration) -> Status {
+        self.advance(delta);
+        self.tick()
+    }
+
+    pub fn reset(&mut self) {
+        self.tree.reset();
+    }
+
+    pub fn trace(&self) -> &TickTrace {
+        &self.trace
+    }
+
+    pub fn ctx(&self) -> &TickCtx {
+        &self.ctx
+    }
+
+    pub fn tree(&self) -> &N {
+        &self.tree
+    }
+
+    pub fn tree_mut(&mut self) -> &mut N {
+        &mut self.tree
+    }
+
+    pub fn into_tree(self) -> N {
+        self.tree
+    }
+}
@@ -0,0 +1,154 @@
+use std::{cell::Cell, rc::Rc};
+
+use arbor_core::{Cost, Inspect, Node, Status, TickCost};
+
+use crate::TickCtx;
+
+#[derive(Debug, Clone)]
+pub struct LeafProbe(Rc<Cell<usize>>);
+
+impl LeafProbe {
+    pub fn count(&self) -> usize {
+        self.0.get()
+    }
+}
+
+// Plays its script one status per tick and repeats the last entry once the
+// script runs out. Ticks and statuses land in the `TickCtx` slot for `id`;
+// resets are counted through the optional probe.
+#[derive(Debug, Clone)]
+pub struct ScriptedLeaf {
+    id: usize,
+    script: Vec<Status>,
+    cursor: usize,
+    rewind_on_reset: bool,
+    reset_counter: Rc<Cell<usize>>,
+}
+
+impl ScriptedLeaf {
+    pub fn new(id: usize, script: Vec<Status>) -> Self {
+        Self::with_reset_behavior(id, script, true)
+    }
+
+    pub fn with_reset_behavior(id: usize, script: Vec<Status>, rewind_on_reset: bool) -> Self {
+        Self {
+            id,
+            script,
+            cursor: 0,
+            rewind_on_reset,
+            reset_counter: Rc::new(Cell::new(0)),
+        }
+    }
+
+    pub fn with_probe(id: usize, script: Vec<Status>, rewind_on_reset: bool) -> (Self, LeafProbe) {
+        let leaf = Self::with_reset_behavior(id, script, rewind_on_reset);
+        let probe = LeafProbe(leaf.reset_counter.clone());
+        (leaf, probe)
+    }
+
+    pub fn sticky(id: usize, status: Status) -> Self {
+        Self::new(id, vec![status])
+    }
+
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    fn current_status(&self) -> Status {
+        self.script
+            .get(self.cursor)
+            .copied()
+            .or_else(|| self.script.last().copied())
+            .unwrap_or(Status::Failure)
+    }
+}
+
+impl Node<TickCtx> for ScriptedLeaf {
+    async fn tick(&mut self, ctx: &mut TickCtx) -> Status {
+        if self.id >= ctx.ticks.len() {
+            panic!(
+                "leaf id {} out of bounds for tick vector of length {}",
+                self.id,
+                ctx.ticks.len()
+            );
+        }
+
+        ctx.ticks[self.id] += 1;
+
+        let status = self.current_status();
+        ctx.leaf_statuses[self.id].push(status);
+
+        if self.cursor + 1 < self.script.len() {
+            self.cursor += 1;
+        }
+
+        status
+    }
+
+    fn reset(&mut self) {
+        self.reset_counter
+            .set(self.reset_counter.get().saturating_add(1));
+        if self.rewind_on_reset {
+            self.cursor = 0;
+        }
+    }
+}
+
+impl Inspect for ScriptedLeaf {
+    fn kind(&self) -> &'static str {
+        "ScriptedLeaf"
+    }
+}
+
+impl TickCost for ScriptedLeaf {
+    const COST: Cost = Cost::LEAF;
+}
+
+pub fn leaf(id: usize, script: &[Status]) -> ScriptedLeaf {
+    ScriptedLeaf::new(id, script.to_vec())
+}
+
+// Hands out probed leaves with consecutive ids, so a `Harness` can size its
+// context and read every reset counter without manual bookkeeping.
+#[derive(Debug, Default)]
+pub struct LeafSet {
+    probes: Vec<LeafProbe>,
+}
+
+impl LeafSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn leaf(&mut self, script: &[Status]) -> ScriptedLeaf {
+        self.push(script, true)
+    }
+
+    // The script keeps its position across resets instead of rewinding.
+    pub fn leaf_keeping_cursor(&mut self, script: &[Status]) -> ScriptedLeaf {
+        self.push(script, false)
+    }
+
+    pub fn len(&self) -> usize {
+        self.probes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.probes.is_empty()
+    }
+
+    pub fn probes(&self) -> &[LeafProbe] {
+        &self.probes
+    }
+
+    pub fn into_probes(self) -> Vec<LeafProbe> {
+        self.probes
+    }
+
+    fn push(&mut self, script: &[Status], rewind_on_reset: bool) -> ScriptedLeaf {
+        let (leaf, probe) =
+            ScriptedLeaf::with_probe(self.probes.len(), script.to_vec(), rewind_on_reset);
+        self.probes.push(probe);
+        leaf
+    }
+}
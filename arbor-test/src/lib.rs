@@ -0,0 +1,13 @@
+mod assert;
+mod clock;
+mod harness;
+mod leaf;
+mod runtime;
+mod trace;
+
+pub use assert::{assert_reset_between, assert_ticked};
+pub use clock::MockClock;
+pub use harness::Harness;
+pub use leaf::{LeafProbe, LeafSet, ScriptedLeaf, leaf};
+pub use runtime::{Finished, StillRunning, block_on, block_on_paused, run_until_done};
+pub use trace::{Cycle, TickCtx, TickTrace, TraceSnapshot};
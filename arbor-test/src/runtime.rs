@@ -0,0 +1,112 @@
+use std::{fmt, future::Future, time::Duration};
+
+use arbor_core::{Node, Status};
+use tokio::time::Instant;
+
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("runtime must build");
+    runtime.block_on(future)
+}
+
+// Tokio's clock starts paused and jumps ahead whenever every task is
+// sleeping, so tick intervals and timeouts cost no wall-clock time.
+pub fn block_on_paused<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .expect("runtime must build");
+    runtime.block_on(future)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Finished {
+    pub status: Status,
+    pub ticks: usize,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StillRunning {
+    pub ticks: usize,
+    pub limit: Duration,
+}
+
+impl fmt::Display for StillRunning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tree was still running after {} ticks and {:?}",
+            self.ticks, self.limit
+        )
+    }
+}
+
+impl std::error::Error for StillRunning {}
+
+// Ticks the root every `tick_interval`, like `arbor::tick_until_done`, until
+// it finishes or `limit` of Tokio time passes. Elapsed time is measured on the Tokio clock, so under paused time
+// it is exact: with a 10ms interval, a root that finishes on its third tick
+// reports 20ms.
+pub async fn run_until_done<Ctx, N>(
+    root: &mut N,
+    ctx: &mut Ctx,
+    tick_interval: Duration,
+    limit: Duration,
+) -> Result<Finished, StillRunning>
+where
+    N: Node<Ctx>,
+{
+    let mut counted = Counted { root, ticks: 0 };
+    let start = Instant::now();
+    let run = async {
+        loop {
+            let status = counted.tick(ctx).await;
+            if status != Status::Running {
+                return status;
+            }
+            tokio::time::sleep(tick_interval).await;
+        }
+    };
+    let result = tokio::time::timeout(limit, run).await;
+
+    match result {
+        Ok(status) => Ok(Finished {
+            status,
+            ticks: counted.ticks,
+            elapsed: start.elapsed(),
+        }),
+        Err(_) => Err(StillRunning {
+            ticks: counted.ticks,
+            limit,
+        }),
+    }
+}
+
+struct Counted<'a, N> {
+    root: &'a mut N,
+    ticks: usize,
+}
+
+impl<Ctx, N> Node<Ctx> for Counted<'_, N>
+where
+    N: Node<Ctx>,
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.ticks += 1;
+        self.root.tick(ctx).await
+    }
+
+    fn reset(&mut self) {
+        self.root.reset();
+    }
+}
@@ -0,0 +1,90 @@
+use arbor_core::Status;
+
+use crate::LeafProbe;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceSnapshot {
+    pub ticks: Vec<usize>,
+    pub resets: Vec<usize>,
+    pub leaf_statuses: Vec<Vec<Status>>,
+    pub root_statuses: Vec<Status>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TickCtx {
+    pub ticks: Vec<usize>,
+    pub leaf_statuses: Vec<Vec<Status>>,
+    pub root_statuses: Vec<Status>,
+}
+
+impl TickCtx {
+    pub fn new(leaves: usize) -> Self {
+        Self {
+            ticks: vec![0; leaves],
+            leaf_statuses: vec![Vec::new(); leaves],
+            root_statuses: Vec::new(),
+        }
+    }
+
+    pub fn record_root(&mut self, status: Status) {
+        self.root_statuses.push(status);
+    }
+
+    pub fn snapshot(&self) -> TraceSnapshot {
+        TraceSnapshot {
+            ticks: self.ticks.clone(),
+            resets: vec![0; self.ticks.len()],
+            leaf_statuses: self.leaf_statuses.clone(),
+            root_statuses: self.root_statuses.clone(),
+        }
+    }
+
+    pub fn snapshot_with_probes(&self, probes: &[LeafProbe]) -> TraceSnapshot {
+        let resets = probes.iter().map(LeafProbe::count).collect();
+        TraceSnapshot {
+            ticks: self.ticks.clone(),
+            resets,
+            leaf_statuses: self.leaf_statuses.clone(),
+            root_statuses: self.root_statuses.clone(),
+        }
+    }
+}
+
+// What happened to each leaf during one root tick. `resets` also counts
+// resets since the previous cycle ended, such as a `Harness::reset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub status: Status,
+    pub ticks: Vec<usize>,
+    pub resets: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TickTrace {
+    pub cycles: Vec<Cycle>,
+}
+
+impl TickTrace {
+    pub fn statuses(&self) -> Vec<Status> {
+        self.cycles.iter().map(|cycle| cycle.status).collect()
+    }
+
+    pub fn ticked_in(&self, leaf: usize) -> Vec<usize> {
+        self.cycles
+            .iter()
+            .enumerate()
+            .filter(|(_, cycle)| cycle.ticks.get(leaf).is_some_and(|ticks| *ticks > 0))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // Resets after cycle `from` finished, up to the end of cycle `to`.
+    pub fn resets_between(&self, leaf: usize, from: usize, to: usize) -> usize {
+        self.cycles
+            .iter()
+            .take(to + 1)
+            .skip(from + 1)
+            .map(|cycle| cycle.resets.get(leaf).copied().unwrap_or(0))
+            .sum()
+    }
+}
@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use arbor::{Action, ReactiveSequence, Sequence, Status, Timeout};
+use arbor_test::{
+    Finished, Harness, LeafSet, MockClock, StillRunning, assert_reset_between, assert_ticked,
+    block_on_paused, run_until_done,
+};
+
+#[test]
+fn harness_records_ticks_and_resets_per_cycle() {
+    let mut leaves = LeafSet::new();
+    let tree = ReactiveSequence::new((
+        leaves.leaf(&[Status::Success, Status::Success, Status::Failure]),
+        leaves.leaf(&[Status::Running]),
+    ));
+    let mut harness = Harness::new(tree, leaves);
+
+    assert_eq!(
+        harness.run(3),
+        [Status::Running, Status::Running, Status::Failure]
+    );
+    assert_ticked(harness.trace(), 0, &[0, 1, 2]);
+    assert_ticked(harness.trace(), 1, &[0, 1]);
+    assert_reset_between(harness.trace(), 1, 1, 2);
+    assert_eq!(harness.trace().resets_between(1, 0, 1), 0);
+    assert_eq!(harness.ctx().root_statuses.len(), 3);
+}
+
+#[test]
+fn resets_outside_a_tick_count_towards_the_next_cycle() {
+    let mut leaves = LeafSet::new();
+    let tree = Sequence::new((
+        leaves.leaf(&[Status::Running]),
+        leaves.leaf_keeping_cursor(&[Status::Success]),
+    ));
+    let mut harness = Harness::new(tree, leaves);
+
+    harness.tick();
+    harness.reset();
+    harness.tick();
+
+    assert_reset_between(harness.trace(), 0, 0, 1);
+    assert_eq!(harness.trace().cycles[1].resets, [1, 1]);
+    assert_eq!(harness.trace().statuses(), [Status::Running; 2]);
+}
+
+#[test]
+#[should_panic(expected = "leaf 1 was expected to tick in cycles [0] but ticked in []")]
+fn assert_ticked_reports_the_cycles_that_ran() {
+    let mut leaves = LeafSet::new();
+    let tree = Sequence::new((
+        leaves.leaf(&[Status::Failure]),
+        leaves.leaf(&[Status::Success]),
+    ));
+    let mut harness = Harness::new(tree, leaves);
+
+    harness.tick();
+    assert_ticked(harness.trace(), 1, &[0]);
+}
+
+#[test]
+#[should_panic(expected = "leaf 0 was not reset between cycle 0 and cycle 1")]
+fn assert_reset_between_fails_without_a_reset() {
+    let mut leaves = LeafSet::new();
+    let tree = Sequence::new((leaves.leaf(&[Status::Running]),));
+    let mut harness = Harness::new(tree, leaves);
+
+    harness.run(2);
+    assert_reset_between(harness.trace(), 0, 0, 1);
+}
+
+#[test]
+fn manual_clock_drives_timed_nodes() {
+    let clock = MockClock::new();
+    let mut leaves = LeafSet::new();
+    let tree = Timeout::new(
+        leaves.leaf(&[Status::Running]),
+        clock.clone(),
+        Duration::from_millis(100),
+    );
+    let mut harness = Harness::new(tree, leaves).with_clock(clock.clone());
+
+    assert_eq!(harness.tick(), Status::Running);
+    assert_eq!(
+        harness.tick_after(Duration::from_millis(60)),
+        Status::Running
+    );
+    assert_eq!(
+        harness.tick_after(Duration::from_millis(40)),
+        Status::Failure
+    );
+    assert_reset_between(harness.trace(), 0, 1, 2);
+    assert_ticked(harness.trace(), 0, &[0, 1]);
+
+    clock.set(Duration::ZERO);
+    assert_eq!(harness.tick(), Status::Running);
+}
+
+#[test]
+#[should_panic(expected = "harness has no clock")]
+fn advancing_without_a_clock_panics() {
+    let mut leaves = LeafSet::new();
+    let tree = leaves.leaf(&[Status::Success]);
+    Harness::new(tree, leaves).advance(Duration::from_millis(1));
+}
+
+#[test]
+fn run_until_done_measures_paused_tokio_time() {
+    let mut remaining = 2;
+    let mut tree = Action::new(move |_: &mut ()| {
+        let done = remaining == 0;
+        remaining -= usize::from(!done);
+        async move {
+            if done {
+                Status::Success
+            } else {
+                Status::Running
+            }
+        }
+    });
+
+    let finished = block_on_paused(run_until_done(
+        &mut tree,
+        &mut (),
+        Duration::from_millis(10),
+        Duration::from_secs(1),
+    ));
+    assert_eq!(
+        finished,
+        Ok(Finished {
+            status: Status::Success,
+            ticks: 3,
+            elapsed: Duration::from_millis(20),
+        })
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn run_until_done_gives_up_after_the_limit() {
+    let mut tree = Action::new(|_: &mut ()| async { Status::Running });
+
+    let error = run_until_done(
+        &mut tree,
+        &mut (),
+        Duration::from_millis(10),
+        Duration::from_millis(45),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        error,
+        StillRunning {
+            ticks: 5,
+            limit: Duration::from_millis(45),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "tree was still running after 5 ticks and 45ms"
+    );
+}
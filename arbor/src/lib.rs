@@ -1,11 +1,26 @@
 use core::time::Duration;
 
+mod reload;
+mod swap;
+
 pub use arbor_core::{
-    Action, Clock, Condition, Constant, ForceFailure, ForceSuccess, Inverter, Node, NodeList,
-    Parallel, ParallelPolicy, ReactiveSelector, ReactiveSequence, Repeat, Retry, Selector,
-    Sequence, Status, Timeout,
+    Action, And, Blackboard, BlackboardContext, BlackboardError, Callback, CheckEntry, Clock,
+    ConcurrentNodeList, ConcurrentParallel, Condition, Conditioned, Constant, Cost, CostList,
+    Debounce, DebounceFor, Diagnostic, Effort, Entry, Finding, FixedStorage, ForceFailure,
+    ForceSuccess, Formula, Fresh, Hysteresis, IfThenElse, Inspect, InspectList, Inverter, Key,
+    MAX_OBSERVED, MAX_PATH_DEPTH, MAX_SCOPE_DEPTH, MapCtx, MapStorage, Memory, Monitor,
+    MonitorContext, Monitored, Monitors, Named, Node, NodeCount, NodeExt, NodeList, NodePath,
+    NodeState, Not, Observation, Observed, Or, Parallel, ParallelCounts, ParallelError,
+    ParallelPolicy, ParallelWithMemory, Params, PathSegment, Port, PortDirection, Ports,
+    Precondition, Predicate, ReactiveSelector, ReactiveSequence, Remap, Repeat, Retry, Scope,
+    Selector, Sequence, SetEntry, Severity, Span, SplitContext, Status, Storage, Switch,
+    SwitchError, TickCost, Timeout, Tracked, Unset, Value, ValueKind, Violation, Weighted,
+    WhileDoElse, WithPorts, cost_of, diagnostics, find, findings, lint, validate, walk,
 };
 
+pub use reload::{ParseError, ParseErrorKind, Registry, ReloadError, Reloader};
+pub use swap::{BoxedNode, DynNode, SwapEvent, SwapHandle, Swappable};
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct TokioClock;
 
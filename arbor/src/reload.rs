@@ -0,0 +1,653 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use arbor_core::{
+    Diagnostic, ForceFailure, ForceSuccess, Inverter, Memory, Named, Parallel, ParallelError,
+    ParallelPolicy, ParallelWithMemory, Port, ReactiveSelector, ReactiveSequence, Repeat, Retry,
+    Selector, Sequence, validate,
+};
+
+use crate::{BoxedNode, DynNode, SwapEvent, Swappable, swap::SwapHandle};
+
+type Factory<Ctx> = Box<dyn Fn() -> BoxedNode<Ctx> + Send + Sync>;
+
+pub struct Registry<Ctx> {
+    leaves: BTreeMap<&'static str, Factory<Ctx>>,
+    environment: Vec<Port>,
+    labels: BTreeSet<&'static str>,
+}
+
+impl<Ctx> Default for Registry<Ctx> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ctx> Registry<Ctx> {
+    pub fn new() -> Self {
+        Self {
+            leaves: BTreeMap::new(),
+            environment: Vec::new(),
+            labels: BTreeSet::new(),
+        }
+    }
+
+    pub fn register<N, F>(mut self, name: &'static str, factory: F) -> Self
+    where
+        F: Fn() -> N + Send + Sync + 'static,
+        N: DynNode<Ctx> + Send + 'static,
+    {
+        self.leaves.insert(
+            name,
+            Box::new(move || -> BoxedNode<Ctx> { Box::new(factory()) }),
+        );
+        self
+    }
+
+    // Ports the application itself provides before ticking (outputs) and
+    // reads afterwards (inputs), passed to `validate` for every build.
+    pub fn environment(mut self, ports: &[Port]) -> Self {
+        self.environment.extend_from_slice(ports);
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.leaves.contains_key(name)
+    }
+
+    pub fn parse(&mut self, source: &str) -> Result<BoxedNode<Ctx>, ParseError>
+    where
+        Ctx: 'static,
+    {
+        let mut parser = Parser {
+            lexer: Lexer::new(source),
+            registry: self,
+        };
+        let root = parser.node()?;
+        match parser.lexer.next()? {
+            None => Ok(root),
+            Some(token) => Err(token.unexpected("end of file")),
+        }
+    }
+
+    pub fn build(&mut self, source: &str) -> Result<BoxedNode<Ctx>, ReloadError>
+    where
+        Ctx: 'static,
+    {
+        let root = self.parse(source)?;
+
+        let mut diagnostics = Vec::new();
+        validate(&root, &self.environment, &mut |diagnostic| {
+            diagnostics.push(*diagnostic);
+        });
+        if diagnostics.is_empty() {
+            Ok(root)
+        } else {
+            Err(ReloadError::Invalid(diagnostics))
+        }
+    }
+
+    // Node labels are `&'static str`. Each distinct label is leaked once and
+    // reused by later reloads, so editing a file repeatedly does not grow
+    // memory unless it keeps introducing new labels.
+    fn intern(&mut self, label: &str) -> &'static str {
+        if let Some(interned) = self.labels.get(label) {
+            return interned;
+        }
+        let interned: &'static str = Box::leak(label.into());
+        self.labels.insert(interned);
+        interned
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnexpectedChar(char),
+    Unexpected {
+        expected: &'static str,
+        found: String,
+    },
+    UnknownNode(String),
+    UnknownArgument {
+        node: &'static str,
+        argument: String,
+    },
+    MissingArgument {
+        node: &'static str,
+        argument: &'static str,
+    },
+    ChildCount {
+        node: &'static str,
+        expected: &'static str,
+        found: usize,
+    },
+    Parallel(ParallelError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::UnexpectedChar(found) => write!(f, "unexpected character `{found}`"),
+            ParseErrorKind::Unexpected { expected, found } => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            ParseErrorKind::UnknownNode(name) => write!(f, "unknown node `{name}`"),
+            ParseErrorKind::UnknownArgument { node, argument } => {
+                write!(f, "`{node}` does not take argument `{argument}`")
+            }
+            ParseErrorKind::MissingArgument { node, argument } => {
+                write!(f, "`{node}` needs {argument}")
+            }
+            ParseErrorKind::ChildCount {
+                node,
+                expected,
+                found,
+            } => write!(f, "`{node}` takes {expected} children, found {found}"),
+            ParseErrorKind::Parallel(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum ReloadError {
+    Io(io::Error),
+    Parse(ParseError),
+    Invalid(Vec<Diagnostic>),
+}
+
+impl fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReloadError::Io(error) => write!(f, "cannot read tree file: {error}"),
+            ReloadError::Parse(error) => write!(f, "cannot parse tree file: {error}"),
+            ReloadError::Invalid(diagnostics) => {
+                f.write_str("tree failed validation:")?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n  {diagnostic}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReloadError::Io(error) => Some(error),
+            ReloadError::Parse(error) => Some(error),
+            ReloadError::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReloadError {
+    fn from(error: io::Error) -> Self {
+        ReloadError::Io(error)
+    }
+}
+
+impl From<ParseError> for ReloadError {
+    fn from(error: ParseError) -> Self {
+        ReloadError::Parse(error)
+    }
+}
+
+// Polls the file's modification time; there is no platform file watcher, so
+// edits are picked up at the polling interval.
+pub struct Reloader<Ctx> {
+    path: PathBuf,
+    registry: Registry<Ctx>,
+    stamp: Option<(SystemTime, u64)>,
+    handle: SwapHandle<Ctx>,
+}
+
+impl<Ctx> Reloader<Ctx>
+where
+    Ctx: 'static,
+{
+    pub fn open(
+        path: impl Into<PathBuf>,
+        registry: Registry<Ctx>,
+    ) -> Result<(Self, Swappable<Ctx>), ReloadError> {
+        Self::open_with_observer(path, registry, |_| {})
+    }
+
+    pub fn open_with_observer<F>(
+        path: impl Into<PathBuf>,
+        mut registry: Registry<Ctx>,
+        observer: F,
+    ) -> Result<(Self, Swappable<Ctx, F>), ReloadError>
+    where
+        F: FnMut(SwapEvent),
+    {
+        let path = path.into();
+        let stamp = stamp(&path)?;
+        let root = registry.build(&fs::read_to_string(&path)?)?;
+        let slot = Swappable::from_boxed(root, observer);
+        let reloader = Self {
+            path,
+            registry,
+            stamp: Some(stamp),
+            handle: slot.handle(),
+        };
+        Ok((reloader, slot))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // `Ok(true)` when the file changed and its tree is queued; the slot
+    // installs it at its next tick, resetting the old root if it is running.
+    // A file that fails to read, parse or validate leaves the old tree in
+    // place and is not retried until it changes again.
+    pub fn poll(&mut self) -> Result<bool, ReloadError> {
+        let stamp = stamp(&self.path)?;
+        if self.stamp == Some(stamp) {
+            return Ok(false);
+        }
+
+        self.stamp = Some(stamp);
+        self.reload()?;
+        Ok(true)
+    }
+
+    pub fn reload(&mut self) -> Result<(), ReloadError> {
+        let root = self.registry.build(&fs::read_to_string(&self.path)?)?;
+        self.handle.request(root, true);
+        Ok(())
+    }
+
+    pub async fn watch(
+        mut self,
+        interval: Duration,
+        mut report: impl FnMut(Result<(), ReloadError>),
+    ) {
+        loop {
+            tokio::time::sleep(interval).await;
+            match self.poll() {
+                Ok(false) => {}
+                Ok(true) => report(Ok(())),
+                Err(error) => report(Err(error)),
+            }
+        }
+    }
+}
+
+fn stamp(path: &Path) -> io::Result<(SystemTime, u64)> {
+    let metadata = fs::metadata(path)?;
+    Ok((metadata.modified()?, metadata.len()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind<'s> {
+    Ident(&'s str),
+    Colon,
+    Equals,
+    Comma,
+    OpenParen,
+    CloseParen,
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone)]
+struct Token<'s> {
+    kind: TokenKind<'s>,
+    line: usize,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        let found = match self.kind {
+            TokenKind::Ident(ident) => format!("`{ident}`"),
+            TokenKind::Colon => "`:`".into(),
+            TokenKind::Equals => "`=`".into(),
+            TokenKind::Comma => "`,`".into(),
+            TokenKind::OpenParen => "`(`".into(),
+            TokenKind::CloseParen => "`)`".into(),
+            TokenKind::Open => "`{`".into(),
+            TokenKind::Close => "`}`".into(),
+        };
+        self.error(ParseErrorKind::Unexpected { expected, found })
+    }
+}
+
+struct Lexer<'s> {
+    source: &'s str,
+    offset: usize,
+    line: usize,
+    column: usize,
+    peeked: Option<Option<Token<'s>>>,
+}
+
+impl<'s> Lexer<'s> {
+    fn new(source: &'s str) -> Self {
+        Self {
+            source,
+            offset: 0,
+            line: 1,
+            column: 1,
+            peeked: None,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token<'s>>, ParseError> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.scan()?);
+        }
+        Ok(self.peeked.as_ref().and_then(Option::as_ref))
+    }
+
+    fn next(&mut self) -> Result<Option<Token<'s>>, ParseError> {
+        match self.peeked.take() {
+            Some(token) => Ok(token),
+            None => self.scan(),
+        }
+    }
+
+    fn end_error(&self, expected: &'static str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            kind: ParseErrorKind::Unexpected {
+                expected,
+                found: "end of file".into(),
+            },
+        }
+    }
+
+    fn bump(&mut self, c: char) {
+        self.offset += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+    }
+
+    fn scan(&mut self) -> Result<Option<Token<'s>>, ParseError> {
+        let mut in_comment = false;
+        while let Some(c) = self.source[self.offset..].chars().next() {
+            if in_comment {
+                in_comment = c != '\n';
+            } else if c == '#' {
+                in_comment = true;
+            } else if !c.is_whitespace() {
+                break;
+            }
+            self.bump(c);
+        }
+
+        let (line, column, start) = (self.line, self.column, self.offset);
+        let Some(c) = self.source[start..].chars().next() else {
+            return Ok(None);
+        };
+        let kind = match c {
+            ':' => TokenKind::Colon,
+            '=' => TokenKind::Equals,
+            ',' => TokenKind::Comma,
+            '(' => TokenKind::OpenParen,
+            ')' => TokenKind::CloseParen,
+            '{' => TokenKind::Open,
+            '}' => TokenKind::Close,
+            c if is_ident(c) => {
+                while let Some(c) = self.source[self.offset..].chars().next() {
+                    if !is_ident(c) {
+                        break;
+                    }
+                    self.bump(c);
+                }
+                return Ok(Some(Token {
+                    kind: TokenKind::Ident(&self.source[start..self.offset]),
+                    line,
+                    column,
+                }));
+            }
+            c => {
+                return Err(ParseError {
+                    line,
+                    column,
+                    kind: ParseErrorKind::UnexpectedChar(c),
+                });
+            }
+        };
+        self.bump(c);
+        Ok(Some(Token { kind, line, column }))
+    }
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+struct Parser<'s, 'r, Ctx> {
+    lexer: Lexer<'s>,
+    registry: &'r mut Registry<Ctx>,
+}
+
+impl<'s, Ctx> Parser<'s, '_, Ctx>
+where
+    Ctx: 'static,
+{
+    fn ident(&mut self, expected: &'static str) -> Result<(&'s str, Token<'s>), ParseError> {
+        match self.lexer.next()? {
+            Some(token) => match token.kind {
+                TokenKind::Ident(ident) => Ok((ident, token)),
+                _ => Err(token.unexpected(expected)),
+            },
+            None => Err(self.lexer.end_error(expected)),
+        }
+    }
+
+    fn eat(&mut self, kind: TokenKind<'_>) -> Result<bool, ParseError> {
+        let matches = self.lexer.peek()?.is_some_and(|token| token.kind == kind);
+        if matches {
+            self.lexer.next()?;
+        }
+        Ok(matches)
+    }
+
+    // node := [label ':'] kind ['(' argument (',' argument)* ')'] ['{' node* '}']
+    // argument := word | word '=' word
+    fn node(&mut self) -> Result<BoxedNode<Ctx>, ParseError> {
+        let (mut kind, mut token) = self.ident("a node")?;
+        let mut label = None;
+        if self.eat(TokenKind::Colon)? {
+            label = Some(kind);
+            (kind, token) = self.ident("a node")?;
+        }
+
+        let mut arguments = Vec::new();
+        if self.eat(TokenKind::OpenParen)? {
+            loop {
+                let (word, argument) = self.ident("an argument")?;
+                let value = if self.eat(TokenKind::Equals)? {
+                    Some(self.ident("an argument value")?.0)
+                } else {
+                    None
+                };
+                arguments.push((word, value, argument));
+
+                if self.eat(TokenKind::CloseParen)? {
+                    break;
+                }
+                if !self.eat(TokenKind::Comma)? {
+                    return Err(match self.lexer.next()? {
+                        Some(token) => token.unexpected("`,` or `)`"),
+                        None => self.lexer.end_error("`,` or `)`"),
+                    });
+                }
+            }
+        }
+
+        let mut children = Vec::new();
+        let braced = self.eat(TokenKind::Open)?;
+        if braced {
+            while !self.eat(TokenKind::Close)? {
+                if self.lexer.peek()?.is_none() {
+                    return Err(self.lexer.end_error("`}`"));
+                }
+                children.push(self.node()?);
+            }
+        }
+
+        let node = self.build(kind, &token, arguments, children, braced)?;
+        Ok(match label {
+            Some(label) => Box::new(Named::new(node, self.registry.intern(label))),
+            None => node,
+        })
+    }
+
+    fn build(
+        &mut self,
+        kind: &'s str,
+        token: &Token<'s>,
+        arguments: Vec<(&'s str, Option<&'s str>, Token<'s>)>,
+        mut children: Vec<BoxedNode<Ctx>>,
+        braced: bool,
+    ) -> Result<BoxedNode<Ctx>, ParseError> {
+        let mut memory = Memory::Resume;
+        let mut count = None;
+        let mut success = None;
+        let mut failure = None;
+
+        let (name, arity): (&'static str, Arity) = match kind {
+            "sequence" => ("sequence", Arity::Many),
+            "selector" => ("selector", Arity::Many),
+            "reactive_sequence" => ("reactive_sequence", Arity::Many),
+            "reactive_selector" => ("reactive_selector", Arity::Many),
+            "parallel" => ("parallel", Arity::Many),
+            "parallel_with_memory" => ("parallel_with_memory", Arity::Many),
+            "inverter" => ("inverter", Arity::One),
+            "force_success" => ("force_success", Arity::One),
+            "force_failure" => ("force_failure", Arity::One),
+            "retry" => ("retry", Arity::One),
+            "repeat" => ("repeat", Arity::One),
+            leaf => {
+                let Some((&name, _)) = self.registry.leaves.get_key_value(leaf) else {
+                    return Err(token.error(ParseErrorKind::UnknownNode(leaf.into())));
+                };
+                if let Some((argument, _, token)) = arguments.first() {
+                    return Err(token.error(ParseErrorKind::UnknownArgument {
+                        node: name,
+                        argument: (*argument).into(),
+                    }));
+                }
+                if braced {
+                    return Err(token.error(ParseErrorKind::ChildCount {
+                        node: name,
+                        expected: "no",
+                        found: children.len(),
+                    }));
+                }
+                let leaf = (self.registry.leaves[name])();
+                return Ok(Box::new(Named::new(leaf, name)));
+            }
+        };
+
+        for (word, value, token) in arguments {
+            let number = value.and_then(|value| value.parse::<usize>().ok());
+            match (name, word, value) {
+                ("sequence" | "selector", "persistent", None) => memory = Memory::Persistent,
+                ("retry" | "repeat", word, None) if word.parse::<usize>().is_ok() => {
+                    count = word.parse().ok();
+                }
+                ("parallel" | "parallel_with_memory", "success", Some(_)) if number.is_some() => {
+                    success = number;
+                }
+                ("parallel" | "parallel_with_memory", "failure", Some(_)) if number.is_some() => {
+                    failure = number;
+                }
+                _ => {
+                    return Err(token.error(ParseErrorKind::UnknownArgument {
+                        node: name,
+                        argument: match value {
+                            Some(value) => format!("{word}={value}"),
+                            None => word.into(),
+                        },
+                    }));
+                }
+            }
+        }
+
+        let found = children.len();
+        let child_count = |expected| {
+            token.error(ParseErrorKind::ChildCount {
+                node: name,
+                expected,
+                found,
+            })
+        };
+        match arity {
+            Arity::Many if found == 0 => return Err(child_count("one or more")),
+            Arity::One if found != 1 => return Err(child_count("exactly one")),
+            _ => {}
+        }
+
+        let policy = match (success, failure) {
+            (None, None) => ParallelPolicy::default(),
+            (success, failure) => ParallelPolicy::Thresholds {
+                success: success.unwrap_or(found),
+                failure: failure.unwrap_or(1),
+            },
+        };
+        let parallel = |error| token.error(ParseErrorKind::Parallel(error));
+
+        Ok(match name {
+            "sequence" => Box::new(Sequence::with_memory(children, memory)),
+            "selector" => Box::new(Selector::with_memory(children, memory)),
+            "reactive_sequence" => Box::new(ReactiveSequence::new(children)),
+            "reactive_selector" => Box::new(ReactiveSelector::new(children)),
+            "parallel" => Box::new(Parallel::with_policy(children, policy).map_err(parallel)?),
+            "parallel_with_memory" => {
+                Box::new(ParallelWithMemory::with_policy(children, policy).map_err(parallel)?)
+            }
+            "inverter" => Box::new(Inverter::new(children.remove(0))),
+            "force_success" => Box::new(ForceSuccess::new(children.remove(0))),
+            "force_failure" => Box::new(ForceFailure::new(children.remove(0))),
+            _ => {
+                let Some(count) = count else {
+                    return Err(token.error(ParseErrorKind::MissingArgument {
+                        node: name,
+                        argument: "a count",
+                    }));
+                };
+                match name {
+                    "retry" => Box::new(Retry::new(children.remove(0), count)),
+                    _ => Box::new(Repeat::new(children.remove(0), count)),
+                }
+            }
+        })
+    }
+}
+
+enum Arity {
+    One,
+    Many,
+}
@@ -0,0 +1,295 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use arbor_core::{Inspect, Node, Params, Ports, Remap, Status};
+
+// `Node::tick` is an `async fn`, so `dyn Node<Ctx>` is not object safe. The
+// slot boxes each tick's future instead; that allocation is the price of a
+// child whose type is only known at runtime.
+pub trait DynNode<Ctx>: Inspect {
+    fn tick_dyn<'a>(&'a mut self, ctx: &'a mut Ctx) -> Pin<Box<dyn Future<Output = Status> + 'a>>;
+
+    fn reset_dyn(&mut self);
+
+    fn skipped_dyn(&self) -> bool;
+}
+
+impl<Ctx, N> DynNode<Ctx> for N
+where
+    N: Node<Ctx> + Inspect,
+{
+    fn tick_dyn<'a>(&'a mut self, ctx: &'a mut Ctx) -> Pin<Box<dyn Future<Output = Status> + 'a>> {
+        Box::pin(self.tick(ctx))
+    }
+
+    fn reset_dyn(&mut self) {
+        self.reset();
+    }
+
+    fn skipped_dyn(&self) -> bool {
+        self.skipped()
+    }
+}
+
+pub type BoxedNode<Ctx> = Box<dyn DynNode<Ctx> + Send>;
+
+// Dereference explicitly: `BoxedNode` is itself a `DynNode` through these
+// impls, and method syntax would pick that impl and recurse.
+impl<Ctx> Node<Ctx> for BoxedNode<Ctx> {
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        (**self).tick_dyn(ctx).await
+    }
+
+    fn reset(&mut self) {
+        (**self).reset_dyn();
+    }
+
+    fn skipped(&self) -> bool {
+        (**self).skipped_dyn()
+    }
+}
+
+impl<Ctx> Inspect for BoxedNode<Ctx> {
+    fn kind(&self) -> &'static str {
+        (**self).kind()
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        (**self).label()
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        (**self).inspect_children(visit);
+    }
+
+    fn ports(&self) -> Option<&dyn Ports> {
+        (**self).ports()
+    }
+
+    fn scope(&self) -> Option<(&'static str, &'static [Remap])> {
+        (**self).scope()
+    }
+
+    fn params(&self) -> Option<Params> {
+        (**self).params()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapEvent {
+    // A swap was requested while the current child is running; it waits for
+    // the child to finish or be reset.
+    Deferred { generation: u64 },
+    // The running child was reset so an interrupting swap could apply.
+    Interrupted { generation: u64 },
+    Applied { generation: u64 },
+    // A newer request replaced this one before it applied.
+    Superseded { generation: u64 },
+}
+
+struct Request<Ctx> {
+    node: BoxedNode<Ctx>,
+    generation: u64,
+    interrupt: bool,
+}
+
+struct Shared<Ctx> {
+    pending: Option<Request<Ctx>>,
+    superseded: Vec<u64>,
+    requested: u64,
+}
+
+fn lock<Ctx>(shared: &Mutex<Shared<Ctx>>) -> MutexGuard<'_, Shared<Ctx>> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub struct SwapHandle<Ctx> {
+    shared: Arc<Mutex<Shared<Ctx>>>,
+}
+
+impl<Ctx> Clone for SwapHandle<Ctx> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<Ctx> SwapHandle<Ctx> {
+    // Applies once the slot is not running: at the next tick after the
+    // current child finishes, or when the slot is reset.
+    pub fn swap<N>(&self, node: N) -> u64
+    where
+        N: DynNode<Ctx> + Send + 'static,
+    {
+        self.request(Box::new(node), false)
+    }
+
+    // Applies at the next tick, resetting the current child if it is running.
+    pub fn swap_interrupting<N>(&self, node: N) -> u64
+    where
+        N: DynNode<Ctx> + Send + 'static,
+    {
+        self.request(Box::new(node), true)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        lock(&self.shared).pending.is_some()
+    }
+
+    pub(crate) fn request(&self, node: BoxedNode<Ctx>, interrupt: bool) -> u64 {
+        let mut shared = lock(&self.shared);
+        shared.requested += 1;
+        let generation = shared.requested;
+        if let Some(previous) = shared.pending.replace(Request {
+            node,
+            generation,
+            interrupt,
+        }) {
+            shared.superseded.push(previous.generation);
+        }
+        generation
+    }
+}
+
+pub struct Swappable<Ctx, F = fn(SwapEvent)> {
+    child: BoxedNode<Ctx>,
+    shared: Arc<Mutex<Shared<Ctx>>>,
+    observer: F,
+    generation: u64,
+    running: bool,
+    deferred: Option<u64>,
+}
+
+impl<Ctx> Swappable<Ctx> {
+    pub fn new<N>(child: N) -> Self
+    where
+        N: DynNode<Ctx> + Send + 'static,
+    {
+        Self::with_observer(child, |_| {})
+    }
+}
+
+impl<Ctx, F> Swappable<Ctx, F>
+where
+    F: FnMut(SwapEvent),
+{
+    pub fn with_observer<N>(child: N, observer: F) -> Self
+    where
+        N: DynNode<Ctx> + Send + 'static,
+    {
+        Self::from_boxed(Box::new(child), observer)
+    }
+
+    pub(crate) fn from_boxed(child: BoxedNode<Ctx>, observer: F) -> Self {
+        Self {
+            child,
+            shared: Arc::new(Mutex::new(Shared {
+                pending: None,
+                superseded: Vec::new(),
+                requested: 0,
+            })),
+            observer,
+            generation: 0,
+            running: false,
+            deferred: None,
+        }
+    }
+
+    pub fn handle(&self) -> SwapHandle<Ctx> {
+        SwapHandle {
+            shared: self.shared.clone(),
+        }
+    }
+
+    // The generation of the installed child; the initial child is 0.
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn apply_pending(&mut self) {
+        let (superseded, request) = {
+            let mut shared = lock(&self.shared);
+            let superseded = core::mem::take(&mut shared.superseded);
+            let ready = shared
+                .pending
+                .as_ref()
+                .is_some_and(|request| !self.running || request.interrupt);
+            let request = if ready { shared.pending.take() } else { None };
+            (superseded, request)
+        };
+
+        for generation in superseded {
+            (self.observer)(SwapEvent::Superseded { generation });
+        }
+
+        let Some(request) = request else {
+            if !self.running {
+                return;
+            }
+            if let Some(generation) = lock(&self.shared)
+                .pending
+                .as_ref()
+                .map(|request| request.generation)
+                .filter(|generation| self.deferred != Some(*generation))
+            {
+                self.deferred = Some(generation);
+                (self.observer)(SwapEvent::Deferred { generation });
+            }
+            return;
+        };
+
+        if self.running {
+            self.child.reset_dyn();
+            self.running = false;
+            (self.observer)(SwapEvent::Interrupted {
+                generation: request.generation,
+            });
+        }
+        self.child = request.node;
+        self.generation = request.generation;
+        self.deferred = None;
+        (self.observer)(SwapEvent::Applied {
+            generation: request.generation,
+        });
+    }
+}
+
+impl<Ctx, F> Node<Ctx> for Swappable<Ctx, F>
+where
+    F: FnMut(SwapEvent),
+{
+    async fn tick(&mut self, ctx: &mut Ctx) -> Status {
+        self.apply_pending();
+
+        let status = self.child.tick_dyn(ctx).await;
+        self.running = status == Status::Running;
+        if !self.running {
+            self.child.reset_dyn();
+        }
+        status
+    }
+
+    fn reset(&mut self) {
+        self.child.reset_dyn();
+        self.running = false;
+        self.apply_pending();
+    }
+
+    fn skipped(&self) -> bool {
+        self.child.skipped_dyn()
+    }
+}
+
+impl<Ctx, F> Inspect for Swappable<Ctx, F> {
+    fn kind(&self) -> &'static str {
+        "Swappable"
+    }
+
+    fn inspect_children(&self, visit: &mut dyn FnMut(&dyn Inspect)) {
+        visit(&*self.child);
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    cell::RefCell,
+    fs::{self, File},
+    path::PathBuf,
+    rc::Rc,
+    time::{Duration, SystemTime},
+};
+
+use arbor::{
+    Action, Blackboard, CheckEntry, FixedStorage, Inspect, Key, Node, ParseErrorKind, Port,
+    Registry, ReloadError, Reloader, SetEntry, Status, SwapEvent, walk,
+};
+
+type Board = Blackboard<FixedStorage<8>>;
+
+const ARMED: Key<bool> = Key::new("armed");
+const WAYPOINT: Key<u32> = Key::new("waypoint");
+
+struct TreeFile {
+    path: PathBuf,
+    version: u64,
+}
+
+impl TreeFile {
+    fn new(name: &str, source: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("arbor-reload-{}-{name}.tree", std::process::id()));
+        let mut file = Self { path, version: 0 };
+        file.write(source);
+        file
+    }
+
+    // Steps the modification time explicitly so edits within one timestamp
+    // tick are still seen as changes.
+    fn write(&mut self, source: &str) {
+        fs::write(&self.path, source).unwrap();
+        self.version += 1;
+        File::options()
+            .write(true)
+            .open(&self.path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(self.version))
+            .unwrap();
+    }
+}
+
+impl Drop for TreeFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn registry() -> Registry<Board> {
+    Registry::new()
+        .register("arm", || SetEntry::new(ARMED, true))
+        .register("check_armed", || {
+            CheckEntry::new(ARMED, |armed: &bool| *armed)
+        })
+        .register("wait", || {
+            let mut ticks = 0;
+            Action::new(move |_: &mut Board| {
+                ticks += 1;
+                let done = ticks % 2 == 0;
+                async move {
+                    if done {
+                        Status::Success
+                    } else {
+                        Status::Running
+                    }
+                }
+            })
+        })
+        .register("fail", || {
+            Action::new(|_: &mut Board| async { Status::Failure })
+        })
+}
+
+fn paths(root: &dyn Inspect) -> Vec<String> {
+    let mut paths = Vec::new();
+    walk(root, &mut |path, node| {
+        paths.push(format!("{path} {}", node.kind()));
+    });
+    paths
+}
+
+#[tokio::test]
+async fn parses_composites_decorators_labels_and_leaves() {
+    let mut registry = registry();
+    let tree = registry
+        .parse(
+            "# preflight, then fly
+            mission: sequence(persistent) {
+                arm
+                check: reactive_selector { check_armed fail }
+                retry(2) { inverter { fail } }
+                parallel(success=2, failure=2) { wait force_success { fail } }
+            }",
+        )
+        .unwrap();
+
+    assert_eq!(
+        paths(&tree),
+        [
+            "mission Sequence",
+            "mission/arm SetEntry",
+            "mission/check ReactiveSelector",
+            "mission/check/check_armed CheckEntry",
+            "mission/check/fail Action",
+            "mission/2 Retry",
+            "mission/2/0 Inverter",
+            "mission/2/0/fail Action",
+            "mission/3 Parallel",
+            "mission/3/wait Action",
+            "mission/3/1 ForceSuccess",
+            "mission/3/1/fail Action",
+        ]
+    );
+
+    let mut tree = tree;
+    let mut board = Board::default();
+    assert_eq!(tree.tick(&mut board).await, Status::Running);
+    assert_eq!(tree.tick(&mut board).await, Status::Success);
+}
+
+#[test]
+fn parse_errors_report_positions() {
+    let mut registry = registry();
+    let mut error = |source| registry.parse(source).map(drop).unwrap_err();
+
+    let unknown = error("sequence {\n  arm\n  land\n}");
+    assert_eq!((unknown.line, unknown.column), (3, 3));
+    assert_eq!(unknown.kind, ParseErrorKind::UnknownNode("land".into()));
+    assert_eq!(unknown.to_string(), "3:3: unknown node `land`");
+
+    assert_eq!(
+        error("sequence { arm").to_string(),
+        "1:15: expected `}`, found end of file"
+    );
+    assert_eq!(
+        error("inverter { arm fail }").kind,
+        ParseErrorKind::ChildCount {
+            node: "inverter",
+            expected: "exactly one",
+            found: 2,
+        }
+    );
+    assert_eq!(
+        error("retry { arm }").to_string(),
+        "1:1: `retry` needs a count"
+    );
+    assert_eq!(
+        error("selector(fast) { arm }").to_string(),
+        "1:10: `selector` does not take argument `fast`"
+    );
+    assert_eq!(
+        error("arm { fail }").to_string(),
+        "1:1: `arm` takes no children, found 1"
+    );
+    assert!(matches!(
+        error("parallel(success=3) { arm }").kind,
+        ParseErrorKind::Parallel(_)
+    ));
+    assert_eq!(
+        error("arm fail").to_string(),
+        "1:5: expected end of file, found `fail`"
+    );
+    assert_eq!(error("arm;").kind, ParseErrorKind::UnexpectedChar(';'));
+}
+
+#[test]
+fn build_rejects_trees_that_fail_port_validation() {
+    let mut registry = registry();
+    assert!(registry.build("sequence { arm check_armed }").is_ok());
+
+    let Err(ReloadError::Invalid(diagnostics)) = registry.build("check_armed") else {
+        panic!("expected validation failure");
+    };
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "check_armed: input `armed` is never written"
+    );
+
+    assert!(matches!(
+        registry.build("arm"),
+        Err(ReloadError::Invalid(_))
+    ));
+
+    let mut registry = registry.environment(&[Port::output(ARMED), Port::input(WAYPOINT)]);
+    assert!(registry.build("check_armed").is_ok());
+    assert!(registry.build("arm").is_err());
+    let mut registry = registry.environment(&[Port::input(ARMED)]);
+    assert!(registry.build("arm").is_ok());
+}
+
+#[tokio::test]
+async fn reloader_swaps_the_root_between_ticks_and_keeps_it_on_errors() {
+    let mut file = TreeFile::new("swap", "sequence { arm wait check_armed }");
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = events.clone();
+    let (mut reloader, mut root) =
+        Reloader::open_with_observer(&file.path, registry(), move |event| {
+            sink.borrow_mut().push(event)
+        })
+        .unwrap();
+    let mut board = Board::default();
+
+    assert!(!reloader.poll().unwrap());
+    assert_eq!(root.tick(&mut board).await, Status::Running);
+
+    file.write("sequence { arm wait check_armed");
+    let Err(ReloadError::Parse(error)) = reloader.poll() else {
+        panic!("expected parse error");
+    };
+    assert_eq!(error.to_string(), "1:32: expected `}`, found end of file");
+    assert!(!reloader.poll().unwrap(), "a broken file is reported once");
+    assert_eq!(root.tick(&mut board).await, Status::Success);
+    assert_eq!(root.generation(), 0);
+
+    file.write("force_success { fail }");
+    assert!(reloader.poll().unwrap());
+    assert_eq!(root.tick(&mut board).await, Status::Success);
+    assert_eq!(root.generation(), 1);
+    assert_eq!(paths(&root)[1], "root/0 ForceSuccess");
+    assert_eq!(*events.borrow(), [SwapEvent::Applied { generation: 1 }]);
+
+    fs::remove_file(&file.path).unwrap();
+    assert!(matches!(reloader.poll(), Err(ReloadError::Io(_))));
+    assert_eq!(root.tick(&mut board).await, Status::Success);
+}
+
+#[tokio::test]
+async fn reload_interrupts_a_running_root() {
+    let mut file = TreeFile::new("interrupt", "wait");
+    let (mut reloader, mut root) = Reloader::open(&file.path, registry()).unwrap();
+    let mut board = Board::default();
+
+    assert_eq!(root.tick(&mut board).await, Status::Running);
+    file.write("fail");
+    assert!(reloader.poll().unwrap());
+    assert_eq!(root.tick(&mut board).await, Status::Failure);
+    assert_eq!(reloader.path(), file.path);
+}
+
+#[tokio::test(start_paused = true)]
+async fn watch_polls_the_file_and_reports_each_change() {
+    let mut file = TreeFile::new("watch", "wait");
+    let (reloader, _root) = Reloader::open(&file.path, registry()).unwrap();
+    let reports = Rc::new(RefCell::new(Vec::new()));
+    let sink = reports.clone();
+    let watch = reloader.watch(Duration::from_millis(100), move |result| {
+        sink.borrow_mut()
+            .push(result.map_err(|error| error.to_string()))
+    });
+
+    tokio::select! {
+        _ = watch => unreachable!("watch runs until dropped"),
+        _ = async {
+            tokio::time::sleep(Duration::from_millis(150)).await;
+            file.write("fail {");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            file.write("fail");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        } => {}
+    }
+
+    assert_eq!(
+        *reports.borrow(),
+        [
+            Err("cannot parse tree file: 1:7: expected `}`, found end of file".to_string()),
+            Ok(()),
+        ]
+    );
+}
@@ -0,0 +1,154 @@
+use std::{cell::RefCell, rc::Rc, thread};
+
+use arbor::{Action, Constant, Node, NodeExt, Sequence, Status, SwapEvent, Swappable, walk};
+
+#[derive(Debug, Default)]
+struct SurveyCtx {
+    visited: Vec<&'static str>,
+}
+
+fn pattern(name: &'static str, legs: usize) -> impl Node<SurveyCtx> + arbor::Inspect + Send {
+    let mut remaining = legs;
+    Action::new(move |ctx: &mut SurveyCtx| {
+        ctx.visited.push(name);
+        remaining -= 1;
+        let done = remaining == 0;
+        if done {
+            remaining = legs;
+        }
+        async move {
+            if done {
+                Status::Success
+            } else {
+                Status::Running
+            }
+        }
+    })
+    .named(name)
+}
+
+fn observed() -> (Rc<RefCell<Vec<SwapEvent>>>, impl FnMut(SwapEvent)) {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = events.clone();
+    (events, move |event| sink.borrow_mut().push(event))
+}
+
+#[tokio::test]
+async fn swap_waits_for_the_running_child_to_finish() {
+    let (events, observer) = observed();
+    let mut slot = Swappable::with_observer(pattern("lawnmower", 2), observer);
+    let handle = slot.handle();
+    let mut ctx = SurveyCtx::default();
+
+    assert_eq!(slot.tick(&mut ctx).await, Status::Running);
+    assert_eq!(handle.swap(pattern("spiral", 1)), 1);
+    assert!(handle.is_pending());
+
+    assert_eq!(slot.tick(&mut ctx).await, Status::Success);
+    assert_eq!(slot.generation(), 0);
+    assert_eq!(slot.tick(&mut ctx).await, Status::Success);
+    assert_eq!(slot.generation(), 1);
+    assert!(!handle.is_pending());
+
+    assert_eq!(ctx.visited, ["lawnmower", "lawnmower", "spiral"]);
+    assert_eq!(
+        *events.borrow(),
+        [
+            SwapEvent::Deferred { generation: 1 },
+            SwapEvent::Applied { generation: 1 },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn reset_is_a_safe_point_for_pending_swaps() {
+    let (events, observer) = observed();
+    let mut slot = Swappable::with_observer(pattern("lawnmower", 3), observer);
+    let handle = slot.handle();
+    let mut ctx = SurveyCtx::default();
+
+    assert_eq!(slot.tick(&mut ctx).await, Status::Running);
+    handle.swap(pattern("spiral", 1));
+    slot.reset();
+
+    assert_eq!(slot.generation(), 1);
+    assert_eq!(slot.tick(&mut ctx).await, Status::Success);
+    assert_eq!(ctx.visited, ["lawnmower", "spiral"]);
+    assert_eq!(*events.borrow(), [SwapEvent::Applied { generation: 1 }]);
+}
+
+#[tokio::test]
+async fn interrupting_swap_resets_the_old_child_at_the_next_tick() {
+    let (events, observer) = observed();
+    let mut slot = Swappable::with_observer(pattern("lawnmower", 3), observer);
+    let handle = slot.handle();
+    let mut ctx = SurveyCtx::default();
+
+    assert_eq!(slot.tick(&mut ctx).await, Status::Running);
+    handle.swap_interrupting(pattern("spiral", 2));
+    assert_eq!(slot.tick(&mut ctx).await, Status::Running);
+    assert_eq!(slot.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(ctx.visited, ["lawnmower", "spiral", "spiral"]);
+    assert_eq!(
+        *events.borrow(),
+        [
+            SwapEvent::Interrupted { generation: 1 },
+            SwapEvent::Applied { generation: 1 },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn newer_requests_supersede_pending_ones() {
+    let (events, observer) = observed();
+    let mut slot = Swappable::with_observer(Constant::new(Status::Failure), observer);
+    let handle = slot.handle();
+    let mut ctx = SurveyCtx::default();
+
+    handle.swap(Constant::new(Status::Running));
+    handle.swap(Constant::new(Status::Success));
+
+    assert_eq!(slot.tick(&mut ctx).await, Status::Success);
+    assert_eq!(slot.generation(), 2);
+    assert_eq!(
+        *events.borrow(),
+        [
+            SwapEvent::Superseded { generation: 1 },
+            SwapEvent::Applied { generation: 2 },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn handles_swap_from_other_threads_while_the_tree_keeps_running() {
+    let slot = Swappable::new(pattern("lawnmower", 2));
+    let handle = slot.handle();
+    let mut tree = Sequence::new((
+        Action::new(|ctx: &mut SurveyCtx| {
+            ctx.visited.push("takeoff");
+            async { Status::Success }
+        }),
+        slot,
+    ));
+    let mut ctx = SurveyCtx::default();
+
+    assert_eq!(tree.tick(&mut ctx).await, Status::Running);
+    thread::spawn(move || handle.swap(pattern("spiral", 1)))
+        .join()
+        .unwrap();
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+    assert_eq!(tree.tick(&mut ctx).await, Status::Success);
+
+    assert_eq!(
+        ctx.visited,
+        ["takeoff", "lawnmower", "lawnmower", "takeoff", "spiral"]
+    );
+
+    let mut labels = Vec::new();
+    walk(&tree, &mut |path, node| {
+        labels.push((path.to_string(), node.kind()));
+    });
+    assert_eq!(labels[2], ("root/1".to_string(), "Swappable"));
+    assert_eq!(labels[3], ("root/1/spiral".to_string(), "Action"));
+}